@@ -0,0 +1,112 @@
+//! Compares parsing a large `work-list` page through `serde_json::Value` with deserializing it
+//! straight from the response bytes.
+//!
+//! Run with `cargo bench --bench parse`.
+
+use std::time::{Duration, Instant};
+
+use crossref_rs::response::Response;
+use crossref_rs::ParseMode;
+use serde_json::{json, Value};
+
+fn work(i: usize) -> Value {
+    let references: Vec<_> = (0..50)
+        .map(|r| {
+            json!({
+                "key": format!("ref{}", r),
+                "DOI": format!("10.1000/ref.{}.{}", i, r),
+                "doi-asserted-by": "crossref",
+                "first-page": "29",
+                "volume": "59",
+                "author": "Ray",
+                "year": "2004",
+                "journal-title": "American Psychologist",
+                "unstructured": "Ray, O. (2004). How the mind hurts and heals the body. American Psychologist, 59(1), 29-40."
+            })
+        })
+        .collect();
+    json!({
+        "indexed": {"date-parts": [[2019, 2, 14]], "date-time": "2019-02-14T05:10:15Z", "timestamp": 1550121015066u64},
+        "reference-count": 50,
+        "publisher": "American Psychological Association (APA)",
+        "issue": "1",
+        "content-domain": {"domain": [], "crossmark-restriction": false},
+        "short-container-title": ["American Psychologist"],
+        "DOI": format!("10.1037/0003-066x.59.1.{}", i),
+        "type": "journal-article",
+        "created": {"date-parts": [[2004, 1, 21]], "date-time": "2004-01-21T14:31:19Z", "timestamp": 1074695479000u64},
+        "page": "29-40",
+        "source": "Crossref",
+        "is-referenced-by-count": 83,
+        "title": ["How the Mind Hurts and Heals the Body."],
+        "prefix": "10.1037",
+        "volume": "59",
+        "author": [
+            {"given": "Oakley", "family": "Ray", "sequence": "first", "affiliation": []},
+            {"given": "Jane", "family": "Doe", "sequence": "additional", "affiliation": [{"name": "Some University"}]}
+        ],
+        "member": "15",
+        "published-online": {"date-parts": [[2004]]},
+        "container-title": ["American Psychologist"],
+        "language": "en",
+        "link": [{"URL": "http://psycnet.apa.org/journals/amp/59/1/29.pdf", "content-type": "unspecified", "content-version": "vor", "intended-application": "similarity-checking"}],
+        "deposited": {"date-parts": [[2018, 4, 8]], "date-time": "2018-04-08T18:56:17Z", "timestamp": 1523213777000u64},
+        "score": 1.0,
+        "issued": {"date-parts": [[2004]]},
+        "references-count": 50,
+        "alternative-id": ["2004-10043-004", "14736318"],
+        "URL": "http://dx.doi.org/10.1037/0003-066x.59.1.29",
+        "relation": {},
+        "ISSN": ["1935-990X", "0003-066X"],
+        "issn-type": [{"value": "0003-066X", "type": "print"}, {"value": "1935-990X", "type": "electronic"}],
+        "reference": references
+    })
+}
+
+fn page(rows: usize) -> Vec<u8> {
+    let items: Vec<_> = (0..rows).map(work).collect();
+    serde_json::to_vec(&json!({
+        "status": "ok",
+        "message-type": "work-list",
+        "message-version": "1.0.0",
+        "message": {
+            "facets": {},
+            "total-results": rows,
+            "items-per-page": rows,
+            "query": {"start-index": 0, "search-terms": null},
+            "items": items
+        }
+    }))
+    .unwrap()
+}
+
+fn bench(name: &str, iterations: u32, mut f: impl FnMut()) -> Duration {
+    f();
+    let start = Instant::now();
+    for _ in 0..iterations {
+        f();
+    }
+    let per_iter = start.elapsed() / iterations;
+    println!("{:<32} {:>10.2?} per page", name, per_iter);
+    per_iter
+}
+
+fn main() {
+    let bytes = page(1000);
+    println!("work-list page with 1000 items, {} KiB\n", bytes.len() / 1024);
+
+    for mode in [ParseMode::Strict, ParseMode::Lenient] {
+        let value_path = bench(&format!("{:?} via Value", mode), 10, || {
+            let value: Value = serde_json::from_slice(&bytes).unwrap();
+            Response::parse(value, mode).unwrap();
+        });
+        let direct = bench(&format!("{:?} from bytes", mode), 10, || {
+            Response::from_slice(&bytes, mode).unwrap();
+        });
+        println!(
+            "{:<32} {:>9.2}x\n",
+            "speedup",
+            value_path.as_secs_f64() / direct.as_secs_f64()
+        );
+    }
+}
@@ -1,6 +1,18 @@
+//! [Content negotiation](https://citation.crosscite.org/docs.html) returns the metadata of a DOI in
+//! other formats than the json of the api, like BibTeX, RIS or a formatted citation.
+//!
+//! The request goes to the DOI resolver (`https://doi.org` by default), which redirects it to the
+//! registration agency of the DOI. Not every agency supports every format, see [CnFormat::supported_by].
+
+use crate::error::{ErrorKind, Result};
+use crate::ident::Doi;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
-#[derive(Debug, Clone, Serialize)]
+/// The formats the metadata of a DOI can be requested in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
 #[allow(missing_docs)]
 pub enum CnFormat {
     RdfXml,
@@ -17,37 +29,275 @@ pub enum CnFormat {
 }
 
 impl CnFormat {
-    /// the mime identifier
+    /// all formats
+    pub const ALL: [CnFormat; 11] = [
+        CnFormat::RdfXml,
+        CnFormat::Turtle,
+        CnFormat::CiteProcJson,
+        CnFormat::CiteProcJsonIsh,
+        CnFormat::Text,
+        CnFormat::Ris,
+        CnFormat::BibTex,
+        CnFormat::CrossrefXml,
+        CnFormat::DataciteXml,
+        CnFormat::BibEntry,
+        CnFormat::CrossrefTdm,
+    ];
+
+    /// the mime identifier of the returned content
     pub fn mime_type(&self) -> &str {
-        match self {
-            CnFormat::RdfXml => "text/xml",
-            CnFormat::Turtle => "text/plain",
-            CnFormat::CiteProcJson => "application/json",
-            CnFormat::CiteProcJsonIsh => "application/json",
-            CnFormat::Text => "text/plain",
-            CnFormat::Ris => "text/plain",
-            CnFormat::BibTex => "text/xml",
-            CnFormat::CrossrefXml => "text/xml",
-            CnFormat::DataciteXml => "",
-            CnFormat::BibEntry => "text/plain",
-            CnFormat::CrossrefTdm => "text/xml",
-        }
-    }
-    /// the mime type's header
-    pub fn header(&self) -> &str {
         match self {
             CnFormat::RdfXml => "application/rdf+xml",
             CnFormat::Turtle => "text/turtle",
             CnFormat::CiteProcJson | CnFormat::CiteProcJsonIsh => {
-                "transform/application/vnd.citationstyles.csl+json"
+                "application/vnd.citationstyles.csl+json"
             }
             CnFormat::Text => "text/x-bibliography",
             CnFormat::Ris => "application/x-research-info-systems",
-            CnFormat::BibTex => "application/x-bibtex",
+            CnFormat::BibTex | CnFormat::BibEntry => "application/x-bibtex",
             CnFormat::CrossrefXml => "application/vnd.crossref.unixref+xml",
             CnFormat::DataciteXml => "application/vnd.datacite.datacite+xml",
-            CnFormat::BibEntry => "application/x-bibtex",
             CnFormat::CrossrefTdm => "application/vnd.crossref.unixsd+xml",
         }
     }
+
+    /// the value of the `Accept` header that requests this format
+    pub fn header(&self) -> &str {
+        match self {
+            CnFormat::CiteProcJsonIsh => "application/citeproc+json",
+            _ => self.mime_type(),
+        }
+    }
+
+    /// the name of the format, as used by the crossref tools
+    pub fn name(&self) -> &str {
+        match self {
+            CnFormat::RdfXml => "rdf-xml",
+            CnFormat::Turtle => "turtle",
+            CnFormat::CiteProcJson => "citeproc-json",
+            CnFormat::CiteProcJsonIsh => "citeproc-json-ish",
+            CnFormat::Text => "text",
+            CnFormat::Ris => "ris",
+            CnFormat::BibTex => "bibtex",
+            CnFormat::CrossrefXml => "crossref-xml",
+            CnFormat::DataciteXml => "datacite-xml",
+            CnFormat::BibEntry => "bibentry",
+            CnFormat::CrossrefTdm => "crossref-tdm",
+        }
+    }
+
+    /// whether the registration agency with the id `agency` (like `crossref` or `datacite`) serves this format.
+    ///
+    /// Unknown agencies are assumed to support the formats every agency supports.
+    pub fn supported_by(&self, agency: &str) -> bool {
+        match (agency.to_ascii_lowercase().as_str(), self) {
+            ("crossref", CnFormat::DataciteXml) => false,
+            ("crossref", _) => true,
+            ("datacite", CnFormat::CrossrefXml | CnFormat::CrossrefTdm) => false,
+            ("datacite", _) => true,
+            (
+                _,
+                CnFormat::RdfXml
+                | CnFormat::Turtle
+                | CnFormat::CiteProcJson
+                | CnFormat::CiteProcJsonIsh
+                | CnFormat::Text
+                | CnFormat::Ris
+                | CnFormat::BibTex,
+            ) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for CnFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for CnFormat {
+    type Err = ErrorKind;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        CnFormat::ALL
+            .iter()
+            .find(|format| format.name() == s)
+            .copied()
+            .ok_or_else(|| ErrorKind::InvalidField {
+                msg: format!("unknown content negotiation format `{}`", s),
+            })
+    }
+}
+
+string_enum! {
+    /// A citation style of the [CSL style repository](https://github.com/citation-style-language/styles),
+    /// styles without a variant can be used with their file name via `CitationStyle::from("...")`
+    pub enum CitationStyle {
+        /// American Psychological Association 7th edition
+        Apa => "apa",
+        /// Chicago Manual of Style, author-date
+        ChicagoAuthorDate => "chicago-author-date",
+        /// Chicago Manual of Style, notes and bibliography
+        ChicagoNoteBibliography => "chicago-note-bibliography",
+        /// Institute of Electrical and Electronics Engineers
+        Ieee => "ieee",
+        /// Vancouver, the style of the ICMJE recommendations
+        Vancouver => "vancouver",
+        /// Harvard, Cite Them Right
+        Harvard => "harvard-cite-them-right",
+        /// Modern Language Association 9th edition
+        Mla => "modern-language-association",
+        /// American Medical Association 11th edition
+        Ama => "american-medical-association",
+        /// American Chemical Society
+        Acs => "american-chemical-society",
+        /// Nature
+        Nature => "nature",
+        /// Elsevier Harvard, with titles
+        ElsevierHarvard => "elsevier-harvard",
+        /// Springer basic, author-date
+        SpringerBasic => "springer-basic-author-date",
+        /// BibTeX generic citation style
+        Bibtex => "bibtex",
+    }
+}
+
+/// A request for a citation formatted in a CSL `style` and `locale`, via [CnFormat::Text].
+///
+/// # Example
+///
+/// ```edition2018
+/// use crossref_rs::cn::{CitationRequest, CitationStyle};
+/// let request = CitationRequest::new(CitationStyle::Apa).locale("de-DE");
+/// assert_eq!("text/x-bibliography; style=apa; locale=de-DE", request.header());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CitationRequest {
+    /// the citation style
+    pub style: CitationStyle,
+    /// the language of terms like `and` or `Retrieved from`, like `en-US` or `fr-FR`, the agency
+    /// uses `en-US` if not set
+    pub locale: Option<String>,
+}
+
+impl CitationRequest {
+    /// a request for `style` in the default locale
+    pub fn new<T: Into<CitationStyle>>(style: T) -> Self {
+        CitationRequest {
+            style: style.into(),
+            locale: None,
+        }
+    }
+
+    /// set the locale like `en-GB` or `de-DE`
+    pub fn locale(mut self, locale: &str) -> Self {
+        self.locale = Some(locale.to_string());
+        self
+    }
+
+    /// the `Accept` header value with the `style` and `locale` parameters
+    pub fn header(&self) -> String {
+        let mut header = format!("{}; style={}", CnFormat::Text.header(), self.style);
+        if let Some(locale) = &self.locale {
+            header.push_str("; locale=");
+            header.push_str(locale);
+        }
+        header
+    }
+}
+
+impl From<CitationStyle> for CitationRequest {
+    fn from(style: CitationStyle) -> Self {
+        CitationRequest::new(style)
+    }
+}
+
+impl Default for CitationRequest {
+    fn default() -> Self {
+        CitationRequest::new(CitationStyle::Apa)
+    }
+}
+
+/// The content a DOI resolved to for a [CnFormat]
+#[derive(Debug, Clone)]
+pub struct CnResponse {
+    /// the DOI that was resolved
+    pub doi: Doi,
+    /// the requested format
+    pub format: CnFormat,
+    /// the `Content-Type` the agency answered with
+    pub content_type: Option<String>,
+    /// the url the request ended up at after following the redirects
+    pub url: String,
+    /// the raw body
+    pub body: bytes::Bytes,
+}
+
+impl CnResponse {
+    /// the body as text
+    ///
+    /// # Errors
+    ///
+    /// Fails if the body is not valid utf-8
+    pub fn text(&self) -> Result<&str> {
+        std::str::from_utf8(&self.body).map_err(|e| {
+            ErrorKind::InvalidField {
+                msg: format!("{} content of {} is not utf-8: {}", self.format, self.doi, e),
+            }
+            .into()
+        })
+    }
+
+    /// deserialize the json body of [CnFormat::CiteProcJson] and [CnFormat::CiteProcJsonIsh], like into a
+    /// [crate::response::csl::CslItem]
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+
+    /// parse the xml body of [CnFormat::CrossrefXml] and [CnFormat::CrossrefTdm]
+    pub fn unixref(&self) -> Result<crate::response::unixref::UnixrefRecord> {
+        Ok(crate::response::unixref::UnixrefRecord::parse(self.text()?)?)
+    }
+
+    /// the raw body
+    pub fn into_bytes(self) -> bytes::Bytes {
+        self.body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats() {
+        assert_eq!("application/x-bibtex", CnFormat::BibTex.mime_type());
+        assert_eq!("application/vnd.datacite.datacite+xml", CnFormat::DataciteXml.header());
+        for format in CnFormat::ALL.iter() {
+            assert!(!format.header().is_empty());
+            assert_eq!(*format, format.name().parse::<CnFormat>().unwrap());
+        }
+    }
+
+    #[test]
+    fn supported_by_agency() {
+        assert!(CnFormat::CrossrefTdm.supported_by("crossref"));
+        assert!(!CnFormat::DataciteXml.supported_by("crossref"));
+        assert!(CnFormat::DataciteXml.supported_by("DataCite"));
+        assert!(!CnFormat::CrossrefXml.supported_by("datacite"));
+        assert!(CnFormat::Ris.supported_by("medra"));
+        assert!(!CnFormat::CrossrefXml.supported_by("medra"));
+    }
+
+    #[test]
+    fn citation_request_header() {
+        assert_eq!(
+            "text/x-bibliography; style=ieee",
+            CitationRequest::new(CitationStyle::Ieee).header()
+        );
+        let request = CitationRequest::new("elsevier-vancouver").locale("fr-FR");
+        assert_eq!(CitationStyle::Unknown("elsevier-vancouver".to_string()), request.style);
+        assert_eq!("text/x-bibliography; style=elsevier-vancouver; locale=fr-FR", request.header());
+    }
 }
@@ -1,3 +1,4 @@
+use crossref::harvest::{Compression, HarvestWriter};
 use crossref::{query::*, Crossref, Order, Sort, WorkResultControl, WorksQuery};
 use std::{fs, path::PathBuf};
 use structopt::StructOpt;
@@ -39,6 +40,8 @@ enum App {
         )]
         deep_page: bool,
         #[structopt(flatten)]
+        harvest: HarvestOpts,
+        #[structopt(flatten)]
         opts: Opts,
         #[structopt(subcommand)]
         combined: Option<Combined>,
@@ -159,6 +162,7 @@ impl App {
                 opts,
                 combined,
                 deep_page,
+                harvest,
             } => {
                 if let Some(id) = &opts.id {
                     Ok(serde_json::to_writer_pretty(
@@ -211,10 +215,14 @@ impl App {
                         };
 
                         if *deep_page {
-                            Ok(serde_json::to_writer_pretty(
-                                writer,
-                                &client.deep_page(query).into_work_iter().collect::<Vec<_>>(),
-                            )?)
+                            // the pages are streamed into the archive, a deep paged query may
+                            // return millions of works
+                            let mut archive = harvest.writer()?;
+                            for work in client.deep_page(query)?.into_work_iter() {
+                                archive.write(&work)?;
+                            }
+                            archive.finish()?;
+                            Ok(())
                         } else {
                             Ok(serde_json::to_writer_pretty(writer, &client.works(query)?)?)
                         }
@@ -259,6 +267,50 @@ struct Out {
     silent: bool,
 }
 
+#[derive(Debug, StructOpt)]
+struct HarvestOpts {
+    #[structopt(
+        long = "harvest-dir",
+        parse(from_os_str),
+        default_value = ".",
+        help = "directory the deep paged works are written to as JSON Lines files"
+    )]
+    dir: PathBuf,
+    #[structopt(
+        long = "harvest-name",
+        default_value = "works",
+        help = "name of the JSON Lines files, like works-00000.jsonl"
+    )]
+    name: String,
+    #[structopt(
+        long = "compression",
+        default_value = "none",
+        help = "compression of the JSON Lines files: none, gzip or zstd"
+    )]
+    compression: Compression,
+    #[structopt(long = "max-records", help = "start a new file after this many works")]
+    max_records: Option<usize>,
+    #[structopt(
+        long = "max-bytes",
+        help = "start a new file after this many bytes of json"
+    )]
+    max_bytes: Option<u64>,
+}
+
+impl HarvestOpts {
+    pub fn writer(&self) -> crossref::Result<HarvestWriter> {
+        let mut writer =
+            HarvestWriter::new(&self.dir, &self.name)?.compression(self.compression);
+        if let Some(max) = self.max_records {
+            writer = writer.max_records(max);
+        }
+        if let Some(max) = self.max_bytes {
+            writer = writer.max_bytes(max);
+        }
+        Ok(writer)
+    }
+}
+
 #[derive(Debug, StructOpt)]
 struct ClientOpts {
     #[structopt(
@@ -1,7 +1,9 @@
+use crate::cn::CnFormat;
 use crate::query::ResourceComponent;
 use crate::response::MessageType;
 use failure::{Backtrace, Compat, Context, Fail};
 use serde::{de, ser};
+use std::convert::Infallible;
 use std::{fmt, result};
 
 /// A type alias for handling errors throughout crossref.
@@ -86,18 +88,52 @@ pub enum ErrorKind {
     /// When crossref could not find anything
     #[fail(display = "Nothing was found for resource `{}`", resource)]
     ResourceNotFound { resource: Box<ResourceComponent> },
+    /// if reading or writing a file failed
+    #[fail(display = "io error: {}", error)]
+    Io { error: std::io::Error },
+
     /// if a error in serde occurred
     #[fail(display = "invalid serde: {}", error)]
     Serde { error: serde_json::Error },
 
+    /// if a DOI could not be validated
     #[fail(display = "{}", error)]
     DoiValidationError { error: String },
 
+    /// if an identifier like an ISSN or ORCID could not be validated
+    #[fail(display = "invalid {}: {}", kind, error)]
+    IdentifierValidationError {
+        /// the kind of identifier, like `ISSN`
+        kind: String,
+        /// the notification
+        error: String,
+    },
+
     #[fail(display = "{}", error)]
     ClientError { error: String },
 
-    #[fail(display = "{}", error)]
-    InvalidResultControl { error: String },
+    /// if the registration agency of a DOI does not serve the requested content negotiation format
+    #[fail(
+        display = "{} is not available for {}, registered with {}",
+        format, doi, agency
+    )]
+    UnsupportedFormat {
+        /// the requested format
+        format: CnFormat,
+        /// the DOI that was resolved
+        doi: String,
+        /// the id of the registration agency of the DOI, `unknown` if it could not be determined
+        agency: String,
+    },
+
+    /// if a query parameter can not be used with the targeted route
+    #[fail(display = "parameter `{}` is not supported: {}", param, msg)]
+    UnsupportedParameter {
+        /// the name of the parameter
+        param: String,
+        /// the notification
+        msg: String,
+    },
 }
 
 impl From<ErrorKind> for Error {
@@ -112,12 +148,24 @@ impl From<Context<ErrorKind>> for Error {
     }
 }
 
+impl From<Infallible> for Error {
+    fn from(never: Infallible) -> Error {
+        match never {}
+    }
+}
+
 impl From<serde_json::Error> for Error {
     fn from(error: serde_json::Error) -> Error {
         ErrorKind::Serde { error }.into()
     }
 }
 
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Error {
+        ErrorKind::Io { error }.into()
+    }
+}
+
 impl From<reqwest::Error> for Error {
     fn from(reqwest: reqwest::Error) -> Error {
         ErrorKind::ReqWest { reqwest }.into()
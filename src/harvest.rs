@@ -0,0 +1,550 @@
+//! Archives of harvested works as [JSON Lines](https://jsonlines.org), one `Work` per line.
+//!
+//! A [HarvestWriter] streams works into numbered files like `works-00000.jsonl.gz`, optionally
+//! compressed with gzip (feature `gzip`) or zstd (feature `zstd`) and rotated after a number of
+//! records or bytes. A [HarvestReader] reads such files back, so a harvest can be processed offline.
+
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::error::{ErrorKind, Result};
+use crate::response::work::{ParseMode, Work, WorkList};
+
+/// The compression of harvest files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Compression {
+    /// plain `.jsonl`
+    #[default]
+    None,
+    /// `.jsonl.gz`, writing and reading requires the feature `gzip`
+    Gzip,
+    /// `.jsonl.zst`, writing and reading requires the feature `zstd`
+    Zstd,
+}
+
+impl Compression {
+    /// the file extension of harvest files with this compression
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Compression::None => "jsonl",
+            Compression::Gzip => "jsonl.gz",
+            Compression::Zstd => "jsonl.zst",
+        }
+    }
+
+    /// whether the feature this compression requires is enabled
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            Compression::None => true,
+            Compression::Gzip => cfg!(feature = "gzip"),
+            Compression::Zstd => cfg!(feature = "zstd"),
+        }
+    }
+
+    /// the compression of the file at `path` by its extension, `None` if it is no harvest file
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?;
+        [Compression::None, Compression::Gzip, Compression::Zstd]
+            .into_iter()
+            .find(|c| name.ends_with(&format!(".{}", c.extension())))
+    }
+
+    /// the error for reading or writing files of a compression whose feature is not enabled
+    fn disabled(&self) -> io::Error {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("`.{}` files require a crate feature that is not enabled", self.extension()),
+        )
+    }
+}
+
+impl std::str::FromStr for Compression {
+    type Err = ErrorKind;
+
+    /// `none`, `gzip` or `zstd`, also accepts the extensions `gz` and `zst`
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "none" | "jsonl" => Ok(Compression::None),
+            "gzip" | "gz" => Ok(Compression::Gzip),
+            "zstd" | "zst" => Ok(Compression::Zstd),
+            _ => Err(ErrorKind::InvalidField {
+                msg: format!("unknown compression `{}`", s),
+            }),
+        }
+    }
+}
+
+/// the index of the harvest file at `path` if it is one of the files called `name`, like `3` for
+/// `works-00003.jsonl.gz`
+fn file_index(path: &Path, name: &str) -> Option<usize> {
+    Compression::from_path(path)?;
+    let file_name = path.file_name()?.to_str()?;
+    let (index, _) = file_name.strip_prefix(name)?.strip_prefix('-')?.split_once('.')?;
+    if index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    index.parse().ok()
+}
+
+/// a file being written, with its encoder
+enum Sink {
+    Plain(BufWriter<File>),
+    #[cfg(feature = "gzip")]
+    Gzip(flate2::write::GzEncoder<BufWriter<File>>),
+    #[cfg(feature = "zstd")]
+    Zstd(zstd::Encoder<'static, BufWriter<File>>),
+}
+
+impl Sink {
+    fn create(path: &Path, compression: Compression) -> io::Result<Self> {
+        if !compression.is_enabled() {
+            return Err(compression.disabled());
+        }
+        let file = BufWriter::new(File::options().write(true).create_new(true).open(path)?);
+        Ok(match compression {
+            #[cfg(feature = "gzip")]
+            Compression::Gzip => Sink::Gzip(flate2::write::GzEncoder::new(file, flate2::Compression::default())),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => Sink::Zstd(zstd::Encoder::new(file, 0)?),
+            _ => Sink::Plain(file),
+        })
+    }
+
+    fn writer(&mut self) -> &mut dyn Write {
+        match self {
+            Sink::Plain(w) => w,
+            #[cfg(feature = "gzip")]
+            Sink::Gzip(w) => w,
+            #[cfg(feature = "zstd")]
+            Sink::Zstd(w) => w,
+        }
+    }
+
+    /// write the trailer of the compression and flush the file
+    fn finish(self) -> io::Result<()> {
+        match self {
+            Sink::Plain(mut w) => w.flush(),
+            #[cfg(feature = "gzip")]
+            Sink::Gzip(w) => w.finish()?.flush(),
+            #[cfg(feature = "zstd")]
+            Sink::Zstd(w) => w.finish()?.flush(),
+        }
+    }
+}
+
+/// Streams works into JSON Lines files in a directory, rotating to a new file when a limit is reached
+///
+/// The files are named `{name}-{index}.{extension}`, with a five digit index starting at `0`, or
+/// after the highest index already in the directory so an existing archive is continued and never
+/// overwritten. Works that kept their unparsed json are written as returned by crossref.
+/// Call [HarvestWriter::finish] when done, dropping the writer finishes the last file but ignores
+/// any error.
+///
+/// # Example
+///
+/// ```edition2018
+/// use crossref_rs::harvest::{Compression, HarvestWriter};
+/// # fn run(works: Vec<crossref_rs::Work>) -> crossref_rs::Result<()> {
+/// let mut writer = HarvestWriter::new("harvest", "works")?.max_records(100_000);
+/// writer.write_all(&works)?;
+/// let files = writer.finish()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct HarvestWriter {
+    dir: PathBuf,
+    name: String,
+    compression: Compression,
+    max_records: Option<usize>,
+    max_bytes: Option<u64>,
+    sink: Option<Sink>,
+    next_index: usize,
+    file_records: usize,
+    file_bytes: u64,
+    records: usize,
+    files: Vec<PathBuf>,
+}
+
+impl HarvestWriter {
+    /// a writer for files called `name` in `dir`, which is created if missing
+    pub fn new<P: Into<PathBuf>>(dir: P, name: &str) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        let mut next_index = 0;
+        for entry in fs::read_dir(&dir)? {
+            if let Some(index) = file_index(&entry?.path(), name) {
+                next_index = next_index.max(index + 1);
+            }
+        }
+        Ok(HarvestWriter {
+            dir,
+            name: name.to_string(),
+            compression: Compression::None,
+            max_records: None,
+            max_bytes: None,
+            sink: None,
+            next_index,
+            file_records: 0,
+            file_bytes: 0,
+            records: 0,
+            files: Vec::new(),
+        })
+    }
+
+    /// compress the files with `compression`, applies from the next file on
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// start a new file after `max` records
+    pub fn max_records(mut self, max: usize) -> Self {
+        self.max_records = Some(max.max(1));
+        self
+    }
+
+    /// start a new file once `max` bytes of json were written to the current one, before compression
+    pub fn max_bytes(mut self, max: u64) -> Self {
+        self.max_bytes = Some(max);
+        self
+    }
+
+    /// append `work` as a single line, its unparsed json if it was kept
+    pub fn write(&mut self, work: &Work) -> Result<()> {
+        let mut line = match &work.raw {
+            Some(raw) => serde_json::to_vec(raw)?,
+            None => serde_json::to_vec(work)?,
+        };
+        line.push(b'\n');
+
+        let full = self.max_records.is_some_and(|max| self.file_records >= max)
+            || self.max_bytes.is_some_and(|max| self.file_bytes >= max);
+        if full {
+            self.close_file()?;
+        }
+        if self.sink.is_none() {
+            let path = self.dir.join(format!(
+                "{}-{:05}.{}",
+                self.name,
+                self.next_index,
+                self.compression.extension()
+            ));
+            self.sink = Some(Sink::create(&path, self.compression)?);
+            self.next_index += 1;
+            self.files.push(path);
+        }
+        let sink = self.sink.as_mut().expect("opened above");
+        sink.writer().write_all(&line)?;
+        self.file_records += 1;
+        self.file_bytes += line.len() as u64;
+        self.records += 1;
+        Ok(())
+    }
+
+    /// append all `works`
+    pub fn write_all<'a, I: IntoIterator<Item = &'a Work>>(&mut self, works: I) -> Result<()> {
+        for work in works {
+            self.write(work)?;
+        }
+        Ok(())
+    }
+
+    /// append the items of a page of works
+    pub fn write_list(&mut self, list: &WorkList) -> Result<()> {
+        self.write_all(&list.items)
+    }
+
+    /// the number of works written so far
+    pub fn records(&self) -> usize {
+        self.records
+    }
+
+    /// the files written so far, including the current one
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// finish the current file and return all files that were written
+    pub fn finish(mut self) -> Result<Vec<PathBuf>> {
+        self.close_file()?;
+        Ok(std::mem::take(&mut self.files))
+    }
+
+    fn close_file(&mut self) -> io::Result<()> {
+        self.file_records = 0;
+        self.file_bytes = 0;
+        match self.sink.take() {
+            Some(sink) => sink.finish(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for HarvestWriter {
+    fn drop(&mut self) {
+        let _ = self.close_file();
+    }
+}
+
+/// Reads the works of JSON Lines harvest files back, in the order of the files and lines
+///
+/// Empty lines are skipped, every other line is parsed with [Work::from_json].
+pub struct HarvestReader {
+    files: std::vec::IntoIter<PathBuf>,
+    lines: Option<(PathBuf, io::Lines<Box<dyn BufRead>>)>,
+    line: usize,
+    mode: ParseMode,
+}
+
+impl HarvestReader {
+    /// a reader of the single file at `path`
+    pub fn open<P: Into<PathBuf>>(path: P) -> Self {
+        HarvestReader::from_files(vec![path.into()])
+    }
+
+    /// a reader of all `files` in order
+    pub fn from_files(files: Vec<PathBuf>) -> Self {
+        HarvestReader {
+            files: files.into_iter(),
+            lines: None,
+            line: 0,
+            mode: ParseMode::Strict,
+        }
+    }
+
+    /// a reader of the harvest files called `name` in `dir`, in the order of their index
+    pub fn from_dir<P: AsRef<Path>>(dir: P, name: &str) -> Result<Self> {
+        let mut files = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if let Some(index) = file_index(&path, name).filter(|_| path.is_file()) {
+                files.push((index, path));
+            }
+        }
+        files.sort();
+        Ok(HarvestReader::from_files(files.into_iter().map(|(_, path)| path).collect()))
+    }
+
+    /// parse the works in `mode`, `ParseMode::Strict` by default
+    pub fn mode(mut self, mode: ParseMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    fn open_file(path: &Path) -> Result<Box<dyn BufRead>> {
+        let compression = Compression::from_path(path).ok_or_else(|| ErrorKind::InvalidField {
+            msg: format!("{} is no harvest file", path.display()),
+        })?;
+        if !compression.is_enabled() {
+            return Err(compression.disabled().into());
+        }
+        let file = BufReader::new(File::open(path)?);
+        Ok(match compression {
+            #[cfg(feature = "gzip")]
+            Compression::Gzip => Box::new(BufReader::new(flate2::bufread::MultiGzDecoder::new(file))),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => Box::new(BufReader::new(zstd::Decoder::with_buffer(file)?)),
+            _ => Box::new(file),
+        })
+    }
+}
+
+impl Iterator for HarvestReader {
+    type Item = Result<Work>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some((path, lines)) = &mut self.lines else {
+                let path = self.files.next()?;
+                match HarvestReader::open_file(&path) {
+                    Ok(reader) => self.lines = Some((path, reader.lines())),
+                    Err(e) => return Some(Err(e)),
+                }
+                self.line = 0;
+                continue;
+            };
+            self.line += 1;
+            match lines.next() {
+                None => self.lines = None,
+                Some(Err(e)) => {
+                    // the rest of a file that failed to read, like a truncated archive, is skipped
+                    let msg = format!("{}:{}: {}", path.display(), self.line, e);
+                    self.lines = None;
+                    return Some(Err(ErrorKind::Io {
+                        error: io::Error::new(e.kind(), msg),
+                    }
+                    .into()));
+                }
+                Some(Ok(line)) if line.trim().is_empty() => {}
+                Some(Ok(line)) => {
+                    return Some(Work::from_json(&line, self.mode).map_err(|e| {
+                        ErrorKind::InvalidField {
+                            msg: format!("{}:{}: {}", path.display(), self.line, e),
+                        }
+                        .into()
+                    }))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::work_fixture;
+    use serde_json::{json, Value};
+
+    fn work(i: usize) -> Work {
+        work_fixture(json!({
+            "DOI": format!("10.5555/{}", i),
+            "title": [format!("Work {}", i)],
+            "issued": {"date-parts": [[2019, 2, 1]]},
+        }))
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("crossref-harvest-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn round_trip(name: &str, compression: Compression) {
+        let dir = temp_dir(name);
+        let works: Vec<Work> = (0..5).map(work).collect();
+        let mut writer = HarvestWriter::new(&dir, "works")
+            .unwrap()
+            .compression(compression)
+            .max_records(2);
+        writer.write_all(&works).unwrap();
+        assert_eq!(5, writer.records());
+        let files = writer.finish().unwrap();
+        assert_eq!(3, files.len());
+        assert!(files[2].ends_with(format!("works-00002.{}", compression.extension())));
+
+        let read: Vec<Work> = HarvestReader::from_dir(&dir, "works")
+            .unwrap()
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(
+            works.iter().map(|w| w.doi.to_string()).collect::<Vec<_>>(),
+            read.iter().map(|w| w.doi.to_string()).collect::<Vec<_>>()
+        );
+        assert_eq!(works[4].title, read[4].title);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn jsonl_round_trip() {
+        round_trip("plain", Compression::None);
+        #[cfg(feature = "gzip")]
+        round_trip("gzip", Compression::Gzip);
+        #[cfg(feature = "zstd")]
+        round_trip("zstd", Compression::Zstd);
+        assert_eq!(Compression::Gzip, "gz".parse().unwrap());
+        assert!("brotli".parse::<Compression>().is_err());
+    }
+
+    #[test]
+    fn rotate_by_bytes() {
+        let dir = temp_dir("bytes");
+        let mut writer = HarvestWriter::new(&dir, "works").unwrap().max_bytes(1);
+        writer.write_all(&[work(1), work(2)]).unwrap();
+        assert_eq!(2, writer.finish().unwrap().len());
+
+        fs::write(dir.join("works-00002.jsonl"), "\n{\"DOI\": \"10.5555/3\"}\n").unwrap();
+        let results: Vec<Result<Work>> = HarvestReader::from_dir(&dir, "works").unwrap().collect();
+        assert_eq!(3, results.len());
+        let error = results[2].as_ref().unwrap_err().to_string();
+        assert!(error.contains("works-00002.jsonl:2"), "{}", error);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn continue_existing_archive() {
+        let dir = temp_dir("continue");
+        let mut writer = HarvestWriter::new(&dir, "works").unwrap();
+        writer.write(&work(1)).unwrap();
+        writer.finish().unwrap();
+        fs::write(dir.join("other-00007.jsonl"), "").unwrap();
+
+        let mut writer = HarvestWriter::new(&dir, "works").unwrap();
+        writer.write(&work(2)).unwrap();
+        let files = writer.finish().unwrap();
+        assert!(files[0].ends_with("works-00001.jsonl"));
+
+        let read: Vec<Work> = HarvestReader::from_dir(&dir, "works")
+            .unwrap()
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(2, read.len());
+        assert_eq!("10.5555/2", read[1].doi.to_string());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn full_work_round_trip() {
+        let json = include_str!("../tests/data/work.json");
+        let dir = temp_dir("full");
+        let work = Work::from_json(json, ParseMode::Strict).unwrap();
+        let mut raw = Work::from_json(json, ParseMode::Strict).unwrap();
+        raw.raw = Some(serde_json::from_str(json).unwrap());
+
+        let mut writer = HarvestWriter::new(&dir, "works").unwrap();
+        writer.write_all([&work, &raw]).unwrap();
+        let files = writer.finish().unwrap();
+
+        let read: Vec<Work> = HarvestReader::open(&files[0]).collect::<Result<_>>().unwrap();
+        let value = |work: &Work| serde_json::to_value(work).unwrap();
+        assert_eq!(value(&work), value(&read[0]));
+        assert_eq!(value(&work), value(&read[1]));
+
+        let lines: Vec<Value> = fs::read_to_string(&files[0])
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(raw.raw.as_ref(), Some(&lines[1]));
+        assert_eq!("2017-03-22T00:00:00Z", lines[1]["license"][0]["start"]["date-time"]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    #[test]
+    fn disabled_compression_fails() {
+        let dir = temp_dir("disabled");
+        let mut writer = HarvestWriter::new(&dir, "works")
+            .unwrap()
+            .compression(Compression::Gzip);
+        assert!(writer.write(&work(1)).is_err());
+        assert!(!Compression::Gzip.is_enabled());
+
+        fs::write(dir.join("works-00000.jsonl.gz"), "").unwrap();
+        let results: Vec<Result<Work>> = HarvestReader::from_dir(&dir, "works").unwrap().collect();
+        assert_eq!(1, results.len());
+        assert!(results[0].is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn truncated_gzip_stops_the_file() {
+        let dir = temp_dir("truncated");
+        let mut writer = HarvestWriter::new(&dir, "works")
+            .unwrap()
+            .compression(Compression::Gzip)
+            .max_records(50);
+        writer.write_all(&(0..60).map(work).collect::<Vec<_>>()).unwrap();
+        let files = writer.finish().unwrap();
+        let bytes = fs::read(&files[0]).unwrap();
+        fs::write(&files[0], &bytes[..bytes.len() / 2]).unwrap();
+
+        let results: Vec<Result<Work>> = HarvestReader::from_dir(&dir, "works").unwrap().collect();
+        let errors = results.iter().filter(|r| r.is_err()).count();
+        assert_eq!(1, errors);
+        assert!(results.last().unwrap().is_ok());
+        assert_eq!(10, results.iter().rev().take_while(|r| r.is_ok()).count());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,642 @@
+//! Validated identifiers used by the crossref api.
+//!
+//! Every identifier accepts the common input forms (bare, prefixed or as url) and
+//! normalizes them, so that two identifiers compare equal if they denote the same item.
+//! Deserialized identifiers keep the string they were read from and serialize back to it.
+//!
+//! # Example
+//!
+//! ```edition2018
+//! use crossref_rs::ident::{Doi, Issn, Orcid};
+//! # fn run() -> Result<(), crossref_rs::Error> {
+//! let doi: Doi = "https://doi.org/10.1037/0003-066X.59.1.29".parse()?;
+//! assert_eq!("10.1037/0003-066x.59.1.29", doi.as_str());
+//!
+//! let issn: Issn = "0003066x".parse()?;
+//! assert_eq!("0003-066X", issn.as_str());
+//!
+//! let orcid: Orcid = "http://orcid.org/0000-0002-1825-0097".parse()?;
+//! assert_eq!("0000-0002-1825-0097", orcid.as_str());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::ErrorKind;
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// the registrant code that prefixes all DOIs of the Open Funder Registry
+const FUNDER_REGISTRY_PREFIX: &str = "10.13039/";
+
+/// removes the first matching prefix of `prefixes`, ignoring ascii case
+fn strip_prefixes<'a>(s: &'a str, prefixes: &[&str]) -> &'a str {
+    for prefix in prefixes {
+        if s.len() >= prefix.len()
+            && s.is_char_boundary(prefix.len())
+            && s[..prefix.len()].eq_ignore_ascii_case(prefix)
+        {
+            return &s[prefix.len()..];
+        }
+    }
+    s
+}
+
+/// all url forms a resolver can be addressed with
+fn strip_url(s: &str, hosts: &[&str]) -> String {
+    let s = strip_prefixes(s.trim(), &["https://", "http://"]);
+    let s = strip_prefixes(s, &["www."]);
+    for host in hosts {
+        let stripped = strip_prefixes(s, &[host]);
+        if stripped.len() != s.len() {
+            return stripped.trim_start_matches('/').to_string();
+        }
+    }
+    s.to_string()
+}
+
+fn invalid(kind: &str, id: &str, reason: &str) -> ErrorKind {
+    ErrorKind::IdentifierValidationError {
+        kind: kind.to_string(),
+        error: format!("`{}` {}", id, reason),
+    }
+}
+
+/// the digit value of an ascii digit, `X` represents `10` if allowed
+fn digit(c: char, allow_x: bool) -> Option<u32> {
+    match c {
+        'x' | 'X' if allow_x => Some(10),
+        c => c.to_digit(10),
+    }
+}
+
+macro_rules! impl_string_ident {
+    ($($name:ident,)*) => {
+        $(
+        impl $name {
+            /// the normalized identifier
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// the identifier as it was deserialized, the normalized identifier otherwise
+            pub fn original(&self) -> &str {
+                self.1.as_deref().unwrap_or(&self.0)
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl std::hash::Hash for $name {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.0.hash(state)
+            }
+        }
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> String {
+                id.0
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.original())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                let id: $name = s.parse().map_err(de::Error::custom)?;
+                let original = (s != id.0).then_some(s);
+                Ok($name(id.0, original))
+            }
+        }
+        )+
+        impl_ident_conversions!($($name,)*);
+    };
+}
+
+macro_rules! impl_ident_conversions {
+    ($($name:ident,)*) => {
+        $(
+        impl TryFrom<&str> for $name {
+            type Error = ErrorKind;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = ErrorKind;
+
+            fn try_from(s: String) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
+        impl TryFrom<&String> for $name {
+            type Error = ErrorKind;
+
+            fn try_from(s: &String) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(id: &$name) -> $name {
+                id.clone()
+            }
+        }
+        )+
+    };
+}
+
+/// A [DOI](https://www.doi.org/), normalized to its lowercase form without resolver.
+///
+/// Accepts `10.1037/abc`, `doi:10.1037/abc` and the resolver urls `https://doi.org/10.1037/abc`
+/// or `http://dx.doi.org/10.1037/abc`.
+#[derive(Debug, Clone)]
+pub struct Doi(String, Option<String>);
+
+impl Doi {
+    /// the DOI prefix, e.g. `10.1037` for `10.1037/abc`
+    pub fn prefix(&self) -> Prefix {
+        let (prefix, _) = self.0.split_once('/').expect("validated DOI contains a `/`");
+        Prefix(prefix.to_string(), None)
+    }
+
+    /// the part of the DOI after the prefix
+    pub fn suffix(&self) -> &str {
+        let (_, suffix) = self.0.split_once('/').expect("validated DOI contains a `/`");
+        suffix
+    }
+
+    /// the resolvable url `https://doi.org/{doi}`
+    pub fn url(&self) -> String {
+        format!("https://doi.org/{}", self.0)
+    }
+}
+
+impl FromStr for Doi {
+    type Err = ErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let doi = strip_url(s, &["doi.org/", "dx.doi.org/"]);
+        let doi = strip_prefixes(&doi, &["doi:"]).trim().to_lowercase();
+        let err = |reason: &str| ErrorKind::DoiValidationError {
+            error: format!("invalid DOI `{}`: {}", s, reason),
+        };
+        let (prefix, suffix) = doi
+            .split_once('/')
+            .ok_or_else(|| err("missing `/` between prefix and suffix"))?;
+        if !is_doi_prefix(prefix) {
+            return Err(err("prefix must be of the form `10.NNNN`"));
+        }
+        if suffix.is_empty() {
+            return Err(err("empty suffix"));
+        }
+        if suffix.chars().any(char::is_whitespace) {
+            return Err(err("suffix contains whitespace"));
+        }
+        Ok(Doi(doi, None))
+    }
+}
+
+fn is_doi_prefix(prefix: &str) -> bool {
+    prefix
+        .strip_prefix("10.")
+        .map(|code| {
+            !code.is_empty()
+                && code
+                    .split('.')
+                    .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+        })
+        .unwrap_or_default()
+}
+
+/// A DOI prefix that identifies the owner of a DOI, e.g. `10.1016`.
+///
+/// Accepts `10.1016`, `doi:10.1016` and the crossref url `http://id.crossref.org/prefix/10.1016`.
+#[derive(Debug, Clone)]
+pub struct Prefix(String, Option<String>);
+
+impl FromStr for Prefix {
+    type Err = ErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let prefix = strip_url(s, &["id.crossref.org/prefix/"]);
+        let prefix = strip_prefixes(&prefix, &["doi:"]).trim();
+        if is_doi_prefix(prefix) {
+            Ok(Prefix(prefix.to_string(), None))
+        } else {
+            Err(invalid("prefix", s, "is not of the form `10.NNNN`"))
+        }
+    }
+}
+
+/// An [ISSN](https://www.issn.org/) with verified check digit, normalized to `NNNN-NNNC`.
+///
+/// Accepts the forms `0003-066X`, `0003066x` and `ISSN 0003-066X`.
+#[derive(Debug, Clone)]
+pub struct Issn(String, Option<String>);
+
+impl FromStr for Issn {
+    type Err = ErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let issn = strip_prefixes(s.trim(), &["issn:", "issn"]);
+        let chars: Vec<char> = issn
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        if chars.len() != 8 {
+            return Err(invalid("ISSN", s, "must consist of 8 digits"));
+        }
+        let mut sum = 0;
+        for (i, c) in chars.iter().enumerate() {
+            let d = digit(*c, i == 7).ok_or_else(|| invalid("ISSN", s, "contains non digits"))?;
+            if i < 7 {
+                sum += d * (8 - i as u32);
+            } else if (11 - sum % 11) % 11 != d {
+                return Err(invalid("ISSN", s, "has an invalid check digit"));
+            }
+        }
+        let issn: String = chars.iter().collect();
+        Ok(Issn(format!("{}-{}", &issn[..4], &issn[4..]), None))
+    }
+}
+
+/// An [ISBN](https://www.isbn-international.org/) in its 10 or 13 digit form with verified check digit.
+///
+/// Hyphens and spaces are removed, `ISBN 978-3-16-148410-0` is normalized to `9783161484100`.
+#[derive(Debug, Clone)]
+pub struct Isbn(String, Option<String>);
+
+impl Isbn {
+    /// the 13 digit form of the ISBN, ISBN-10 are converted by prepending `978`
+    pub fn to_isbn13(&self) -> Isbn {
+        if self.0.len() == 13 {
+            return self.clone();
+        }
+        let mut isbn = format!("978{}", &self.0[..9]);
+        let sum: u32 = isbn
+            .chars()
+            .enumerate()
+            .map(|(i, c)| c.to_digit(10).unwrap() * if i % 2 == 0 { 1 } else { 3 })
+            .sum();
+        isbn.push_str(&((10 - sum % 10) % 10).to_string());
+        Isbn(isbn, None)
+    }
+}
+
+impl FromStr for Isbn {
+    type Err = ErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let isbn = strip_url(s, &["id.crossref.org/isbn/"]);
+        let isbn = strip_prefixes(isbn.trim(), &["isbn-13:", "isbn-10:", "isbn:", "isbn"]);
+        let chars: Vec<char> = isbn
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        let valid = match chars.len() {
+            10 => {
+                let mut sum = 0;
+                for (i, c) in chars.iter().enumerate() {
+                    let d = digit(*c, i == 9)
+                        .ok_or_else(|| invalid("ISBN", s, "contains non digits"))?;
+                    sum += d * (10 - i as u32);
+                }
+                sum % 11 == 0
+            }
+            13 => {
+                let mut sum = 0;
+                for (i, c) in chars.iter().enumerate() {
+                    let d = digit(*c, false)
+                        .ok_or_else(|| invalid("ISBN", s, "contains non digits"))?;
+                    sum += d * if i % 2 == 0 { 1 } else { 3 };
+                }
+                sum % 10 == 0
+            }
+            _ => return Err(invalid("ISBN", s, "must consist of 10 or 13 digits")),
+        };
+        if valid {
+            Ok(Isbn(chars.into_iter().collect(), None))
+        } else {
+            Err(invalid("ISBN", s, "has an invalid check digit"))
+        }
+    }
+}
+
+/// An [ORCID](https://orcid.org) iD with verified check digit, normalized to `NNNN-NNNN-NNNN-NNNC`.
+///
+/// Accepts the bare iD with or without hyphens and the urls `https://orcid.org/{id}`.
+#[derive(Debug, Clone)]
+pub struct Orcid(String, Option<String>);
+
+impl Orcid {
+    /// the url form `https://orcid.org/{id}`
+    pub fn url(&self) -> String {
+        format!("https://orcid.org/{}", self.0)
+    }
+}
+
+impl FromStr for Orcid {
+    type Err = ErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let orcid = strip_url(s, &["orcid.org/"]);
+        let chars: Vec<char> = orcid
+            .chars()
+            .filter(|c| *c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        if chars.len() != 16 {
+            return Err(invalid("ORCID", s, "must consist of 16 digits"));
+        }
+        // ISO 7064 11,2
+        let mut total = 0;
+        for (i, c) in chars.iter().enumerate() {
+            let d = digit(*c, i == 15).ok_or_else(|| invalid("ORCID", s, "contains non digits"))?;
+            if i < 15 {
+                total = (total + d) * 2;
+            } else if (12 - total % 11) % 11 != d {
+                return Err(invalid("ORCID", s, "has an invalid check digit"));
+            }
+        }
+        let orcid = chars
+            .chunks(4)
+            .map(|c| c.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("-");
+        Ok(Orcid(orcid, None))
+    }
+}
+
+/// The id of a funder in the [Open Funder Registry](https://www.crossref.org/services/funder-registry/).
+///
+/// Accepts the bare id `100000015`, the funder DOI `10.13039/100000015` and its resolver urls.
+#[derive(Debug, Clone)]
+pub struct FunderId(String, Option<String>);
+
+impl FunderId {
+    /// the funder DOI `10.13039/{id}`
+    pub fn doi(&self) -> Doi {
+        Doi(format!("{}{}", FUNDER_REGISTRY_PREFIX, self.0), None)
+    }
+}
+
+impl FromStr for FunderId {
+    type Err = ErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = strip_url(s, &["doi.org/", "dx.doi.org/"]);
+        let id = strip_prefixes(&id, &["doi:"]).trim();
+        let id = strip_prefixes(id, &[FUNDER_REGISTRY_PREFIX]);
+        if !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()) {
+            Ok(FunderId(id.to_string(), None))
+        } else {
+            Err(invalid("funder id", s, "is not a numeric funder registry id"))
+        }
+    }
+}
+
+impl From<FunderId> for Doi {
+    fn from(id: FunderId) -> Doi {
+        id.doi()
+    }
+}
+
+impl_string_ident!(Doi, Prefix, Issn, Isbn, Orcid, FunderId,);
+
+/// The numeric id of a crossref member.
+///
+/// Accepts `78` and the crossref url `http://id.crossref.org/member/78`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub struct MemberId(u64);
+
+impl MemberId {
+    /// create a new `MemberId`
+    pub fn new(id: u64) -> Self {
+        MemberId(id)
+    }
+
+    /// the numeric id
+    pub fn id(&self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for MemberId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<u64> for MemberId {
+    fn from(id: u64) -> MemberId {
+        MemberId(id)
+    }
+}
+
+impl FromStr for MemberId {
+    type Err = ErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        strip_url(s, &["id.crossref.org/member/"])
+            .trim()
+            .parse()
+            .map(MemberId)
+            .map_err(|_| invalid("member id", s, "is not a numeric member id"))
+    }
+}
+
+impl_ident_conversions!(MemberId,);
+
+impl<'de> Deserialize<'de> for MemberId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MemberIdVisitor;
+
+        impl<'de> Visitor<'de> for MemberIdVisitor {
+            type Value = MemberId;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a numeric member id")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<MemberId, E> {
+                Ok(MemberId(v))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<MemberId, E> {
+                u64::try_from(v)
+                    .map(MemberId)
+                    .map_err(|_| E::custom(format!("invalid member id `{}`", v)))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<MemberId, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(MemberIdVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_doi() {
+        let expected = "10.1037/0003-066x.59.1.29";
+        for doi in &[
+            "10.1037/0003-066X.59.1.29",
+            "doi:10.1037/0003-066X.59.1.29",
+            "DOI: 10.1037/0003-066X.59.1.29",
+            "https://doi.org/10.1037/0003-066X.59.1.29",
+            "http://dx.doi.org/10.1037/0003-066x.59.1.29",
+            " 10.1037/0003-066x.59.1.29 ",
+        ] {
+            assert_eq!(expected, doi.parse::<Doi>().unwrap().as_str());
+        }
+        let doi: Doi = expected.parse().unwrap();
+        assert_eq!("10.1037", doi.prefix().as_str());
+        assert_eq!("0003-066x.59.1.29", doi.suffix());
+
+        for doi in &["", "10.1037", "11.1037/abc", "10./abc", "10.1037/", "10.1037/a b"] {
+            assert!(matches!(
+                doi.parse::<Doi>(),
+                Err(ErrorKind::DoiValidationError { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn parse_issn() {
+        for issn in &["0003-066X", "0003066x", "ISSN 0003-066X", " 0003-066x"] {
+            assert_eq!("0003-066X", issn.parse::<Issn>().unwrap().as_str());
+        }
+        assert_eq!("1935-990X", "1935990X".parse::<Issn>().unwrap().as_str());
+        assert!("0003-0661".parse::<Issn>().is_err());
+        assert!("0003-06X6".parse::<Issn>().is_err());
+        assert!("0003-066".parse::<Issn>().is_err());
+    }
+
+    #[test]
+    fn parse_isbn() {
+        let isbn: Isbn = "ISBN 978-3-16-148410-0".parse().unwrap();
+        assert_eq!("9783161484100", isbn.as_str());
+        let isbn: Isbn = "0-306-40615-2".parse().unwrap();
+        assert_eq!("0306406152", isbn.as_str());
+        assert_eq!("9780306406157", isbn.to_isbn13().as_str());
+        assert_eq!("080442957X", "0-8044-2957-x".parse::<Isbn>().unwrap().as_str());
+        assert!("978-3-16-148410-1".parse::<Isbn>().is_err());
+        assert!("0-306-40615-3".parse::<Isbn>().is_err());
+        assert!("978-3-16".parse::<Isbn>().is_err());
+    }
+
+    #[test]
+    fn parse_orcid() {
+        for orcid in &[
+            "0000-0002-1825-0097",
+            "0000000218250097",
+            "https://orcid.org/0000-0002-1825-0097",
+            "http://orcid.org/0000-0002-1825-0097",
+        ] {
+            assert_eq!("0000-0002-1825-0097", orcid.parse::<Orcid>().unwrap().as_str());
+        }
+        assert_eq!(
+            "0000-0002-9079-593X",
+            "0000-0002-9079-593x".parse::<Orcid>().unwrap().as_str()
+        );
+        assert!("0000-0002-1825-0098".parse::<Orcid>().is_err());
+        assert!("0000-0002-1825".parse::<Orcid>().is_err());
+    }
+
+    #[test]
+    fn parse_funder_member_prefix() {
+        for id in &[
+            "100000015",
+            "10.13039/100000015",
+            "http://dx.doi.org/10.13039/100000015",
+        ] {
+            let id: FunderId = id.parse().unwrap();
+            assert_eq!("100000015", id.as_str());
+            assert_eq!("10.13039/100000015", id.doi().as_str());
+        }
+        assert!("10.1037/100000015".parse::<FunderId>().is_err());
+
+        assert_eq!(MemberId::new(78), "78".parse().unwrap());
+        assert_eq!(
+            MemberId::new(78),
+            "http://id.crossref.org/member/78".parse().unwrap()
+        );
+        assert!("member".parse::<MemberId>().is_err());
+
+        assert_eq!(
+            "10.1016",
+            "http://id.crossref.org/prefix/10.1016"
+                .parse::<Prefix>()
+                .unwrap()
+                .as_str()
+        );
+        assert!("10.abc".parse::<Prefix>().is_err());
+    }
+
+    #[test]
+    fn serde_idents() {
+        let doi: Doi = serde_json::from_str(r#""10.1037/ABC""#).unwrap();
+        assert_eq!("10.1037/abc", doi.as_str());
+        assert_eq!(r#""10.1037/ABC""#, serde_json::to_string(&doi).unwrap());
+        assert_eq!(doi, "10.1037/abc".parse().unwrap());
+        let orcid: Orcid = serde_json::from_str(r#""http://orcid.org/0000-0002-1825-0097""#).unwrap();
+        assert_eq!("0000-0002-1825-0097", orcid.as_str());
+        assert_eq!(
+            r#""http://orcid.org/0000-0002-1825-0097""#,
+            serde_json::to_string(&orcid).unwrap()
+        );
+        let doi: Doi = "10.1037/ABC".parse().unwrap();
+        assert_eq!(r#""10.1037/abc""#, serde_json::to_string(&doi).unwrap());
+        assert!(serde_json::from_str::<Issn>(r#""0003-0661""#).is_err());
+        assert_eq!(MemberId::new(15), serde_json::from_str(r#""15""#).unwrap());
+        assert_eq!(MemberId::new(15), serde_json::from_str("15").unwrap());
+        assert_eq!("15", serde_json::to_string(&MemberId::new(15)).unwrap());
+    }
+}
@@ -111,13 +111,16 @@
 //!
 //! let agency = client.work_agency("10.1037/0003-066X.59.1.29")?;
 //!
-//! let funder = client.funder("funder_id")?;
+//! let funder = client.funder("100000015")?;
 //!
-//! let member = client.member("member_id")?;
+//! let member = client.member("98")?;
 //! # Ok(())
 //! # }
 //! ```
 //!
+//! Identifiers are validated and normalized before a request is sent, the methods accept `&str`
+//! as well as the identifier types of the [ident] module.
+//!
 //! **Query**
 //!
 //! ```edition2018
@@ -187,7 +190,7 @@
 //! # fn run() -> Result<(), crossref::Error> {
 //! let client = Crossref::builder().build()?;
 //!
-//! let all_works: Vec<Work> = client.deep_page(WorksQuery::new("Machine Learning")).flat_map(|x|x.items).collect();
+//! let all_works: Vec<Work> = client.deep_page(WorksQuery::new("Machine Learning"))?.flat_map(|x|x.items).collect();
 //!
 //! # Ok(())
 //! # }
@@ -200,7 +203,7 @@
 //! # fn run() -> Result<(), crossref::Error> {
 //! let client = Crossref::builder().build()?;
 //!
-//! let all_works: Vec<Work> = client.deep_page("Machine Learning").into_work_iter().collect();
+//! let all_works: Vec<Work> = client.deep_page("Machine Learning")?.into_work_iter().collect();
 //!
 //! # Ok(())
 //! # }
@@ -217,7 +220,7 @@
 //! # fn run() -> Result<(), crossref::Error> {
 //! let client = Crossref::builder().build()?;
 //!
-//! let all_funder_work_list: Vec<WorkList> = client.deep_page(WorksQuery::default().into_combined_query::<Funders>("funder id")).collect();
+//! let all_funder_work_list: Vec<WorkList> = client.deep_page(WorksQuery::default().into_combined_query::<Funders>("funder id"))?.collect();
 //!
 //! # Ok(())
 //! # }
@@ -232,7 +235,7 @@
 //! let client = Crossref::builder().build()?;
 //!
 //! let all_works: Vec<Work> = client.deep_page(WorksQuery::default()
-//!         .into_combined_query::<Funders>("funder id"))
+//!         .into_combined_query::<Funders>("funder id"))?
 //!         .into_work_iter()
 //!         .collect();
 //!
@@ -244,7 +247,11 @@
 //#![deny(missing_docs)]
 #![allow(unused)]
 
+#[macro_use]
+mod macros;
+
 mod error;
+pub mod ident;
 /// provides types to construct a specific query
 pub mod query;
 /// provides the response types of the crossref api
@@ -253,33 +260,46 @@ pub mod response;
 // TODO extract to optional feature?
 /// content negotiation
 pub mod cn;
-/// textual data mining
+mod rate;
+/// text and data mining of the full texts publishers link in their works
 pub mod tdm;
+/// json lines archives of harvested works
+pub mod harvest;
+
+mod xml;
 
 #[doc(inline)]
 pub use self::error::{Error, Result};
 
+#[doc(inline)]
+pub use self::ident::{Doi, FunderId, Isbn, Issn, MemberId, Orcid};
+
 #[doc(inline)]
 pub use self::query::works::{
-    FieldQuery, WorkListQuery, WorkResultControl, Works, WorksFilter, WorksIdentQuery, WorksQuery,
+    FieldQuery, WorkElement, WorkListQuery, WorkResultControl, Works, WorksFilter,
+    WorksIdentQuery, WorksQuery,
 };
 
 #[doc(inline)]
 pub use self::query::{Component, CrossrefQuery, CrossrefRoute, Order, Sort};
 pub use self::query::{Funders, Journals, Members, Prefixes, Type, Types};
 pub use self::response::{
-    CrossrefType, Funder, FunderList, Journal, JournalList, Member, MemberList, TypeList, Work,
+    CrossrefDate, CrossrefType, Funder, FunderList, Journal, JournalList, ListEnvelope, Member,
+    MemberList, ParseMode, ParseWarning, PartialWork, PartialWorkList, ResponseMeta, TypeList, Work,
     WorkAgency, WorkList,
 };
 
 pub(crate) use self::response::{Message, Response};
 
+use crate::cn::{CitationRequest, CnFormat, CnResponse};
 use crate::error::ErrorKind;
-use crate::query::{FundersQuery, MembersQuery, ResourceComponent};
+use crate::rate::RateLimiter;
+use crate::query::{FundersQuery, JournalsQuery, MembersQuery, ResourceComponent};
+use crate::response::unixref::UnixrefRecord;
 use crate::response::{MessageType, Prefix};
 use async_iterator::Iterator;
-use query::journals::JournalResultControl;
 use reqwest::{self, Client};
+use std::collections::HashMap;
 use std::default;
 use std::iter::FlatMap;
 use std::rc::Rc;
@@ -306,17 +326,41 @@ macro_rules! get_item {
 }
 
 macro_rules! impl_combined_works_query {
-    ($($name:ident  $component:ident,)*) => {
+    ($($name:ident $partial:ident $component:ident,)*) => {
         $(
         /// Return one page of the components's `Work` that match the query
         ///
+        /// # Errors
+        ///
+        /// Fails if the query selects a subset of elements `UnsupportedParameter`, use the `_partial` variant instead
         pub async fn $name(&self, ident: WorksIdentQuery) -> Result<WorkList> {
+            reject_select(&ident.query)?;
             let resp = self.get_response(&$component::Works(ident)).await?;
             get_item!(WorkList, resp.message, resp.message_type)
+        }
+
+        /// Return one page of the components's `PartialWork` that match the query, use this together
+        /// with [WorksQuery::elements]
+        pub async fn $partial(&self, ident: WorksIdentQuery) -> Result<PartialWorkList> {
+            self.get_partial_works(&$component::Works(ident)).await
         })+
     };
 }
 
+/// `select` can only be used with the `PartialWork` methods
+fn reject_select(query: &WorksQuery) -> Result<()> {
+    if query.elements.is_empty() {
+        Ok(())
+    } else {
+        Err(ErrorKind::UnsupportedParameter {
+            param: "select".to_string(),
+            msg: "a `Work` can not be built from a subset of its elements, use `works_partial`"
+                .to_string(),
+        }
+        .into())
+    }
+}
+
 /// Struct for Crossref search API methods
 #[derive(Debug, Clone)]
 pub struct Crossref {
@@ -324,11 +368,25 @@ pub struct Crossref {
     pub base_url: String,
     /// the reqwest client that handles the requests
     pub client: Client,
+    /// the reqwest client for the content negotiation requests, it does not send the plus token
+    /// to the DOI resolver and the registration agencies it redirects to
+    cn_client: Client,
+    /// how malformed fields of a `Work` are handled
+    pub parse_mode: ParseMode,
+    /// whether the unparsed json is kept on the response items
+    pub keep_raw: bool,
+    /// the DOI resolver content negotiation requests are sent to, `https://doi.org` by default
+    pub cn_base_url: String,
+    /// spaces out the api requests according to the limits crossref announces
+    rate_limiter: Arc<RateLimiter>,
+    /// spaces out the content negotiation requests, the DOI resolver announces its own limits
+    cn_rate_limiter: Arc<RateLimiter>,
     //pub blocking_client: Arc<reqwest::blocking::Client>,
 }
 
 impl Crossref {
     const BASE_URL: &'static str = "https://api.crossref.org";
+    const CN_BASE_URL: &'static str = "https://doi.org";
 
     /// Constructs a new `CrossrefBuilder`.
     ///
@@ -338,8 +396,9 @@ impl Crossref {
     }
 
     // generate all functions to query combined endpoints
-    impl_combined_works_query!(funder_works Funders, member_works Members,
-    type_works Types, journal_works Journals, prefix_works Prefixes,);
+    impl_combined_works_query!(funder_works funder_works_partial Funders,
+    member_works member_works_partial Members, type_works type_works_partial Types,
+    journal_works journal_works_partial Journals, prefix_works prefix_works_partial Prefixes,);
 
     /// Transforms the `CrossrefQuery` in the request route and  executes the request
     ///
@@ -351,16 +410,53 @@ impl Crossref {
     async fn get_response<T: CrossrefQuery>(&self, query: &T) -> Result<Response> {
         let span = tracing::info_span!("crossref");
         let _guard = span.enter();
-        let q = query.to_url(&self.base_url)?;
-        println!("url: {}", q);
+        if !self.keep_raw {
+            let (headers, bytes) = self.get_bytes(query).await?;
+            let mut resp = Response::from_slice(&bytes, self.parse_mode)?;
+            resp.attach_headers(headers);
+            return Ok(resp);
+        }
+        let (headers, json) = self.get_json(query).await?;
+        let mut resp = Response::parse(json.clone(), self.parse_mode)?;
+        if let (Some(message), Some(value)) = (resp.message.as_mut(), json.get("message")) {
+            message.attach_raw(value);
+        }
+        resp.attach_headers(headers);
+        Ok(resp)
+    }
 
-        let res: std::result::Result<serde_json::Value, reqwest::Error> =
-            self.client.get(&q).send().await?.json().await;
+    /// Executes the request for the `CrossrefQuery` and returns the response headers and the unparsed json body
+    async fn get_json<T: CrossrefQuery>(
+        &self,
+        query: &T,
+    ) -> Result<(HashMap<String, String>, serde_json::Value)> {
+        let (headers, bytes) = self.get_bytes(query).await?;
+        Ok((headers, serde_json::from_slice(&bytes)?))
+    }
 
-        match res {
-            Ok(json) => Ok(Response::try_from(json)?),
-            Err(e) => Err(ErrorKind::ReqWest { reqwest: e }.into()),
-        }
+    /// Executes the request for the `CrossrefQuery` and returns the response headers and the raw body.
+    ///
+    /// Header names are lowercase, values that are not valid utf-8 are skipped.
+    async fn get_bytes<T: CrossrefQuery>(
+        &self,
+        query: &T,
+    ) -> Result<(HashMap<String, String>, bytes::Bytes)> {
+        let q = query.to_url(&self.base_url)?;
+        tracing::debug!(url = %q, "sending request");
+
+        self.rate_limiter.acquire().await;
+        let res = self.client.get(&q).send().await?;
+        self.rate_limiter.update(res.headers());
+        let headers = res
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.as_str().to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let bytes = res
+            .bytes()
+            .await
+            .map_err(|e| ErrorKind::ReqWest { reqwest: e })?;
+        Ok((headers, bytes))
     }
 
     //fn get_response_blocking<T: CrossrefQuery>(&self, query: &T) -> Result<Response> {
@@ -407,20 +503,63 @@ impl Crossref {
     /// This method fails if the `works` element expands to a bad route `ResourceNotFound`
     /// Fails if the response body doesn't have `message` field `MissingMessage`.
     /// Fails if anything else than a `WorkList` is returned as message `UnexpectedItem`
+    /// Fails if the query selects a subset of elements `UnsupportedParameter`, use [Crossref::works_partial] instead
     pub async fn works<T: Into<WorkListQuery>>(&self, query: T) -> Result<WorkList> {
-        let resp = self.get_response(&query.into()).await?;
+        let query = query.into();
+        reject_select(query.query())?;
+        let resp = self.get_response(&query).await?;
 
         get_item!(WorkList, resp.message, resp.message_type)
     }
 
+    /// Return the `PartialWork` items that match a certain query.
+    ///
+    /// Use this together with [WorksQuery::elements] to only retrieve the selected fields of each `Work`.
+    ///
+    /// # Example
+    ///
+    /// ```edition2018
+    /// use crossref_rs::{Crossref, WorksQuery, WorkElement};
+    /// # async fn run() -> Result<(), crossref_rs::Error> {
+    /// let client = Crossref::builder().build()?;
+    ///
+    /// let query = WorksQuery::new("Machine Learning")
+    ///     .elements(vec![WorkElement::DOI, WorkElement::Title, WorkElement::Author]);
+    ///
+    /// let works = client.works_partial(query).await?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails if anything else than a `work-list` is returned as message `UnexpectedItem`
+    pub async fn works_partial<T: Into<WorkListQuery>>(&self, query: T) -> Result<PartialWorkList> {
+        self.get_partial_works(&query.into()).await
+    }
+
+    /// Executes the request for the `CrossrefQuery` and parses the message as `PartialWorkList`
+    async fn get_partial_works<T: CrossrefQuery>(&self, query: &T) -> Result<PartialWorkList> {
+        let (headers, bytes) = self.get_bytes(query).await?;
+        let mut list = PartialWorkList::from_slice(&bytes)?;
+        list.meta.headers = headers;
+        Ok(list)
+    }
+
     /// Return the `Work` that is identified by  the `doi`.
     ///
     /// # Errors
+    /// This method fails if the `doi` is not a valid DOI `DoiValidationError`
     /// This method fails if the doi could not identified `ResourceNotFound`
     ///
-    pub async fn work(&self, doi: &str) -> Result<Work> {
+    pub async fn work<T>(&self, doi: T) -> Result<Work>
+    where
+        T: TryInto<Doi>,
+        Error: From<T::Error>,
+    {
         let resp = self
-            .get_response(&Works::Identifier(doi.to_string()))
+            .get_response(&Works::Identifier(doi.try_into()?))
             .await?;
         get_item!(Work, resp.message, resp.message_type).map(|x| *x)
     }
@@ -439,7 +578,7 @@ impl Crossref {
     /// # fn run() -> Result<(), crossref::Error> {
     /// let client = Crossref::builder().build()?;
     ///
-    /// let all_works: Vec<Work> = client.deep_page(WorksQuery::new("Machine Learning")).flat_map(|x|x.items).collect();
+    /// let all_works: Vec<Work> = client.deep_page(WorksQuery::new("Machine Learning"))?.flat_map(|x|x.items).collect();
     ///
     /// # Ok(())
     /// # }
@@ -455,7 +594,7 @@ impl Crossref {
     /// # fn run() -> Result<(), crossref::Error> {
     /// let client = Crossref::builder().build()?;
     ///
-    /// let all_funder_work_list: Vec<WorkList> = client.deep_page(WorksQuery::default().into_combined_query::<Funders>("funder id")).collect();
+    /// let all_funder_work_list: Vec<WorkList> = client.deep_page(WorksQuery::default().into_combined_query::<Funders>("funder id"))?.collect();
     ///
     /// # Ok(())
     /// # }
@@ -470,7 +609,7 @@ impl Crossref {
     /// let client = Crossref::builder().build()?;
     ///
     /// let all_works: Vec<Work> = client.deep_page(WorksQuery::default()
-    ///         .into_combined_query::<Funders>("funder id"))
+    ///         .into_combined_query::<Funders>("funder id"))?
     ///         .into_work_iter()
     ///         .collect();
     ///
@@ -502,22 +641,191 @@ impl Crossref {
     /// # }
     /// ```
     ///
-    pub fn deep_page<T: Into<WorkListQuery>>(&self, query: T) -> WorkListIterator {
-        WorkListIterator {
+    /// The iterator ends on the first failed request.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the query selects a subset of elements `UnsupportedParameter`, use
+    /// [Crossref::deep_page_partial] instead
+    pub fn deep_page<T: Into<WorkListQuery>>(&self, query: T) -> Result<WorkListIterator<'_>> {
+        WorkListIterator::new(query.into(), self)
+    }
+
+    /// Iterate over the pages of `PartialWork` items of a query that selects a subset of elements,
+    /// following the `next-cursor` like [Crossref::deep_page]
+    pub fn deep_page_partial<T: Into<WorkListQuery>>(&self, query: T) -> PartialWorkListIterator<'_> {
+        PartialWorkListIterator {
             query: query.into(),
             client: self,
-            index: 0,
             finish_next_iteration: false,
         }
     }
 
+    /// Return the metadata of the `doi` in another `format` by [content negotiation](https://citation.crosscite.org/docs.html).
+    ///
+    /// The request is sent to [Crossref::cn_base_url] and follows the redirects to the registration
+    /// agency of the DOI, so this also works for DOIs that are not registered with crossref.
+    ///
+    /// # Example
+    ///
+    /// ```edition2018
+    /// use crossref_rs::Crossref;
+    /// use crossref_rs::cn::CnFormat;
+    /// # async fn run() -> Result<(), crossref_rs::Error> {
+    /// let client = Crossref::builder().build()?;
+    /// let bibtex = client.content_negotiate("10.1037/0003-066x.59.1.29", CnFormat::BibTex).await?;
+    /// println!("{}", bibtex.text()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// This method fails if the `doi` is not a valid DOI `DoiValidationError`
+    /// This method fails if the doi could not be resolved `ResourceNotFound`
+    /// This method fails if the agency of the doi does not serve `format` `UnsupportedFormat`
+    pub async fn content_negotiate<T>(&self, doi: T, format: CnFormat) -> Result<CnResponse>
+    where
+        T: TryInto<Doi>,
+        Error: From<T::Error>,
+    {
+        self.negotiate(doi.try_into()?, format, format.header()).await
+    }
+
+    /// Return the UNIXSD record of the `doi`, with the citation list, funder groups, components and
+    /// crossmark record the json of the api does not expose in full
+    ///
+    /// # Errors
+    /// This method fails if the `doi` is not a valid DOI `DoiValidationError`
+    /// This method fails if the doi could not be resolved `ResourceNotFound`
+    /// This method fails if the doi is not registered with crossref `UnsupportedFormat`
+    pub async fn work_unixref<T>(&self, doi: T) -> Result<UnixrefRecord>
+    where
+        T: TryInto<Doi>,
+        Error: From<T::Error>,
+    {
+        self.content_negotiate(doi, CnFormat::CrossrefTdm)
+            .await?
+            .unixref()
+    }
+
+    /// Return the citation of the `doi` formatted in the CSL style and locale of the `request`
+    ///
+    /// # Example
+    ///
+    /// ```edition2018
+    /// use crossref_rs::Crossref;
+    /// use crossref_rs::cn::{CitationRequest, CitationStyle};
+    /// # async fn run() -> Result<(), crossref_rs::Error> {
+    /// let client = Crossref::builder().build()?;
+    /// let request = CitationRequest::new(CitationStyle::ChicagoAuthorDate).locale("en-GB");
+    /// let citation = client.citation("10.1037/0003-066x.59.1.29", &request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Fails like [Crossref::content_negotiate], unknown styles are answered with `ResourceNotFound`
+    /// by some agencies and with a citation in the default style by others
+    pub async fn citation<T>(&self, doi: T, request: &CitationRequest) -> Result<String>
+    where
+        T: TryInto<Doi>,
+        Error: From<T::Error>,
+    {
+        let resp = self
+            .negotiate(doi.try_into()?, CnFormat::Text, &request.header())
+            .await?;
+        Ok(resp.text()?.trim().to_string())
+    }
+
+    /// Return the formatted citation of each of the `dois` in the same order, one request per DOI.
+    ///
+    /// A failed DOI does not stop the batch, its error is returned at its position.
+    pub async fn citations<I>(&self, dois: I, request: &CitationRequest) -> Vec<Result<String>>
+    where
+        I: IntoIterator,
+        I::Item: TryInto<Doi>,
+        Error: From<<I::Item as TryInto<Doi>>::Error>,
+    {
+        let mut citations = Vec::new();
+        for doi in dois {
+            citations.push(self.citation(doi, request).await);
+        }
+        citations
+    }
+
+    /// Executes the content negotiation request for `doi` with the `Accept` header `accept`
+    async fn negotiate(&self, doi: Doi, format: CnFormat, accept: &str) -> Result<CnResponse> {
+        use reqwest::{header, StatusCode};
+        let mut url = url::Url::parse(&self.cn_base_url).map_err(|_| ErrorKind::Config {
+            msg: format!("invalid content negotiation base url `{}`", self.cn_base_url),
+        })?;
+        url.path_segments_mut()
+            .map_err(|_| ErrorKind::Config {
+                msg: format!("invalid content negotiation base url `{}`", self.cn_base_url),
+            })?
+            .pop_if_empty()
+            .push(doi.as_str());
+
+        self.cn_rate_limiter.acquire().await;
+        let res = self
+            .cn_client
+            .get(url.as_str())
+            .header(header::ACCEPT, accept)
+            .send()
+            .await?;
+        self.cn_rate_limiter.update(res.headers());
+
+        match res.status() {
+            StatusCode::NOT_FOUND => {
+                return Err(ErrorKind::ResourceNotFound {
+                    resource: Box::new(ResourceComponent::Works(Works::Identifier(doi))),
+                }
+                .into())
+            }
+            StatusCode::NOT_ACCEPTABLE => {
+                let agency = match self.work_agency::<Doi>(doi.clone()).await {
+                    Ok(agency) => agency.agency.id,
+                    Err(_) => "unknown".to_string(),
+                };
+                return Err(ErrorKind::UnsupportedFormat {
+                    format,
+                    doi: doi.to_string(),
+                    agency,
+                }
+                .into());
+            }
+            _ => {}
+        }
+        let res = res.error_for_status()?;
+        let content_type = res
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let url = res.url().to_string();
+        let body = res.bytes().await?;
+
+        Ok(CnResponse {
+            doi,
+            format,
+            content_type,
+            url,
+            body,
+        })
+    }
+
     /// Return the `Agency` that registers the `Work` identified by  the `doi`.
     ///
     /// # Errors
+    /// This method fails if the `doi` is not a valid DOI `DoiValidationError`
     /// This method fails if the doi could not identified `ResourceNotFound`
     ///
-    pub async fn work_agency(&self, doi: &str) -> Result<WorkAgency> {
-        let resp = self.get_response(&Works::Agency(doi.to_string())).await?;
+    pub async fn work_agency<T>(&self, doi: T) -> Result<WorkAgency>
+    where
+        T: TryInto<Doi>,
+        Error: From<T::Error>,
+    {
+        let resp = self.get_response(&Works::Agency(doi.try_into()?)).await?;
         get_item!(WorkAgency, resp.message, resp.message_type)
     }
 
@@ -528,13 +836,96 @@ impl Crossref {
     }
 
     /// Return the `Funder` for the `id`
-    pub async fn funder(&self, id: &str) -> Result<Funder> {
+    pub async fn funder<T>(&self, id: T) -> Result<Funder>
+    where
+        T: TryInto<FunderId>,
+        Error: From<T::Error>,
+    {
         let resp = self
-            .get_response(&Funders::Identifier(id.to_string()))
+            .get_response(&Funders::Identifier(id.try_into()?))
             .await?;
         get_item!(Funder, resp.message, resp.message_type).map(|x| *x)
     }
 
+    /// Return the `Funder` items that are descendants of the funder with `id` in the funder hierarchy
+    ///
+    /// Each related funder is requested on its own, a stale id does not fail the others, its error
+    /// is returned at its position.
+    pub async fn funder_descendants<T>(&self, id: T) -> Result<Vec<Result<Funder>>>
+    where
+        T: TryInto<FunderId>,
+        Error: From<T::Error>,
+    {
+        let funder = self.funder(id).await?;
+        Ok(self.funders_by_id(&funder.descendants).await)
+    }
+
+    /// Return the parent `Funder` of the funder with `id` in the funder hierarchy, `None` if it is
+    /// at the top of the hierarchy
+    pub async fn funder_parent<T>(&self, id: T) -> Result<Option<Funder>>
+    where
+        T: TryInto<FunderId>,
+        Error: From<T::Error>,
+    {
+        let funder = self.funder(id).await?;
+        let parent: Vec<FunderId> = funder.parent().map(|node| node.id.clone()).into_iter().collect();
+        self.funders_by_id(&parent).await.pop().transpose()
+    }
+
+    /// Return the `Funder` items above the funder with `id` in the funder hierarchy, starting with
+    /// the top of the hierarchy
+    ///
+    /// Each related funder is requested on its own, a stale id does not fail the others, its error
+    /// is returned at its position.
+    pub async fn funder_ancestors<T>(&self, id: T) -> Result<Vec<Result<Funder>>>
+    where
+        T: TryInto<FunderId>,
+        Error: From<T::Error>,
+    {
+        let funder = self.funder(id).await?;
+        let ancestors: Vec<FunderId> = funder
+            .ancestors()
+            .into_iter()
+            .map(|node| node.id.clone())
+            .collect();
+        Ok(self.funders_by_id(&ancestors).await)
+    }
+
+    /// Return the `Funder` items that replaced the funder with `id`
+    ///
+    /// Each related funder is requested on its own, a stale id does not fail the others, its error
+    /// is returned at its position.
+    pub async fn funder_replaced_by<T>(&self, id: T) -> Result<Vec<Result<Funder>>>
+    where
+        T: TryInto<FunderId>,
+        Error: From<T::Error>,
+    {
+        let funder = self.funder(id).await?;
+        Ok(self.funders_by_id(&funder.replaced_by).await)
+    }
+
+    /// Return the `Funder` items that were replaced by the funder with `id`
+    ///
+    /// Each related funder is requested on its own, a stale id does not fail the others, its error
+    /// is returned at its position.
+    pub async fn funder_replaces<T>(&self, id: T) -> Result<Vec<Result<Funder>>>
+    where
+        T: TryInto<FunderId>,
+        Error: From<T::Error>,
+    {
+        let funder = self.funder(id).await?;
+        Ok(self.funders_by_id(&funder.replaces).await)
+    }
+
+    /// Return the `Funder` for each of the `ids` in the same order, one request per id
+    async fn funders_by_id(&self, ids: &[FunderId]) -> Vec<Result<Funder>> {
+        let mut funders = Vec::with_capacity(ids.len());
+        for id in ids {
+            funders.push(self.funder(id).await);
+        }
+        funders
+    }
+
     /// Return the matching `Members` items.
     pub async fn members(&self, members: MembersQuery) -> Result<MemberList> {
         let resp = self.get_response(&Members::Query(members)).await?;
@@ -542,42 +933,56 @@ impl Crossref {
     }
 
     /// Return the `Member` for the `id`
-    pub async fn member(&self, member_id: &str) -> Result<Member> {
+    pub async fn member<T>(&self, member_id: T) -> Result<Member>
+    where
+        T: TryInto<MemberId>,
+        Error: From<T::Error>,
+    {
         let resp = self
-            .get_response(&Members::Identifier(member_id.to_string()))
+            .get_response(&Members::Identifier(member_id.try_into()?))
             .await?;
         get_item!(Member, resp.message, resp.message_type).map(|x| *x)
     }
 
     /// Return the `Prefix` for the `id`
-    pub async fn prefix(&self, id: &str) -> Result<Prefix> {
+    pub async fn prefix<T>(&self, id: T) -> Result<Prefix>
+    where
+        T: TryInto<ident::Prefix>,
+        Error: From<T::Error>,
+    {
         let resp = self
-            .get_response(&Prefixes::Identifier(id.to_string()))
+            .get_response(&Prefixes::Identifier(id.try_into()?))
             .await?;
         get_item!(Prefix, resp.message, resp.message_type)
     }
-    /// Return a specific `Journal`
-    pub async fn journal(&self, id: &str) -> Result<Journal> {
+
+    /// Return the `Member` that owns the DOI `prefix`
+    pub async fn prefix_member<T>(&self, prefix: T) -> Result<Member>
+    where
+        T: TryInto<ident::Prefix>,
+        Error: From<T::Error>,
+    {
+        let prefix = self.prefix(prefix).await?;
+        self.member::<MemberId>(prefix.member).await
+    }
+
+    /// Return the `Journal` with the ISSN `id`
+    pub async fn journal<T>(&self, id: T) -> Result<Journal>
+    where
+        T: TryInto<Issn>,
+        Error: From<T::Error>,
+    {
         let resp = self
-            .get_response(&Journals::Identifier(id.to_string()))
+            .get_response(&Journals::Identifier(id.try_into()?))
             .await?;
 
         get_item!(Journal, resp.message, resp.message_type).map(|x| *x)
     }
 
-    /// Return all [Journals] matching the query
-    pub async fn journals(
-        &self,
-        query: String,
-        result_control: Option<JournalResultControl>,
-    ) -> Result<JournalList> {
-        if let Some(rc) = result_control {
-            let resp = self.get_response(&Journals::Query(query, Some(rc))).await?;
-            return get_item!(JournalList, resp.message, resp.message_type);
-        } else {
-            let resp = self.get_response(&Journals::Query(query, None)).await?;
-            return get_item!(JournalList, resp.message, resp.message_type);
-        }
+    /// Return the matching `Journals` items.
+    pub async fn journals(&self, journals: JournalsQuery) -> Result<JournalList> {
+        let resp = self.get_response(&Journals::Query(journals)).await?;
+        get_item!(JournalList, resp.message, resp.message_type)
     }
 
     /// Return all available `Type`
@@ -607,7 +1012,7 @@ impl Crossref {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn random_dois(&self, len: usize) -> Result<Vec<String>> {
+    pub async fn random_dois(&self, len: usize) -> Result<Vec<Doi>> {
         self.works(WorksQuery::random(len))
             .await
             .map(|x| x.items.into_iter().map(|x| x.doi).collect())
@@ -642,6 +1047,12 @@ pub struct CrossrefBuilder {
     plus_token: Option<String>,
     /// use a different base url than `Crossref::BASE_URL` https://api.crossref.org
     base_url: Option<String>,
+    /// how malformed `Work` fields are handled, `ParseMode::Lenient` by default
+    parse_mode: ParseMode,
+    /// whether the unparsed json is kept on the response items
+    keep_raw: bool,
+    /// use a different DOI resolver than `Crossref::CN_BASE_URL` https://doi.org for content negotiation
+    cn_base_url: Option<String>,
 }
 
 impl CrossrefBuilder {
@@ -671,6 +1082,24 @@ impl CrossrefBuilder {
         self
     }
 
+    /// set how malformed fields of a `Work` are handled, see [ParseMode]
+    pub fn parse_mode(mut self, parse_mode: ParseMode) -> Self {
+        self.parse_mode = parse_mode;
+        self
+    }
+
+    /// keep the unparsed json of each `Work`, `Member`, `Funder` and `Journal` in its `raw` field
+    pub fn keep_raw(mut self, keep_raw: bool) -> Self {
+        self.keep_raw = keep_raw;
+        self
+    }
+
+    /// use a different DOI resolver than `https://doi.org` for content negotiation, like `https://data.crossref.org`
+    pub fn cn_base_url(mut self, base_url: &str) -> Self {
+        self.cn_base_url = Some(base_url.to_string());
+        self
+    }
+
     /// Returns a `Crossref` that uses this `CrossrefBuilder` configuration.
     /// # Errors
     ///
@@ -686,6 +1115,12 @@ impl CrossrefBuilder {
                 })?,
             );
         }
+        let cn_client = reqwest::Client::builder()
+            .default_headers(headers.clone())
+            .build()
+            .map_err(|_| ErrorKind::Config {
+                msg: "failed to initialize TLS backend".to_string(),
+            })?;
         if let Some(token) = &self.plus_token {
             headers.insert(
                 header::AUTHORIZATION,
@@ -713,6 +1148,14 @@ impl CrossrefBuilder {
                 .base_url
                 .unwrap_or_else(|| Crossref::BASE_URL.to_string()),
             client, // blocking_client: Arc::new(blocking_client),
+            cn_client,
+            parse_mode: self.parse_mode,
+            keep_raw: self.keep_raw,
+            cn_base_url: self
+                .cn_base_url
+                .unwrap_or_else(|| Crossref::CN_BASE_URL.to_string()),
+            rate_limiter: Arc::new(RateLimiter::default()),
+            cn_rate_limiter: Arc::new(RateLimiter::default()),
         })
     }
 }
@@ -730,13 +1173,16 @@ pub struct WorkListIterator<'a> {
 }
 
 impl<'a> WorkListIterator<'a> {
-    pub fn new<'b: 'a>(query: WorkListQuery, client: &'b Crossref) -> Self {
-        Self {
+    /// Fails if the query selects a subset of elements `UnsupportedParameter`, see
+    /// `PartialWorkListIterator`
+    pub fn new<'b: 'a>(query: WorkListQuery, client: &'b Crossref) -> Result<Self> {
+        reject_select(query.query())?;
+        Ok(Self {
             query,
             client,
             index: 0,
             finish_next_iteration: false,
-        }
+        })
     }
 }
 
@@ -754,36 +1200,14 @@ impl<'a> async_iterator::Iterator for WorkListIterator<'a> {
         if self.finish_next_iteration {
             return None;
         }
-
-        {
-            let control = &mut self.query.query_mut().result_control;
-
-            // if no result control is set, set a new cursor
-            if control.is_none() {
-                *control = Some(WorkResultControl::new_cursor());
-            }
-        }
+        init_cursor(&mut self.query);
 
         let resp = self.client.get_response(&self.query).await;
         if let Ok(resp) = resp {
             let worklist: Result<WorkList> = get_item!(WorkList, resp.message, resp.message_type);
             if let Ok(worklist) = worklist {
-                if let Some(cursor) = &worklist.next_cursor {
-                    match &mut self.query.query_mut().result_control {
-                        Some(WorkResultControl::Cursor { token, .. }) => {
-                            // use the received cursor token in next iteration
-                            *token = Some(cursor.clone())
-                        }
-                        Some(WorkResultControl::Standard(_)) => {
-                            // standard result control was set, don't deep page and return next iteration
-                            self.finish_next_iteration = true;
-                        }
-                        _ => (),
-                    }
-                } else {
-                    // no cursor received, end next iteration
-                    self.finish_next_iteration = true;
-                }
+                self.finish_next_iteration =
+                    advance_cursor(&mut self.query, worklist.next_cursor.as_deref());
 
                 if worklist.items.is_empty() {
                     None
@@ -800,3 +1224,58 @@ impl<'a> async_iterator::Iterator for WorkListIterator<'a> {
         }
     }
 }
+
+/// Allows iterating of deep page partial work requests, see [Crossref::deep_page_partial]
+pub struct PartialWorkListIterator<'a> {
+    /// the query
+    query: WorkListQuery,
+    /// performs each request
+    client: &'a Crossref,
+    /// whether the iterator should finish next iteration
+    finish_next_iteration: bool,
+}
+
+impl<'a> async_iterator::Iterator for PartialWorkListIterator<'a> {
+    type Item = PartialWorkList;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        if self.finish_next_iteration {
+            return None;
+        }
+        init_cursor(&mut self.query);
+
+        let worklist = self.client.get_partial_works(&self.query).await.ok()?;
+        self.finish_next_iteration = advance_cursor(&mut self.query, worklist.next_cursor.as_deref());
+        if worklist.items.is_empty() {
+            None
+        } else {
+            Some(worklist)
+        }
+    }
+}
+
+/// if no result control is set, set a new cursor
+fn init_cursor(query: &mut WorkListQuery) {
+    let control = &mut query.query_mut().result_control;
+    if control.is_none() {
+        *control = Some(WorkResultControl::new_cursor());
+    }
+}
+
+/// use the received `next_cursor` in the next request, returns whether the iteration is finished
+fn advance_cursor(query: &mut WorkListQuery, next_cursor: Option<&str>) -> bool {
+    let Some(cursor) = next_cursor else {
+        // no cursor received, end next iteration
+        return true;
+    };
+    match &mut query.query_mut().result_control {
+        Some(WorkResultControl::Cursor { token, .. }) => {
+            // use the received cursor token in next iteration
+            *token = Some(cursor.to_string());
+            false
+        }
+        // standard result control was set, don't deep page and return next iteration
+        Some(WorkResultControl::Standard(_)) => true,
+        _ => false,
+    }
+}
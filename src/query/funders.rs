@@ -1,4 +1,5 @@
 use crate::error::Result;
+use crate::ident::FunderId;
 use crate::query::facet::FacetCount;
 use crate::query::works::{WorksCombiner, WorksFilter, WorksIdentQuery, WorksQuery};
 use crate::query::*;
@@ -36,17 +37,36 @@ impl Filter for FundersFilter {}
 
 impl_common_query!(FundersQuery, FundersFilter);
 
+impl FundersQuery {
+    /// search funders by their name or alternative names, the `name` is added as free form query term
+    pub fn name<T: ToString>(self, name: T) -> Self {
+        self.query(name)
+    }
+
+    /// only funders located in the country `location`
+    pub fn location<T: ToString>(self, location: T) -> Self {
+        self.filter(FundersFilter::Location(location.to_string()))
+    }
+}
+
 /// constructs the request payload for the `/funders` route
 #[derive(Debug, Clone)]
 pub enum Funders {
     /// target a specific funder at `/funder/{id}`
-    Identifier(String),
+    Identifier(FunderId),
     /// target all funders that match the query at `/funders?query...`
     Query(FundersQuery),
     /// target a `Work` for a specific funder at `/funders/{id}/works?query..`
     Works(WorksIdentQuery),
 }
 
+impl Funders {
+    /// create a new `Funders::Identifier` for the funder with `id`
+    pub fn id(id: FunderId) -> Self {
+        Funders::Identifier(id)
+    }
+}
+
 impl CrossrefRoute for Funders {
     fn route(&self) -> Result<String> {
         match self {
@@ -1,182 +1,72 @@
-use crate::error::{ErrorKind, Result};
+use crate::error::Result;
+use crate::ident::Issn;
 use crate::query::works::{WorksCombiner, WorksFilter, WorksIdentQuery, WorksQuery};
-use crate::query::{Component, CrossrefQuery, CrossrefRoute, ResourceComponent};
-use crate::WorkResultControl;
+use crate::query::*;
+use std::borrow::Cow;
 
+/// filters supported for the `/journals` route.
+///
+/// Crossref currently documents no filters for `/journals`, this enum exists so that
+/// `JournalsQuery` shares its interface with the other queries.
 #[derive(Debug, Clone)]
-pub struct JournalResultControl {
-    pub limit: Option<usize>,
-    pub offset: Option<usize>,
-    pub sample: Option<bool>,
-    pub sort: Option<String>,
-}
-
-impl JournalResultControl {
-    pub fn new(limit: Option<usize>, offset: Option<usize>, sample: Option<bool>, sort: Option<String>) -> Self {
-        JournalResultControl {
-            limit,
-            offset,
-            sample,
-            sort,
-        }
-    }
+pub enum JournalsFilter {}
 
-    pub fn new_from_limit(limit: usize) -> Self {
-        JournalResultControl {
-            limit: Some(limit),
-            offset: None,
-            sample: None,
-            sort: None,
-        }
-    }
-
-    pub fn new_from_offset(offset: usize) -> Self {
-        JournalResultControl {
-            limit: None,
-            offset: Some(offset),
-            sample: None,
-            sort: None,
-        }
-    }
-
-    pub fn new_from_sample(sample: bool) -> Self {
-        JournalResultControl {
-            limit: None,
-            offset: None,
-            sample: Some(sample),
-            sort: None,
-        }
-    }
-
-    pub fn new_from_sort(sort: String) -> Self {
-        JournalResultControl {
-            limit: None,
-            offset: None,
-            sample: None,
-            sort: Some(sort),
-        }
-    }
-
-    pub fn limit(mut self, limit: usize) -> Self {
-        self.limit = Some(limit);
-        self
-    }
-
-    pub fn offset(mut self, offset: usize) -> Self {
-        self.offset = Some(offset);
-        self
+impl JournalsFilter {
+    /// the key name for the filter element
+    pub fn name(&self) -> &str {
+        match *self {}
     }
+}
 
-    pub fn sample(mut self, sample: bool) -> Self {
-        self.sample = Some(sample);
-        self
+impl ParamFragment for JournalsFilter {
+    fn key(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.name())
     }
 
-    pub fn sort(mut self, sort: String) -> Self {
-        self.sort = Some(sort);
-        self
-    }
-
-    pub fn to_string(&self) -> String {
-        let mut rc = String::new();
-        if let Some(l) = self.limit {
-            rc.push_str(&format!("rows={}&", l));
-        }
-        if let Some(o) = self.offset {
-            rc.push_str(&format!("offset={}&", o));
-        }
-        if let Some(s) = self.sample {
-            rc.push_str(&format!("sample={}&", s));
-        }
-        if let Some(s) = &self.sort {
-            rc.push_str(&format!("sort={}&", s));
-        }
-        rc.pop(); // remove trailing '&'
-        rc
+    fn value(&self) -> Option<Cow<'_, str>> {
+        match *self {}
     }
 }
 
-impl TryFrom<String> for JournalResultControl {
-    type Error = ErrorKind;
-
-    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
-        
-    
-        let mut limit = None;
-        let mut offset = None;
-        let mut sample = None;
-        let mut sort = None;
+impl Filter for JournalsFilter {}
 
-        let parts = value.split('&').collect::<Vec<&str>>();
-        for part in parts {
-            let kv = part.split('=').collect::<Vec<&str>>();
-            if kv.len() != 2 {
-                return Err(ErrorKind::InvalidResultControl {
-                    error: value.clone(),
-                });
-            }
-            match kv[0] {
-                "rows" => {
-                    limit = Some(kv[1].parse().map_err(|e| ErrorKind::InvalidResultControl {
-                        error: format!("Invalid limit: {}", e),
-                    })?);
-                }
-                "offset" => {
-                    offset = Some(kv[1].parse().map_err(|e| ErrorKind::InvalidResultControl {
-                        error: format!("Invalid offset: {}", e),
-                    })?);
-                }
-                "sample" => {
-                    sample = Some(kv[1].parse().map_err(|e| ErrorKind::InvalidResultControl {
-                        error: format!("Invalid sample: {}", e),
-                    })?);
-                }
-                "sort" => {
-                    sort = Some(kv[1].to_string());
-                }
-                _ => return Err(ErrorKind::InvalidResultControl { error: value.clone() }),
-            }
-        }
+impl_common_query!(JournalsQuery, JournalsFilter);
 
-        Ok(JournalResultControl {
-            limit,
-            offset,
-            sample,
-            sort,
-        })
+impl JournalsQuery {
+    /// search journals by their title, the `title` is added as free form query term
+    pub fn title<T: ToString>(self, title: T) -> Self {
+        self.query(title)
     }
 }
 
-
-
-
-
-
 /// constructs the request payload for the `/journals` route
 #[derive(Debug, Clone)]
 pub enum Journals {
     /// target a specific journal at `/journals/{id}`
-    Identifier(String),
+    Identifier(Issn),
     /// target a `Work` for a specific funder at `/journals/{id}/works?query..`
     Works(WorksIdentQuery),
-    /// free form query for `/journals?query...`
-    Query(String, Option<JournalResultControl>),
+    /// target all journals that match the query at `/journals?query...`
+    Query(JournalsQuery),
+}
+
+impl Journals {
+    /// create a new `Journals::Identifier` for the journal with the ISSN `id`
+    pub fn id(id: Issn) -> Self {
+        Journals::Identifier(id)
+    }
 }
 
 impl CrossrefRoute for Journals {
     fn route(&self) -> Result<String> {
         match self {
             Journals::Identifier(s) => Ok(format!("{}/{}", Component::Journals.route()?, s)),
-            Journals::Query(query, result_control) => {
-                let q = query.split(' ').collect::<Vec<&str>>().join("+");
-                if let Some(rc) = result_control {
-                    if query.is_empty() {
-                        Ok(format!("{}/?{}", Component::Journals.route()?, rc.to_string()))
-                    } else {
-                        Ok(format!("{}/?query={}&{}", Component::Journals.route()?, q, rc.to_string()))
-                    }
+            Journals::Query(query) => {
+                let query = query.route()?;
+                if query.is_empty() {
+                    Component::Journals.route()
                 } else {
-                    Ok(format!("{}/?query={}", Component::Journals.route()?, q))
+                    Ok(format!("{}?{}", Component::Journals.route()?, query))
                 }
             }
             Journals::Works(combined) => Self::combined_route(combined),
@@ -1,4 +1,5 @@
 use crate::error::Result;
+use crate::ident::MemberId;
 use crate::query::works::{WorksCombiner, WorksFilter, WorksIdentQuery, WorksQuery};
 use crate::query::*;
 use std::borrow::Cow;
@@ -10,9 +11,9 @@ pub enum MembersFilter {
     HasPublicReferences,
     /// metadata for works where references are either `open`, `limited` (to Metadata Plus subscribers) or `closed`
     ReferenceVisibility(Visibility),
-    /// count of DOIs for material published more than two years ago
-    BlackfileDoiCount(i32),
-    /// count of DOIs for material published within last two years
+    /// exact count of DOIs for material published more than two years ago
+    BackfileDoiCount(i32),
+    /// exact count of DOIs for material published within last two years
     CurrentDoiCount(i32),
 }
 
@@ -22,7 +23,7 @@ impl MembersFilter {
         match self {
             MembersFilter::HasPublicReferences => "has-public-references",
             MembersFilter::ReferenceVisibility(_) => "reference-visibility",
-            MembersFilter::BlackfileDoiCount(_) => "blackfile-doi-count",
+            MembersFilter::BackfileDoiCount(_) => "backfile-doi-count",
             MembersFilter::CurrentDoiCount(_) => "current-doi-count",
         }
     }
@@ -35,9 +36,9 @@ impl ParamFragment for MembersFilter {
 
     fn value(&self) -> Option<Cow<str>> {
         match self {
-            MembersFilter::HasPublicReferences => None,
+            MembersFilter::HasPublicReferences => Some(Cow::Borrowed("true")),
             MembersFilter::ReferenceVisibility(vis) => Some(Cow::Borrowed(vis.as_str())),
-            MembersFilter::BlackfileDoiCount(num) => Some(Cow::Owned(num.to_string())),
+            MembersFilter::BackfileDoiCount(num) => Some(Cow::Owned(num.to_string())),
             MembersFilter::CurrentDoiCount(num) => Some(Cow::Owned(num.to_string())),
         }
     }
@@ -47,17 +48,53 @@ impl Filter for MembersFilter {}
 
 impl_common_query!(MembersQuery, MembersFilter);
 
+impl MembersQuery {
+    /// search members by their name, the `name` is added as free form query term
+    pub fn name<T: ToString>(self, name: T) -> Self {
+        self.query(name)
+    }
+
+    /// only members that made their references public for one or more of their prefixes
+    pub fn has_public_references(self) -> Self {
+        self.filter(MembersFilter::HasPublicReferences)
+    }
+
+    /// only members whose references have the `visibility`
+    pub fn reference_visibility(self, visibility: Visibility) -> Self {
+        self.filter(MembersFilter::ReferenceVisibility(visibility))
+    }
+
+    /// only members with exactly `count` DOIs for material published within the last two years,
+    /// the api does not support count ranges
+    pub fn current_doi_count(self, count: i32) -> Self {
+        self.filter(MembersFilter::CurrentDoiCount(count))
+    }
+
+    /// only members with exactly `count` DOIs for material published more than two years ago,
+    /// the api does not support count ranges
+    pub fn backfile_doi_count(self, count: i32) -> Self {
+        self.filter(MembersFilter::BackfileDoiCount(count))
+    }
+}
+
 /// constructs the request payload for the `/members` route
 #[derive(Debug, Clone)]
 pub enum Members {
     /// target a specific member at `/members/{id}`
-    Identifier(String),
+    Identifier(MemberId),
     /// target all members that match the query at `/members?query...`
     Query(MembersQuery),
     /// target a `Work` for a specific funder at `/members/{id}/works?query..`
     Works(WorksIdentQuery),
 }
 
+impl Members {
+    /// create a new `Members::Identifier` for the member with `id`
+    pub fn id(id: MemberId) -> Self {
+        Members::Identifier(id)
+    }
+}
+
 impl CrossrefRoute for Members {
     fn route(&self) -> Result<String> {
         match self {
@@ -1,7 +1,7 @@
 use crate::error::{Error, Result};
 use crate::query::facet::FacetCount;
 pub use crate::query::funders::{Funders, FundersQuery};
-pub use crate::query::journals::Journals;
+pub use crate::query::journals::{Journals, JournalsQuery};
 pub use crate::query::members::{Members, MembersQuery};
 pub use crate::query::prefixes::Prefixes;
 pub use crate::query::types::{Type, Types};
@@ -201,6 +201,121 @@ impl Visibility {
     }
 }
 
+string_enum! {
+    /// One of the relation types from the Crossref relations schema
+    /// [crossref relations](https://www.crossref.org/documentation/schema-library/markup-guide-metadata-segments/relationships/)
+    #[allow(missing_docs)]
+    pub enum RelationType {
+        IsDerivedFrom => "is-derived-from",
+        HasDerivation => "has-derivation",
+        IsReviewOf => "is-review-of",
+        HasReview => "has-review",
+        IsCommentOn => "is-comment-on",
+        HasComment => "has-comment",
+        IsReplyTo => "is-reply-to",
+        HasReply => "has-reply",
+        BasedOnData => "based-on-data",
+        IsDataBasisFor => "is-data-basis-for",
+        HasRelatedMaterial => "has-related-material",
+        IsRelatedMaterial => "is-related-material",
+        IsCompiledBy => "is-compiled-by",
+        Compiles => "compiles",
+        IsDocumentedBy => "is-documented-by",
+        Documents => "documents",
+        IsSupplementTo => "is-supplement-to",
+        IsSupplementedBy => "is-supplemented-by",
+        IsContinuedBy => "is-continued-by",
+        Continues => "continues",
+        IsPartOf => "is-part-of",
+        HasPart => "has-part",
+        References => "references",
+        IsReferencedBy => "is-referenced-by",
+        IsBasedOn => "is-based-on",
+        IsBasisFor => "is-basis-for",
+        Requires => "requires",
+        IsRequiredBy => "is-required-by",
+        Finances => "finances",
+        IsFinancedBy => "is-financed-by",
+        IsTranslationOf => "is-translation-of",
+        HasTranslation => "has-translation",
+        IsPreprintOf => "is-preprint-of",
+        HasPreprint => "has-preprint",
+        IsManuscriptOf => "is-manuscript-of",
+        HasManuscript => "has-manuscript",
+        IsExpressionOf => "is-expression-of",
+        HasExpression => "has-expression",
+        IsManifestationOf => "is-manifestation-of",
+        HasManifestation => "has-manifestation",
+        IsReplacedBy => "is-replaced-by",
+        Replaces => "replaces",
+        IsSameAs => "is-same-as",
+        IsIdenticalTo => "is-identical-to",
+        IsVariantFormOf => "is-variant-form-of",
+        IsOriginalFormOf => "is-original-form-of",
+        IsVersionOf => "is-version-of",
+        HasVersion => "has-version",
+        IsFormatOf => "is-format-of",
+        HasFormat => "has-format",
+        Cites => "cites",
+        IsCitedBy => "is-cited-by",
+    }
+}
+
+string_enum! {
+    /// One of the identifier types from the Crossref relations schema
+    #[allow(missing_docs)]
+    pub enum RelationIdType {
+        Doi => "doi",
+        Issn => "issn",
+        Isbn => "isbn",
+        Uri => "uri",
+        Pmid => "pmid",
+        Pmcid => "pmcid",
+        Purl => "purl",
+        Arxiv => "arxiv",
+        Ark => "ark",
+        Handle => "handle",
+        Uuid => "uuid",
+        Ecli => "ecli",
+        Accession => "accession",
+        Other => "other",
+    }
+}
+
+string_enum! {
+    /// The version of a work a license or full-text link applies to
+    pub enum ContentVersion {
+        /// version of record
+        Vor => "vor",
+        /// accepted manuscript
+        Am => "am",
+        /// text and data mining
+        Tdm => "tdm",
+        /// no version was given
+        Unspecified => "unspecified",
+    }
+}
+
+string_enum! {
+    /// What a full-text link is intended to be used for
+    #[allow(missing_docs)]
+    pub enum IntendedApplication {
+        TextMining => "text-mining",
+        SimilarityChecking => "similarity-checking",
+        Syndication => "syndication",
+        Unspecified => "unspecified",
+    }
+}
+
+string_enum! {
+    /// Who asserted a DOI, either crossref by matching or the publisher
+    #[allow(missing_docs)]
+    pub enum DoiAssertedBy {
+        Crossref => "crossref",
+        Publisher => "publisher",
+    }
+}
+
 /// Determines how results should be sorted
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[cfg_attr(feature = "cli", derive(StructOpt))]
@@ -630,3 +745,237 @@ pub(crate) fn format_queries<T: AsRef<str>>(topics: &[T]) -> String {
         .collect::<Vec<_>>()
         .join("+")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::funders::FundersFilter;
+    use super::members::MembersFilter;
+    use crate::ident::MemberId;
+    use super::*;
+    use std::collections::BTreeSet;
+
+    /// checked-in copy of Crossref's filter documentation
+    const FILTER_DOCS: &str = include_str!("../../tests/data/filter-names.md");
+
+    /// all filter names listed in the table of the `## {route}` section
+    fn documented_filters(route: &str) -> BTreeSet<String> {
+        let header = format!("## {}", route);
+        FILTER_DOCS
+            .lines()
+            .skip_while(|line| line.trim() != header)
+            .skip(1)
+            .take_while(|line| !line.starts_with("## "))
+            .filter_map(|line| line.strip_prefix("| `"))
+            .filter_map(|line| line.split('`').next())
+            .map(str::to_string)
+            .collect()
+    }
+
+    fn names<T: ParamFragment>(filters: &[T]) -> BTreeSet<String> {
+        filters.iter().map(|f| f.key().into_owned()).collect()
+    }
+
+    /// one sample of every `WorksFilter` variant
+    ///
+    /// The listed variants are also matched exhaustively, so a variant added to the enum does not
+    /// compile until it is listed here and checked against the documentation.
+    macro_rules! all_works_filters {
+        ($($variant:ident $(($sample:expr))?),* $(,)?) => {{
+            #[allow(dead_code)]
+            fn exhaustive(filter: &WorksFilter) {
+                match filter {
+                    $(WorksFilter::$variant { .. } => {})*
+                }
+            }
+            vec![$(WorksFilter::$variant $(($sample))?),*]
+        }};
+    }
+
+    #[test]
+    fn works_filters_match_documentation() {
+        let date = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let s = String::new;
+        let filters = all_works_filters![
+            HasFunder,
+            Funder("100000015".parse().unwrap()),
+            Location(s()),
+            Prefix("10.1016".parse().unwrap()),
+            Member("78".parse().unwrap()),
+            FromIndexDate(date),
+            UntilIndexDate(date),
+            FromDepositDate(date),
+            UntilDepositDate(date),
+            FromUpdateDate(date),
+            UntilUpdateDate(date),
+            FromCreatedDate(date),
+            UntilCreatedDate(date),
+            FromPubDate(date),
+            UntilPubDate(date),
+            FromOnlinePubDate(date),
+            UntilOnlinePubDate(date),
+            FromPrintPubDate(date),
+            UntilPrintPubDate(date),
+            FromPostedDate(date),
+            UntilPostedDate(date),
+            FromAcceptedDate(date),
+            UntilAcceptedDate(date),
+            FromIssuedDate(date),
+            UntilIssuedDate(date),
+            FromApprovedDate(date),
+            UntilApprovedDate(date),
+            FromAwardedDate(date),
+            UntilAwardedDate(date),
+            FromEventStartDate(date),
+            UntilEventStartDate(date),
+            FromEventEndDate(date),
+            UntilEventEndDate(date),
+            HasLicense,
+            LicenseUrl(s()),
+            LicenseVersion(ContentVersion::Vor),
+            LicenseDelay(0),
+            HasFullText,
+            FullTextVersion(ContentVersion::Am),
+            FullTextType(s()),
+            FullTextApplication(IntendedApplication::TextMining),
+            HasReferences,
+            ReferenceVisibility(Visibility::Open),
+            HasArchive,
+            Archive(s()),
+            HasOrcid,
+            HasAuthenticatedOrcid,
+            Orcid("0000-0002-1825-0097".parse().unwrap()),
+            Issn("0003-066X".parse().unwrap()),
+            Isbn("9783161484100".parse().unwrap()),
+            Type(Type::JournalArticle),
+            Directory(s()),
+            Doi("10.1037/abc".parse().unwrap()),
+            Updates("10.1037/abc".parse().unwrap()),
+            IsUpdate,
+            HasUpdate,
+            UpdateType(s()),
+            HasUpdatePolicy,
+            ContainerTitle(s()),
+            CategoryName(s()),
+            TypeName(s()),
+            GroupTitle(s()),
+            AwardNumber(s()),
+            AwardFunder("100000015".parse().unwrap()),
+            HasFunderDoi,
+            FunderDoiAssertedBy(DoiAssertedBy::Publisher),
+            HasAssertion,
+            AssertionGroup(s()),
+            Assertion(s()),
+            HasAffiliation,
+            HasRorId,
+            RorId(s()),
+            AlternativeId(s()),
+            ArticleNumber(s()),
+            HasAbstract,
+            HasDescription,
+            HasClinicalTrialNumber,
+            ContentDomain(s()),
+            HasContentDomain,
+            HasDomainRestriction,
+            HasEvent,
+            HasRelation,
+            RelationType(RelationType::IsPreprintOf),
+            RelationObject(s()),
+            RelationObjectType(RelationIdType::Doi),
+        ];
+
+        assert_eq!(documented_filters("works"), names(&filters));
+    }
+
+    #[test]
+    fn members_filters_match_documentation() {
+        let filters = vec![
+            MembersFilter::HasPublicReferences,
+            MembersFilter::ReferenceVisibility(Visibility::Open),
+            MembersFilter::BackfileDoiCount(0),
+            MembersFilter::CurrentDoiCount(0),
+        ];
+
+        assert_eq!(documented_filters("members"), names(&filters));
+    }
+
+    #[test]
+    fn funders_filters_match_documentation() {
+        let filters = vec![FundersFilter::Location(String::new())];
+
+        assert_eq!(documented_filters("funders"), names(&filters));
+    }
+
+    #[test]
+    fn journals_have_no_filters() {
+        assert!(documented_filters("journals").is_empty());
+    }
+
+    #[test]
+    fn prefixes_have_no_filters() {
+        assert!(documented_filters("prefixes").is_empty());
+    }
+
+    #[test]
+    fn serialize_members_query() {
+        let query = MembersQuery::empty()
+            .name("Society for Leukocyte Biology")
+            .has_public_references()
+            .current_doi_count(10);
+
+        assert_eq!(
+            "/members?query=Society+for+Leukocyte+Biology&filter=has-public-references:true,current-doi-count:10",
+            Members::Query(query).route().unwrap()
+        );
+        assert_eq!("/members/183", Members::id(MemberId::new(183)).route().unwrap());
+    }
+
+    #[test]
+    fn serialize_funders_query() {
+        let query = FundersQuery::new("NSF").location("Norway");
+
+        assert_eq!(
+            "/funders?query=NSF&filter=location:Norway",
+            Funders::Query(query).route().unwrap()
+        );
+    }
+
+    #[test]
+    fn serialize_journals_query() {
+        let query = JournalsQuery::empty()
+            .title("Economic Geography")
+            .result_control(ResultControl::Rows(10));
+
+        assert_eq!(
+            "/journals?query=Economic+Geography&rows=10",
+            Journals::Query(query).route().unwrap()
+        );
+        assert_eq!("/journals", Journals::Query(JournalsQuery::empty()).route().unwrap());
+    }
+
+    #[test]
+    fn serialize_prefixes() {
+        assert_eq!("/prefixes/10.1016", Prefixes::prefix("10.1016".parse().unwrap()).route().unwrap());
+        assert_eq!(
+            "/prefixes/10.1016/works?query=ontologies",
+            Prefixes::works("10.1016".parse().unwrap(), WorksQuery::new("ontologies"))
+                .route()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn serialize_valued_filters() {
+        let filters = vec![
+            WorksFilter::RelationType(RelationType::IsPreprintOf),
+            WorksFilter::RelationObjectType("pmid".into()),
+            WorksFilter::LicenseDelay(30),
+            WorksFilter::FromIssuedDate(NaiveDate::from_ymd_opt(2019, 5, 1).unwrap()),
+            WorksFilter::HasRorId,
+        ];
+
+        assert_eq!(
+            "filter=relation.type:is-preprint-of,relation.object-type:pmid,license.delay:30,from-issued-date:2019-05-01,has-ror-id:true",
+            filters.param()
+        );
+    }
+}
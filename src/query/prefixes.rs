@@ -1,4 +1,5 @@
 use crate::error::Result;
+use crate::ident::Prefix;
 use crate::query::works::{WorksCombiner, WorksFilter, WorksIdentQuery, WorksQuery};
 use crate::query::{Component, CrossrefQuery, CrossrefRoute, ResourceComponent};
 
@@ -6,11 +7,23 @@ use crate::query::{Component, CrossrefQuery, CrossrefRoute, ResourceComponent};
 #[derive(Debug, Clone)]
 pub enum Prefixes {
     /// target a specific member at `/prefixes/{id}`
-    Identifier(String),
+    Identifier(Prefix),
     /// target a `Work` for a specific prefix at `/prefixes/{id}/works?query..`
     Works(WorksIdentQuery),
 }
 
+impl Prefixes {
+    /// create a new `Prefixes::Identifier` for the DOI owner `prefix`, e.g. `10.1016`
+    pub fn prefix(prefix: Prefix) -> Self {
+        Prefixes::Identifier(prefix)
+    }
+
+    /// create a new `Prefixes::Works` that targets the `Work` items of `prefix` matching the `query`
+    pub fn works(prefix: Prefix, query: WorksQuery) -> Self {
+        Prefixes::Works(WorksIdentQuery::new(prefix.to_string(), query))
+    }
+}
+
 impl CrossrefRoute for Prefixes {
     fn route(&self) -> Result<String> {
         match self {
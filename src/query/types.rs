@@ -1,43 +1,40 @@
-use crate::error::{Error, ErrorKind, Result};
+use crate::error::Result;
 use crate::query::works::{WorksCombiner, WorksFilter, WorksIdentQuery, WorksQuery};
 use crate::query::{Component, CrossrefQuery, CrossrefRoute, ResourceComponent};
-use serde::{Deserialize, Serialize};
-use std::str::FromStr;
 
-/// all possible types of a `Work`
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
-#[serde(tag = "id")]
-#[serde(rename_all = "kebab-case")]
-#[allow(missing_docs)]
-pub enum Type {
-    BookSection,
-    Monograph,
-    Report,
-    PeerReview,
-    BookTrack,
-    JournalArticle,
-    BookPart,
-    Other,
-    Book,
-    JournalVolume,
-    BookSet,
-    ReferenceEntry,
-    ProceedingsArticle,
-    Journal,
-    Component,
-    BookChapter,
-    ProceedingsSeries,
-    ReportSeries,
-    Proceedings,
-    Standard,
-    ReferenceBook,
-    PostedContent,
-    JournalIssue,
-    Dissertation,
-    Dataset,
-    BookSeries,
-    EditedBook,
-    StandardSeries,
+string_enum! {
+    /// all possible types of a `Work`
+    #[allow(missing_docs)]
+    pub enum Type {
+        BookSection => "book-section",
+        Monograph => "monograph",
+        Report => "report",
+        PeerReview => "peer-review",
+        BookTrack => "book-track",
+        JournalArticle => "journal-article",
+        BookPart => "book-part",
+        Other => "other",
+        Book => "book",
+        JournalVolume => "journal-volume",
+        BookSet => "book-set",
+        ReferenceEntry => "reference-entry",
+        ProceedingsArticle => "proceedings-article",
+        Journal => "journal",
+        Component => "component",
+        BookChapter => "book-chapter",
+        ProceedingsSeries => "proceedings-series",
+        ReportSeries => "report-series",
+        Proceedings => "proceedings",
+        Standard => "standard",
+        ReferenceBook => "reference-book",
+        PostedContent => "posted-content",
+        JournalIssue => "journal-issue",
+        Dissertation => "dissertation",
+        Dataset => "dataset",
+        BookSeries => "book-series",
+        EditedBook => "edited-book",
+        StandardSeries => "standard-series",
+    }
 }
 
 impl Type {
@@ -72,80 +69,12 @@ impl Type {
             Type::BookSeries => "Book Series",
             Type::EditedBook => "Edited Book",
             Type::StandardSeries => "Standard Series",
+            Type::Unknown(id) => id,
         }
     }
     /// the string used to identify the type
     pub fn id(&self) -> &str {
-        match self {
-            Type::BookSection => "book-section",
-            Type::Monograph => "monograph",
-            Type::Report => "report",
-            Type::PeerReview => "peer-review",
-            Type::BookTrack => "book-track",
-            Type::JournalArticle => "journal-article",
-            Type::BookPart => "book-part",
-            Type::Other => "other",
-            Type::Book => "book",
-            Type::JournalVolume => "journal-volume",
-            Type::BookSet => "book-set",
-            Type::ReferenceEntry => "reference-entry",
-            Type::ProceedingsArticle => "proceedings-article",
-            Type::Journal => "journal",
-            Type::Component => "component",
-            Type::BookChapter => "book-chapter",
-            Type::ProceedingsSeries => "proceedings-series",
-            Type::ReportSeries => "report-series",
-            Type::Proceedings => "proceedings",
-            Type::Standard => "standard",
-            Type::ReferenceBook => "reference-book",
-            Type::PostedContent => "posted-content",
-            Type::JournalIssue => "journal-issue",
-            Type::Dissertation => "dissertation",
-            Type::Dataset => "dataset",
-            Type::BookSeries => "book-series",
-            Type::EditedBook => "edited-book",
-            Type::StandardSeries => "standard-series",
-        }
-    }
-}
-
-impl FromStr for Type {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self> {
-        match s {
-            "book-section" => Ok(Type::BookSection),
-            "monograph" => Ok(Type::Monograph),
-            "report" => Ok(Type::Report),
-            "peer-review" => Ok(Type::PeerReview),
-            "book-track" => Ok(Type::BookTrack),
-            "journal-article" => Ok(Type::JournalArticle),
-            "book-part" => Ok(Type::BookPart),
-            "other" => Ok(Type::Other),
-            "book" => Ok(Type::Book),
-            "journal-volume" => Ok(Type::JournalVolume),
-            "book-set" => Ok(Type::BookSet),
-            "reference-entry" => Ok(Type::ReferenceEntry),
-            "proceedings-article" => Ok(Type::ProceedingsArticle),
-            "journal" => Ok(Type::Journal),
-            "component" => Ok(Type::Component),
-            "book-chapter" => Ok(Type::BookChapter),
-            "proceedings-series" => Ok(Type::ProceedingsSeries),
-            "report-series" => Ok(Type::ReportSeries),
-            "proceedings" => Ok(Type::Proceedings),
-            "standard" => Ok(Type::Standard),
-            "reference-book" => Ok(Type::ReferenceBook),
-            "posted-content" => Ok(Type::PostedContent),
-            "journal-issue" => Ok(Type::JournalIssue),
-            "dissertation" => Ok(Type::Dissertation),
-            "dataset" => Ok(Type::Dataset),
-            "book-series" => Ok(Type::BookSeries),
-            "edited-book" => Ok(Type::EditedBook),
-            "standard-series" => Ok(Type::StandardSeries),
-            name => Err(Error::from(ErrorKind::InvalidTypeName {
-                name: name.to_string(),
-            })),
-        }
+        self.as_str()
     }
 }
 
@@ -179,16 +108,15 @@ impl CrossrefQuery for Types {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde_json::*;
 
-    //    #[test]
+    #[test]
     fn test_types() {
-        let section = r#"{
-    "id": "book-section",
-    "label": "Book Section"
-  }"#;
-        let ref_type: Type = serde_json::from_str(section).unwrap();
-
+        let ref_type: Type = serde_json::from_str(r#""book-section""#).unwrap();
         assert_eq!(Type::BookSection, ref_type);
+        assert_eq!("Book Section", ref_type.label());
+
+        let unknown: Type = "grant".parse().unwrap();
+        assert_eq!(Type::Unknown("grant".to_string()), unknown);
+        assert_eq!(r#""grant""#, serde_json::to_string(&unknown).unwrap());
     }
 }
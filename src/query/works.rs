@@ -1,4 +1,5 @@
 use crate::error::{Error, Result};
+use crate::ident::{Doi, FunderId, Isbn, Issn, MemberId, Orcid, Prefix};
 use crate::query::facet::FacetCount;
 use crate::query::types::Type;
 use crate::query::*;
@@ -144,14 +145,14 @@ pub enum WorksFilter {
     /// metadata which includes one or more funder entry
     HasFunder,
     /// metadata which include the `id` in FundRef data
-    Funder(String),
+    Funder(FunderId),
     /// funder records where location = `{country name}`.
     /// Only works on `/funders` route
     Location(String),
     /// metadata belonging to a DOI owner prefix `{owner_prefix}` (e.g. 10.1016 )
-    Prefix(String),
+    Prefix(Prefix),
     /// metadata belonging to a Crossref member
-    Member(String),
+    Member(MemberId),
     /// metadata indexed since (inclusive)
     FromIndexDate(NaiveDate),
     /// metadata indexed before (inclusive)
@@ -195,17 +196,17 @@ pub enum WorksFilter {
     /// metadata where `<license_ref> value equals the value
     LicenseUrl(String),
     /// metadata where the `<license_ref>`'s applies_to attribute is
-    LicenseVersion(String),
+    LicenseVersion(ContentVersion),
     /// metadata where difference between publication date and the `<license_ref>`'s start_date attribute is <= value (in days)
     LicenseDelay(i32),
     /// metadata that includes any full text `<resource>` elements
     HasFullText,
     /// metadata where `<resource>` element's content_version attribute is the value
-    FullTextVersion(String),
+    FullTextVersion(ContentVersion),
     /// metadata where `<resource>` element's content_type attribute is value (e.g. `application/pdf)`
     FullTextType(String),
     /// metadata where `<resource>` link has one of the following intended applications: `text-mining`, `similarity-checking` or `unspecified`
-    FullTextApplication(String),
+    FullTextApplication(IntendedApplication),
     /// metadata for works that have a list of references
     HasReferences,
     /// metadata for works where references are either `open`, `limited` (to Metadata Plus subscribers) or `closed`
@@ -219,11 +220,11 @@ pub enum WorksFilter {
     /// metadata which includes one or more ORCIDs where the depositing publisher claims to have witness the ORCID owner authenticate with ORCID
     HasAuthenticatedOrcid,
     /// metadata where `<orcid>` element's value = the value
-    Orcid(String),
+    Orcid(Orcid),
     /// metadata where record has an ISSN = the value. Format is xxxx-xxxx
-    Issn(String),
+    Issn(Issn),
     /// metadata where record has an ISBN = the value
-    Isbn(String),
+    Isbn(Isbn),
     /// metadata records whose type = value.
     /// Type must be an ID value from the list of types returned by the `/types` resource
     Type(Type),
@@ -231,9 +232,9 @@ pub enum WorksFilter {
     /// Currently the only supported value is `doaj`
     Directory(String),
     /// metadata describing the DOI
-    Doi(String),
+    Doi(Doi),
     /// metadata for records that represent editorial updates to the DOI
-    Updates(String),
+    Updates(Doi),
     /// metadata for records that represent editorial updates
     IsUpdate,
     /// metadata for records that include a link to an editorial update policy
@@ -250,7 +251,7 @@ pub enum WorksFilter {
     AwardNumber(String),
     /// metadata for records with an award with matching funder.
     /// Optionally combine with `award.number`
-    AwardFunder(String),
+    AwardFunder(FunderId),
     /// metadata for records with any assertions
     HasAssertion,
     /// metadata for records with an assertion in a particular group
@@ -261,9 +262,9 @@ pub enum WorksFilter {
     HasAffiliation,
     /// metadata for records with the given alternative ID,
     /// which may be a publisher-specific ID, or any other identifier a publisher may have provided
-    AlternativeId,
+    AlternativeId(String),
     /// metadata for records with a given article number
-    ArticleNumber,
+    ArticleNumber(String),
     /// metadata for records which include an abstract
     HasAbstract,
     /// metadata for records which include a clinical trial number
@@ -278,11 +279,49 @@ pub enum WorksFilter {
     HasRelation,
     /// One of the relation types from the Crossref relations schema
     /// (e.g. `is-referenced-by`, `is-parent-of`, `is-preprint-of`)
-    RelationType,
+    RelationType(RelationType),
     /// Relations where the object identifier matches the identifier provided
-    RelationObject,
+    RelationObject(String),
     /// One of the identifier types from the Crossref relations schema (e.g. `doi`, `issn`)
-    RelationObjectType(String),
+    RelationObjectType(RelationIdType),
+    /// metadata where issued date is since (inclusive)
+    FromIssuedDate(NaiveDate),
+    /// metadata where issued date is before (inclusive)
+    UntilIssuedDate(NaiveDate),
+    /// metadata where approved date is since (inclusive)
+    FromApprovedDate(NaiveDate),
+    /// metadata where approved date is before (inclusive)
+    UntilApprovedDate(NaiveDate),
+    /// metadata where the award date of a grant is since (inclusive)
+    FromAwardedDate(NaiveDate),
+    /// metadata where the award date of a grant is before (inclusive)
+    UntilAwardedDate(NaiveDate),
+    /// metadata where the start date of the associated event is since (inclusive)
+    FromEventStartDate(NaiveDate),
+    /// metadata where the start date of the associated event is before (inclusive)
+    UntilEventStartDate(NaiveDate),
+    /// metadata where the end date of the associated event is since (inclusive)
+    FromEventEndDate(NaiveDate),
+    /// metadata where the end date of the associated event is before (inclusive)
+    UntilEventEndDate(NaiveDate),
+    /// metadata for records which include event information, such as a conference
+    HasEvent,
+    /// metadata for records which include a description, such as grant descriptions
+    HasDescription,
+    /// metadata for records which include one or more ROR IDs in affiliations or funders
+    HasRorId,
+    /// metadata where a ROR ID equals the value, e.g. `https://ror.org/02mhbdp94`
+    RorId(String),
+    /// metadata for records that have been updated by another record
+    HasUpdate,
+    /// metadata for records that represent editorial updates of the given type, e.g. `retraction`
+    UpdateType(String),
+    /// metadata for posted content with the given group title
+    GroupTitle(String),
+    /// metadata which includes one or more funder entry with a funder DOI
+    HasFunderDoi,
+    /// metadata where the funder DOI was asserted by either `crossref` or `publisher`
+    FunderDoiAssertedBy(DoiAssertedBy),
 }
 
 impl WorksFilter {
@@ -344,17 +383,36 @@ impl WorksFilter {
             WorksFilter::AssertionGroup(_) => "assertion-group",
             WorksFilter::Assertion(_) => "assertion",
             WorksFilter::HasAffiliation => "has-affiliation",
-            WorksFilter::AlternativeId => "alternative-id",
-            WorksFilter::ArticleNumber => "article-number",
+            WorksFilter::AlternativeId(_) => "alternative-id",
+            WorksFilter::ArticleNumber(_) => "article-number",
             WorksFilter::HasAbstract => "has-abstract",
-            WorksFilter::HasClinicalTrialNumber => "has-clinical-trial-number	",
+            WorksFilter::HasClinicalTrialNumber => "has-clinical-trial-number",
             WorksFilter::ContentDomain(_) => "content-domain",
             WorksFilter::HasContentDomain => "has-content-domain",
             WorksFilter::HasDomainRestriction => "has-domain-restriction",
             WorksFilter::HasRelation => "has-relation",
-            WorksFilter::RelationType => "relation.type",
-            WorksFilter::RelationObject => "relation.object",
+            WorksFilter::RelationType(_) => "relation.type",
+            WorksFilter::RelationObject(_) => "relation.object",
             WorksFilter::RelationObjectType(_) => "relation.object-type",
+            WorksFilter::FromIssuedDate(_) => "from-issued-date",
+            WorksFilter::UntilIssuedDate(_) => "until-issued-date",
+            WorksFilter::FromApprovedDate(_) => "from-approved-date",
+            WorksFilter::UntilApprovedDate(_) => "until-approved-date",
+            WorksFilter::FromAwardedDate(_) => "from-awarded-date",
+            WorksFilter::UntilAwardedDate(_) => "until-awarded-date",
+            WorksFilter::FromEventStartDate(_) => "from-event-start-date",
+            WorksFilter::UntilEventStartDate(_) => "until-event-start-date",
+            WorksFilter::FromEventEndDate(_) => "from-event-end-date",
+            WorksFilter::UntilEventEndDate(_) => "until-event-end-date",
+            WorksFilter::HasEvent => "has-event",
+            WorksFilter::HasDescription => "has-description",
+            WorksFilter::HasRorId => "has-ror-id",
+            WorksFilter::RorId(_) => "ror-id",
+            WorksFilter::HasUpdate => "has-update",
+            WorksFilter::UpdateType(_) => "update-type",
+            WorksFilter::GroupTitle(_) => "group-title",
+            WorksFilter::HasFunderDoi => "has-funder-doi",
+            WorksFilter::FunderDoiAssertedBy(_) => "funder-doi-asserted-by",
         }
     }
 }
@@ -366,32 +424,39 @@ impl ParamFragment for WorksFilter {
 
     fn value(&self) -> Option<Cow<str>> {
         match self {
-            WorksFilter::Funder(s)
-            | WorksFilter::Location(s)
-            | WorksFilter::Prefix(s)
-            | WorksFilter::Member(s)
+            WorksFilter::Funder(id) | WorksFilter::AwardFunder(id) => Some(Cow::Borrowed(id.as_str())),
+            WorksFilter::Prefix(prefix) => Some(Cow::Borrowed(prefix.as_str())),
+            WorksFilter::Member(id) => Some(Cow::Owned(id.to_string())),
+            WorksFilter::Orcid(orcid) => Some(Cow::Borrowed(orcid.as_str())),
+            WorksFilter::Issn(issn) => Some(Cow::Borrowed(issn.as_str())),
+            WorksFilter::Isbn(isbn) => Some(Cow::Borrowed(isbn.as_str())),
+            WorksFilter::Doi(doi) | WorksFilter::Updates(doi) => Some(Cow::Borrowed(doi.as_str())),
+            WorksFilter::Location(s)
             | WorksFilter::LicenseUrl(s)
-            | WorksFilter::LicenseVersion(s)
-            | WorksFilter::FullTextVersion(s)
             | WorksFilter::FullTextType(s)
-            | WorksFilter::FullTextApplication(s)
             | WorksFilter::Archive(s)
-            | WorksFilter::Orcid(s)
-            | WorksFilter::Issn(s)
-            | WorksFilter::Isbn(s)
             | WorksFilter::Directory(s)
-            | WorksFilter::Doi(s)
-            | WorksFilter::Updates(s)
             | WorksFilter::ContainerTitle(s)
             | WorksFilter::CategoryName(s)
             | WorksFilter::AwardNumber(s)
             | WorksFilter::TypeName(s)
-            | WorksFilter::AwardFunder(s)
             | WorksFilter::AssertionGroup(s)
             | WorksFilter::Assertion(s)
             | WorksFilter::ContentDomain(s)
-            | WorksFilter::RelationObjectType(s) => Some(Cow::Borrowed(s.as_str())),
+            | WorksFilter::AlternativeId(s)
+            | WorksFilter::ArticleNumber(s)
+            | WorksFilter::RelationObject(s)
+            | WorksFilter::RorId(s)
+            | WorksFilter::UpdateType(s)
+            | WorksFilter::GroupTitle(s) => Some(Cow::Borrowed(s.as_str())),
+            WorksFilter::LicenseVersion(v) | WorksFilter::FullTextVersion(v) => {
+                Some(Cow::Borrowed(v.as_str()))
+            }
+            WorksFilter::FullTextApplication(app) => Some(Cow::Borrowed(app.as_str())),
+            WorksFilter::FunderDoiAssertedBy(by) => Some(Cow::Borrowed(by.as_str())),
             WorksFilter::ReferenceVisibility(vis) => Some(Cow::Borrowed(vis.as_str())),
+            WorksFilter::RelationType(ty) => Some(Cow::Borrowed(ty.as_str())),
+            WorksFilter::RelationObjectType(ty) => Some(Cow::Borrowed(ty.as_str())),
             WorksFilter::FromIndexDate(d)
             | WorksFilter::UntilIndexDate(d)
             | WorksFilter::FromDepositDate(d)
@@ -409,10 +474,21 @@ impl ParamFragment for WorksFilter {
             | WorksFilter::FromPostedDate(d)
             | WorksFilter::UntilPostedDate(d)
             | WorksFilter::FromAcceptedDate(d)
-            | WorksFilter::UntilAcceptedDate(d) => {
+            | WorksFilter::UntilAcceptedDate(d)
+            | WorksFilter::FromIssuedDate(d)
+            | WorksFilter::UntilIssuedDate(d)
+            | WorksFilter::FromApprovedDate(d)
+            | WorksFilter::UntilApprovedDate(d)
+            | WorksFilter::FromAwardedDate(d)
+            | WorksFilter::UntilAwardedDate(d)
+            | WorksFilter::FromEventStartDate(d)
+            | WorksFilter::UntilEventStartDate(d)
+            | WorksFilter::FromEventEndDate(d)
+            | WorksFilter::UntilEventEndDate(d) => {
                 Some(Cow::Owned(d.format("%Y-%m-%d").to_string()))
             }
             WorksFilter::Type(t) => Some(Cow::Borrowed(t.id())),
+            WorksFilter::LicenseDelay(days) => Some(Cow::Owned(days.to_string())),
             _ => Some(Cow::Borrowed("true")),
         }
     }
@@ -573,8 +649,10 @@ impl CrossrefQueryParam for WorkResultControl {
 ///
 /// ```edition2018
 /// use crossref::Works;
-///
-/// let works = Works::doi("10.1037/0003-066X.59.1.29");
+/// # fn run() -> Result<(), crossref::Error> {
+/// let works = Works::doi("10.1037/0003-066X.59.1.29".parse()?);
+/// # Ok(())
+/// # }
 /// ```
 ///
 /// Target the agency of a specific publication, where the str supplied is corresponded to the publication's DOI
@@ -584,26 +662,29 @@ impl CrossrefQueryParam for WorkResultControl {
 /// ```edition2018
 /// use crossref::Works;
 ///
-/// let works = Works::agency_for_doi("10.1037/0003-066X.59.1.29");
+/// # fn run() -> Result<(), crossref::Error> {
+/// let works = Works::agency_for_doi("10.1037/0003-066X.59.1.29".parse()?);
+/// # Ok(())
+/// # }
 /// ```
 #[derive(Debug, Clone)]
 pub enum Works {
     /// target a Work by a specific id
-    Identifier(String),
+    Identifier(Doi),
     /// target Works by a query
     Query(WorksQuery),
     /// return the registration agency for a DOI
-    Agency(String),
+    Agency(Doi),
 }
 
 impl Works {
-    /// create a new `Works::Identifier` by converting `doi` to a `String`
-    pub fn doi(doi: &str) -> Self {
-        Works::Identifier(doi.to_string())
+    /// create a new `Works::Identifier` for the `doi`
+    pub fn doi(doi: Doi) -> Self {
+        Works::Identifier(doi)
     }
     /// create a new `Works::Agency` targeting the registration agency for the DOI
-    pub fn agency_for_doi(doi: &str) -> Self {
-        Works::Agency(doi.to_string())
+    pub fn agency_for_doi(doi: Doi) -> Self {
+        Works::Agency(doi)
     }
 }
 
@@ -835,11 +916,12 @@ impl WorksQuery {
         self
     }
 
-        /// select which fields to return
-        pub fn elements(mut self, element: Vec<WorkElement>) -> Self {
-            self.elements.extend(element.into_iter());
-            self
-        }
+    /// select which fields to return.
+    /// The results can only be retrieved as `PartialWork` with [crate::Crossref::works_partial]
+    pub fn elements(mut self, element: Vec<WorkElement>) -> Self {
+        self.elements.extend(element.into_iter());
+        self
+    }
 
     /// ```edition2018
     /// use crossref::{FieldQuery,WorksQuery};
@@ -971,12 +1053,33 @@ pub struct WorksQuery {
     pub sample: Option<usize>,
 }
 
+impl WorksQuery {
+    /// the `select` parameter for the selected elements, if any
+    fn select_param(&self) -> Option<String> {
+        if self.elements.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "select={}",
+            self.elements
+                .iter()
+                .map(WorkElement::name)
+                .collect::<Vec<_>>()
+                .join(",")
+        ))
+    }
+}
+
 impl CrossrefRoute for WorksQuery {
     fn route(&self) -> Result<String> {
         let mut params = Vec::new();
 
         if let Some(sample) = self.sample {
-            return Ok(format!("sample={}", sample));
+            // only `select` is supported alongside `sample`
+            return Ok(match self.select_param() {
+                Some(select) => format!("{}?sample={}&{}", Component::Works.route()?, sample, select),
+                None => format!("{}?sample={}", Component::Works.route()?, sample),
+            });
         }
 
         if !self.free_form_queries.is_empty() {
@@ -991,15 +1094,8 @@ impl CrossrefRoute for WorksQuery {
         if !self.filter.is_empty() {
             params.push(self.filter.param());
         }
-        if !self.elements.is_empty() {
-    
-            let mut elements = self.elements.iter().fold("select=".to_string(), |mut acc, e| {
-                acc.push_str(e.name());
-                acc.push_str(",");
-                acc
-            });
-            elements.pop();
-            params.push(Cow::Owned(elements));
+        if let Some(select) = self.select_param() {
+            params.push(Cow::Owned(select));
         }
         if !self.facets.is_empty() {
             params.push(self.facets.param());
@@ -1055,8 +1151,24 @@ mod tests {
 
     #[test]
     fn serialize_works_ident() {
-        let works = Works::doi("10.1037/0003-066X.59.1.29");
+        let works = Works::doi("10.1037/0003-066X.59.1.29".parse().unwrap());
+
+        assert_eq!("/works/10.1037/0003-066x.59.1.29", &works.route().unwrap())
+    }
 
-        assert_eq!("/works/10.1037/0003-066X.59.1.29", &works.route().unwrap())
+    #[test]
+    fn serialize_select() {
+        let query = WorksQuery::new("ontologies").elements(vec![
+            WorkElement::DOI,
+            WorkElement::Title,
+            WorkElement::Author,
+        ]);
+        assert_eq!(
+            "/works?query=ontologies&select=DOI,title,author",
+            &query.route().unwrap()
+        );
+
+        let sample = WorksQuery::random(10).elements(vec![WorkElement::DOI]);
+        assert_eq!("/works?sample=10&select=DOI", &sample.route().unwrap());
     }
 }
@@ -0,0 +1,97 @@
+//! Spaces out the requests of a `Crossref` client according to the limits crossref announces with
+//! the `x-rate-limit-limit` and `x-rate-limit-interval` headers of each response.
+
+use reqwest::header::HeaderMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// shared by all clones of a `Crossref`, so that they draw from the same budget.
+///
+/// Every host announces its own limits, so each host needs its own `RateLimiter`.
+#[derive(Debug, Default)]
+pub(crate) struct RateLimiter {
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// the minimum time between two requests, `None` until crossref announced a limit
+    spacing: Option<Duration>,
+    /// the earliest instant the next request may be sent
+    next: Option<Instant>,
+}
+
+impl RateLimiter {
+    /// waits until the next request may be sent and reserves that slot
+    pub async fn acquire(&self) {
+        let wait = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            let Some(spacing) = state.spacing else {
+                return;
+            };
+            let now = Instant::now();
+            let slot = state.next.map_or(now, |next| next.max(now));
+            state.next = Some(slot + spacing);
+            slot - now
+        };
+        if !wait.is_zero() {
+            tracing::debug!(?wait, "waiting for the crossref rate limit");
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// updates the limit from the headers of a response, responses without the headers keep the last limit
+    pub fn update(&self, headers: &HeaderMap) {
+        if let Some(spacing) = spacing(headers) {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            state.spacing = Some(spacing);
+        }
+    }
+}
+
+/// `interval / limit` from the headers, e.g. `50` requests per `1s` are 20ms apart
+fn spacing(headers: &HeaderMap) -> Option<Duration> {
+    let limit: u32 = headers
+        .get("x-rate-limit-limit")?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .filter(|limit| *limit > 0)?;
+    let interval = parse_interval(headers.get("x-rate-limit-interval")?.to_str().ok()?)?;
+    Some(interval / limit)
+}
+
+/// parses intervals like `1s`, `500ms` or `1m`, a number without unit is taken as seconds
+fn parse_interval(interval: &str) -> Option<Duration> {
+    let interval = interval.trim();
+    let split = interval
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(interval.len());
+    let (value, unit) = interval.split_at(split);
+    let value: u64 = value.parse().ok()?;
+    Some(match unit.trim() {
+        "ms" => Duration::from_millis(value),
+        "" | "s" => Duration::from_secs(value),
+        "m" => Duration::from_secs(value * 60),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spacing_from_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(None, spacing(&headers));
+        headers.insert("x-rate-limit-limit", "50".parse().unwrap());
+        headers.insert("x-rate-limit-interval", "1s".parse().unwrap());
+        assert_eq!(Some(Duration::from_millis(20)), spacing(&headers));
+        assert_eq!(Some(Duration::from_millis(500)), parse_interval("500ms"));
+        assert_eq!(Some(Duration::from_secs(120)), parse_interval("2m"));
+        assert_eq!(None, parse_interval("soon"));
+    }
+}
@@ -0,0 +1,388 @@
+//! Offline [BibTeX](http://www.bibtex.org/Format/) export of `Work` items, without a content
+//! negotiation round-trip per DOI.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::query::Type;
+use crate::response::work::{Contributor, Work};
+use crate::xml;
+
+/// How the citekey of an entry is built
+#[derive(Debug, Clone, Copy, Default)]
+pub enum CitekeyStyle {
+    /// `Smith2019`, `Smith&Jones2019` or `SmithEtAl2019`, falls back to `Doi` without authors or year
+    #[default]
+    AuthorYear,
+    /// the DOI with every character that is not allowed in a citekey replaced by `_`
+    Doi,
+    /// a custom key, `None` falls back to `AuthorYear`
+    Custom(fn(&Work) -> Option<String>),
+}
+
+/// A BibTeX entry like `@article{Smith2019, ...}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BibEntry {
+    /// the entry type like `article` or `inproceedings`
+    pub entry_type: &'static str,
+    /// the citekey
+    pub key: String,
+    /// the fields in the order they are written, values are already escaped for LaTeX
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl BibEntry {
+    /// the value of the field `name`
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(k, _)| *k == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+impl fmt::Display for BibEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "@{}{{{},", self.entry_type, self.key)?;
+        for (name, value) in &self.fields {
+            if *name == "month" {
+                // the month macros `jan`...`dec` are written without braces
+                writeln!(f, "  {} = {},", name, value)?;
+            } else {
+                writeln!(f, "  {} = {{{}}},", name, value)?;
+            }
+        }
+        write!(f, "}}")
+    }
+}
+
+/// Writes `Work` items as BibTeX entries.
+///
+/// # Example
+///
+/// ```edition2018
+/// use crossref_rs::response::bibtex::{BibtexWriter, CitekeyStyle};
+/// # fn run(works: &[crossref_rs::Work]) {
+/// let bib = BibtexWriter::new().citekey(CitekeyStyle::Doi).write(works);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BibtexWriter {
+    citekey: CitekeyStyle,
+}
+
+impl BibtexWriter {
+    /// a writer with `AuthorYear` citekeys
+    pub fn new() -> Self {
+        BibtexWriter::default()
+    }
+
+    /// set how citekeys are built
+    pub fn citekey(mut self, citekey: CitekeyStyle) -> Self {
+        self.citekey = citekey;
+        self
+    }
+
+    /// the entry for a single `work`
+    pub fn entry(&self, work: &Work) -> BibEntry {
+        let entry_type = entry_type(&work.type_);
+        let mut fields: Vec<(&'static str, String)> = Vec::new();
+        let mut push = |name: &'static str, value: Option<String>| {
+            if let Some(value) = value.filter(|v| !v.is_empty()) {
+                fields.push((name, value));
+            }
+        };
+
+        push("author", names(work.author.as_deref()));
+        push("editor", names(work.editor.as_deref()));
+        push("title", work.full_title().map(|t| escape(&t)));
+        let container = first(&work.container_title).map(|t| escape(&xml::strip_tags(t)));
+        match entry_type {
+            "article" => push("journal", container),
+            "inproceedings" | "incollection" => push("booktitle", container),
+            "book" => push("series", container),
+            _ => {}
+        }
+        push("volume", work.volume.as_deref().map(escape));
+        push("number", work.issue.as_deref().map(escape));
+        push(
+            "pages",
+            work.page_range().map(|range| match range {
+                (first, Some(last)) => format!("{}--{}", escape(first), escape(last)),
+                (first, None) => escape(first),
+            }),
+        );
+        let date = work.publication_date();
+        push("year", date.as_ref().map(|d| d.year().to_string()));
+        push(
+            "month",
+            date.and_then(|d| d.month())
+                .and_then(|m| MONTHS.get(m as usize - 1))
+                .map(|m| m.to_string()),
+        );
+        let publisher = Some(escape(&work.publisher));
+        match entry_type {
+            "phdthesis" => push("school", publisher),
+            "techreport" => push("institution", publisher),
+            _ => push("publisher", publisher),
+        }
+        push(
+            "issn",
+            work.issn
+                .as_ref()
+                .map(|issn| issn.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")),
+        );
+        push(
+            "isbn",
+            work.isbn
+                .as_ref()
+                .map(|isbn| isbn.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")),
+        );
+        // doi and url are verbatim fields and must not be escaped
+        push("doi", Some(work.doi.to_string()));
+        push("url", work.url.clone());
+
+        BibEntry {
+            entry_type,
+            key: self.key(work),
+            fields,
+        }
+    }
+
+    /// the entries for all `works`, citekeys that occur more than once get the suffixes `a`, `b`, ...
+    pub fn entries(&self, works: &[Work]) -> Vec<BibEntry> {
+        let mut entries: Vec<BibEntry> = works.iter().map(|work| self.entry(work)).collect();
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for entry in &entries {
+            *counts.entry(entry.key.clone()).or_default() += 1;
+        }
+        let mut used: HashSet<String> = counts
+            .iter()
+            .filter(|(_, count)| **count == 1)
+            .map(|(key, _)| key.clone())
+            .collect();
+        for entry in entries.iter_mut().filter(|e| counts[&e.key] > 1) {
+            let key = (0..)
+                .map(|i| format!("{}{}", entry.key, suffix(i)))
+                .find(|key| !used.contains(key))
+                .expect("unbounded suffixes");
+            used.insert(key.clone());
+            entry.key = key;
+        }
+        entries
+    }
+
+    /// all `works` as a BibTeX file
+    pub fn write(&self, works: &[Work]) -> String {
+        self.entries(works)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n\n")
+            + "\n"
+    }
+
+    fn key(&self, work: &Work) -> String {
+        let key = match self.citekey {
+            CitekeyStyle::AuthorYear => None,
+            CitekeyStyle::Doi => Some(work.doi.to_string()),
+            CitekeyStyle::Custom(f) => f(work),
+        };
+        let key = key
+            .or_else(|| to_citekey(work.author.as_deref().unwrap_or_default(), work))
+            .unwrap_or_else(|| work.doi.to_string());
+        key.chars()
+            .map(|c| {
+                if c.is_alphanumeric() || matches!(c, '&' | '-' | '_' | ':' | '.' | '/') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+}
+
+impl Work {
+    /// this work as BibTeX entry with an `AuthorYear` citekey, see [BibtexWriter] for other citekeys
+    /// and disambiguated keys for many works
+    pub fn to_bibtex(&self) -> String {
+        BibtexWriter::new().entry(self).to_string()
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+/// `a`...`z`, `aa`, `ab`, ...
+fn suffix(mut i: usize) -> String {
+    let mut s = Vec::new();
+    loop {
+        s.push(b'a' + (i % 26) as u8);
+        if i < 26 {
+            break;
+        }
+        i = i / 26 - 1;
+    }
+    s.reverse();
+    String::from_utf8(s).expect("ascii")
+}
+
+// AuthorYear (e.g. Smith2019) or Author&AuthorYear (e.g. Smith&Jones2019) or AuthorEtAlYear (e.g. SmithEtAl2019)
+fn to_citekey(authors: &[Contributor], work: &Work) -> Option<String> {
+    let family = |i: usize| {
+        let c = &authors[i];
+        c.family.clone().or_else(|| c.name.clone()).unwrap_or_default()
+    };
+    let authors = match authors.len() {
+        0 => return None,
+        1 => family(0),
+        2 => format!("{}&{}", family(0), family(1)),
+        _ => format!("{}EtAl", family(0)),
+    };
+    let year = work.publication_date()?.year();
+    Some(format!("{}{}", authors.replace(' ', ""), year))
+}
+
+fn entry_type(type_: &Type) -> &'static str {
+    match type_ {
+        Type::JournalArticle => "article",
+        Type::ProceedingsArticle => "inproceedings",
+        Type::Book | Type::Monograph | Type::EditedBook | Type::ReferenceBook => "book",
+        Type::BookChapter
+        | Type::BookSection
+        | Type::BookPart
+        | Type::BookTrack
+        | Type::ReferenceEntry => "incollection",
+        Type::Dissertation => "phdthesis",
+        Type::Report => "techreport",
+        _ => "misc",
+    }
+}
+
+fn first(values: &Option<Vec<String>>) -> Option<&str> {
+    values.as_ref()?.first().map(String::as_str)
+}
+
+/// `Family, Given and Family, Given`, organisations are braced so BibTeX does not split them
+fn names(contributors: Option<&[Contributor]>) -> Option<String> {
+    let names: Vec<String> = contributors?
+        .iter()
+        .filter_map(|c| match &c.family {
+            Some(_) => c.display_name().map(|name| escape(&name)),
+            None => c.name.as_ref().map(|name| format!("{{{}}}", escape(name))),
+        })
+        .collect();
+    if names.is_empty() {
+        None
+    } else {
+        Some(names.join(" and "))
+    }
+}
+
+/// escape the LaTeX special characters of `input`
+pub fn escape(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '\\' => out.push_str("\\textbackslash{}"),
+            '{' | '}' | '&' | '%' | '$' | '#' | '_' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '~' => out.push_str("\\textasciitilde{}"),
+            '^' => out.push_str("\\textasciicircum{}"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::work_fixture;
+    use serde_json::json;
+
+    fn work(doi: &str, family: &str) -> Work {
+        work_fixture(json!({
+            "DOI": doi,
+            "publisher": "Smith & Sons",
+            "title": ["Growth of <i>E. coli</i> at 100%"],
+            "subtitle": ["A case_study"],
+            "container-title": ["Journal of Things"],
+            "volume": "12",
+            "issue": "3",
+            "page": "10-20",
+            "ISSN": ["0317-8471"],
+            "URL": format!("http://dx.doi.org/{}", doi),
+            "author": [
+                {"family": family, "given": "Jane", "sequence": "first", "affiliation": []},
+                {"name": "The Consortium", "sequence": "additional", "affiliation": []}
+            ],
+            "published-print": {"date-parts": [[2019, 4]]},
+        }))
+    }
+
+    #[test]
+    fn work_to_bibtex() {
+        let entry = BibtexWriter::new().entry(&work("10.1000/a_1", "Smith"));
+
+        assert_eq!("article", entry.entry_type);
+        assert_eq!("Smith&TheConsortium2019", entry.key);
+        assert_eq!(Some("Smith, Jane and {The Consortium}"), entry.field("author"));
+        assert_eq!(
+            Some("Growth of E. coli at 100\\%: A case\\_study"),
+            entry.field("title")
+        );
+        assert_eq!(Some("10--20"), entry.field("pages"));
+        assert_eq!(Some("apr"), entry.field("month"));
+        assert_eq!(Some("Smith \\& Sons"), entry.field("publisher"));
+        assert_eq!(Some("10.1000/a_1"), entry.field("doi"));
+
+        let bib = work("10.1000/a_1", "Smith").to_bibtex();
+        assert!(bib.starts_with("@article{Smith&TheConsortium2019,\n  author = {Smith, Jane and {The Consortium}},\n"));
+        assert!(bib.contains("  month = apr,\n"));
+        assert!(bib.ends_with("}"));
+    }
+
+    #[test]
+    fn disambiguate_citekeys() {
+        let works = vec![
+            work("10.1000/a", "Smith"),
+            work("10.1000/b", "Jones"),
+            work("10.1000/c", "Smith"),
+        ];
+        let keys: Vec<String> = BibtexWriter::new()
+            .entries(&works)
+            .into_iter()
+            .map(|e| e.key)
+            .collect();
+        assert_eq!(vec![
+                "Smith&TheConsortium2019a",
+                "Jones&TheConsortium2019",
+                "Smith&TheConsortium2019b"
+            ], keys);
+
+        let keys: Vec<String> = BibtexWriter::new()
+            .citekey(CitekeyStyle::Custom(|w| w.volume.clone()))
+            .entries(&works)
+            .into_iter()
+            .map(|e| e.key)
+            .collect();
+        assert_eq!(vec!["12a", "12b", "12c"], keys);
+        assert_eq!("m", suffix(12));
+        assert_eq!("ab", suffix(27));
+    }
+
+    #[test]
+    fn citekey_of_organisation() {
+        let work = work_fixture(json!({
+            "author": [{"name": "The Consortium", "sequence": "first", "affiliation": []}],
+            "issued": {"date-parts": [[2019]]},
+        }));
+        let authors = work.author.as_deref().unwrap();
+        assert_eq!(Some("TheConsortium2019".to_string()), to_citekey(authors, &work));
+    }
+}
@@ -0,0 +1,488 @@
+//! Local conversion between `Work` items and [CSL-JSON](https://citeproc-js.readthedocs.io/en/latest/csl-json/markup.html),
+//! the input format of citeproc engines and Zotero.
+
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::ident::{Doi, Isbn, Issn};
+use crate::query::Type;
+use crate::response::work::{
+    split_page_range, Contributor, ContributorSequence, CrossrefDate, PartialWork, Work,
+};
+use crate::xml;
+
+string_enum! {
+    /// The item types of the [CSL specification](https://docs.citationstyles.org/en/stable/specification.html#appendix-iii-types)
+    #[allow(missing_docs)]
+    #[derive(Default)]
+    pub enum CslType {
+        Article => "article",
+        ArticleJournal => "article-journal",
+        ArticleMagazine => "article-magazine",
+        ArticleNewspaper => "article-newspaper",
+        Book => "book",
+        Chapter => "chapter",
+        Dataset => "dataset",
+        #[default]
+        Document => "document",
+        Entry => "entry",
+        EntryDictionary => "entry-dictionary",
+        EntryEncyclopedia => "entry-encyclopedia",
+        PaperConference => "paper-conference",
+        Periodical => "periodical",
+        Report => "report",
+        Review => "review",
+        Standard => "standard",
+        Thesis => "thesis",
+        Webpage => "webpage",
+    }
+}
+
+impl From<&Type> for CslType {
+    fn from(type_: &Type) -> Self {
+        match type_ {
+            Type::JournalArticle => CslType::ArticleJournal,
+            Type::ProceedingsArticle => CslType::PaperConference,
+            Type::Book | Type::Monograph | Type::EditedBook | Type::ReferenceBook | Type::BookSet => {
+                CslType::Book
+            }
+            Type::BookChapter | Type::BookSection | Type::BookPart | Type::BookTrack => {
+                CslType::Chapter
+            }
+            Type::ReferenceEntry => CslType::EntryEncyclopedia,
+            Type::Dissertation => CslType::Thesis,
+            Type::Report | Type::ReportSeries => CslType::Report,
+            Type::Dataset => CslType::Dataset,
+            Type::PostedContent => CslType::Article,
+            Type::Standard | Type::StandardSeries => CslType::Standard,
+            Type::PeerReview => CslType::Review,
+            Type::Journal | Type::JournalIssue | Type::JournalVolume => CslType::Periodical,
+            _ => CslType::Document,
+        }
+    }
+}
+
+impl From<&CslType> for Type {
+    fn from(type_: &CslType) -> Self {
+        match type_ {
+            CslType::ArticleJournal | CslType::ArticleMagazine | CslType::ArticleNewspaper => {
+                Type::JournalArticle
+            }
+            CslType::PaperConference => Type::ProceedingsArticle,
+            CslType::Book => Type::Book,
+            CslType::Chapter => Type::BookChapter,
+            CslType::Entry | CslType::EntryDictionary | CslType::EntryEncyclopedia => {
+                Type::ReferenceEntry
+            }
+            CslType::Thesis => Type::Dissertation,
+            CslType::Report => Type::Report,
+            CslType::Dataset => Type::Dataset,
+            CslType::Article => Type::PostedContent,
+            CslType::Standard => Type::Standard,
+            CslType::Review => Type::PeerReview,
+            CslType::Periodical => Type::Journal,
+            _ => Type::Other,
+        }
+    }
+}
+
+/// A name of a CSL item, either split into its parts or as `literal` for organisations
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct CslName {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub given: Option<String>,
+    /// a particle that is dropped when only the family name is shown, like `van` in `Ludwig van Beethoven`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dropping_particle: Option<String>,
+    /// a particle that is kept with the family name, like `de` in `Alexis de Tocqueville`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub non_dropping_particle: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub literal: Option<String>,
+}
+
+impl From<&Contributor> for CslName {
+    fn from(contributor: &Contributor) -> Self {
+        let Some(family) = &contributor.family else {
+            return CslName {
+                literal: contributor.name.clone(),
+                ..CslName::default()
+            };
+        };
+        // leading lowercase words of the family name are particles, `van der Berg`
+        let words: Vec<&str> = family.split_whitespace().collect();
+        let particles = words
+            .iter()
+            .take(words.len().saturating_sub(1))
+            .take_while(|w| w.starts_with(char::is_lowercase))
+            .count();
+        let join = |words: &[&str]| Some(words.join(" ")).filter(|s| !s.is_empty());
+        CslName {
+            family: join(&words[particles..]),
+            given: contributor.given.clone(),
+            non_dropping_particle: join(&words[..particles]),
+            suffix: contributor.suffix.clone(),
+            ..CslName::default()
+        }
+    }
+}
+
+impl CslName {
+    /// the contributor this name describes, particles become part of the family name again
+    pub fn to_contributor(&self, sequence: ContributorSequence) -> Contributor {
+        let family = [&self.dropping_particle, &self.non_dropping_particle, &self.family]
+            .iter()
+            .filter_map(|part| part.as_deref())
+            .collect::<Vec<_>>()
+            .join(" ");
+        Contributor {
+            prefix: None,
+            suffix: self.suffix.clone(),
+            family: Some(family).filter(|f| !f.is_empty()),
+            given: self.given.clone(),
+            name: self.literal.clone(),
+            orcid: None,
+            authenticated_orcid: None,
+            affiliation: Vec::new(),
+            sequence,
+        }
+    }
+}
+
+/// A CSL date, one `date-parts` entry for a single date or two for a range
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CslDate {
+    /// `[[year, month, day]]`, month and day are optional
+    #[serde(default, deserialize_with = "deserialize_date_parts", skip_serializing_if = "Vec::is_empty")]
+    pub date_parts: Vec<Vec<i32>>,
+    /// an unparsed date, like `2019-04`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<String>,
+    /// a date that is shown as it is, like `Spring 2019`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub literal: Option<String>,
+}
+
+impl CslDate {
+    /// the first date of `date-parts`, or the `raw` date if it is in ISO-8601 form
+    pub fn date(&self) -> Option<CrossrefDate> {
+        match self.date_parts.first() {
+            Some(parts) => {
+                let parts: Vec<Option<u32>> =
+                    parts.iter().map(|p| u32::try_from(*p).ok()).collect();
+                CrossrefDate::from_parts(&parts)
+            }
+            None => self.raw.as_deref()?.parse().ok(),
+        }
+    }
+}
+
+impl From<CrossrefDate> for CslDate {
+    fn from(date: CrossrefDate) -> Self {
+        CslDate {
+            date_parts: vec![date
+                .to_parts()
+                .into_iter()
+                .flatten()
+                .map(|p| p as i32)
+                .collect()],
+            ..CslDate::default()
+        }
+    }
+}
+
+/// A CSL-JSON item
+#[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct CslItem {
+    #[serde(deserialize_with = "deserialize_string")]
+    pub id: String,
+    #[serde(rename = "type", default)]
+    pub type_: CslType,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub author: Vec<CslName>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub editor: Vec<CslName>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub translator: Vec<CslName>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_short: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container_title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container_title_short: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_opt_string", skip_serializing_if = "Option::is_none")]
+    pub volume: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_opt_string", skip_serializing_if = "Option::is_none")]
+    pub issue: Option<String>,
+    /// the page range like `10-20`
+    #[serde(default, deserialize_with = "deserialize_opt_string", skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_opt_string", skip_serializing_if = "Option::is_none")]
+    pub page_first: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_opt_string", skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issued: Option<CslDate>,
+    #[serde(rename = "DOI", default, skip_serializing_if = "Option::is_none")]
+    pub doi: Option<String>,
+    #[serde(rename = "ISSN", default, skip_serializing_if = "Option::is_none")]
+    pub issn: Option<String>,
+    #[serde(rename = "ISBN", default, skip_serializing_if = "Option::is_none")]
+    pub isbn: Option<String>,
+    #[serde(rename = "URL", default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(rename = "abstract", default, skip_serializing_if = "Option::is_none")]
+    pub abstract_: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// comma separated keywords
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<String>,
+    /// all variables this crate has no field for
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl CslItem {
+    /// the first and the last page of `page`, en dashes are read like `-`
+    pub fn page_range(&self) -> Option<(&str, Option<&str>)> {
+        self.page.as_deref().map(split_page_range)
+    }
+
+    /// the fields of a `Work` this item describes, the id is taken as DOI if the item has none
+    pub fn to_partial_work(&self) -> PartialWork {
+        let names = |names: &[CslName]| {
+            Some(
+                names
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| {
+                        let sequence = if i == 0 {
+                            ContributorSequence::First
+                        } else {
+                            ContributorSequence::Additional
+                        };
+                        name.to_contributor(sequence)
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .filter(|n| !n.is_empty())
+        };
+        let list = |value: &Option<String>| {
+            value.as_ref().map(|v| {
+                v.split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+        };
+        let issued = self.issued.as_ref().and_then(CslDate::date);
+
+        PartialWork {
+            doi: self
+                .doi
+                .as_deref()
+                .or(Some(self.id.as_str()))
+                .and_then(|doi| doi.parse::<Doi>().ok()),
+            type_: Some(Type::from(&self.type_)),
+            title: self.title.clone().map(|t| vec![t]),
+            short_title: self.title_short.clone().map(|t| vec![t]),
+            container_title: self.container_title.clone().map(|t| vec![t]),
+            short_container_title: self.container_title_short.clone().map(|t| vec![t]),
+            publisher: self.publisher.clone(),
+            author: names(&self.author),
+            editor: names(&self.editor),
+            translator: names(&self.translator),
+            volume: self.volume.clone(),
+            issue: self.issue.clone(),
+            page: self.page.clone().or_else(|| self.page_first.clone()),
+            issued,
+            issn: list(&self.issn)
+                .map(|issn| issn.iter().filter_map(|s| s.parse::<Issn>().ok()).collect()),
+            isbn: list(&self.isbn)
+                .map(|isbn| isbn.iter().filter_map(|s| s.parse::<Isbn>().ok()).collect()),
+            url: self.url.clone(),
+            abstract_: self.abstract_.clone(),
+            language: self.language.clone(),
+            subject: list(&self.keyword),
+            ..PartialWork::default()
+        }
+    }
+}
+
+impl From<&Work> for CslItem {
+    fn from(work: &Work) -> Self {
+        let names = |contributors: &Option<Vec<Contributor>>| {
+            contributors.iter().flatten().map(CslName::from).collect()
+        };
+        let first = |values: &Option<Vec<String>>| {
+            values
+                .as_ref()
+                .and_then(|v| v.first())
+                .map(|s| xml::strip_tags(s))
+        };
+        let join = |values: Vec<String>| Some(values.join(", ")).filter(|s| !s.is_empty());
+        let page = work.page.as_ref().or(work.article_number.as_ref()).cloned();
+
+        CslItem {
+            id: work.doi.to_string(),
+            type_: CslType::from(&work.type_),
+            author: names(&work.author),
+            editor: names(&work.editor),
+            translator: names(&work.translator),
+            title: work.full_title(),
+            title_short: first(&work.short_title),
+            container_title: first(&work.container_title),
+            container_title_short: first(&work.short_container_title),
+            publisher: Some(work.publisher.clone()).filter(|p| !p.is_empty()),
+            volume: work.volume.clone(),
+            issue: work.issue.clone(),
+            page_first: work.page_range().map(|(first, _)| first.to_string()),
+            page,
+            number: None,
+            issued: work.publication_date().map(CslDate::from),
+            doi: Some(work.doi.to_string()),
+            issn: join(work.issn.iter().flatten().map(ToString::to_string).collect()),
+            isbn: join(work.isbn.iter().flatten().map(ToString::to_string).collect()),
+            url: work.url.clone(),
+            abstract_: work.parsed_abstract().map(|a| a.to_plain_text()),
+            language: work.language.clone(),
+            keyword: join(work.subject.clone().unwrap_or_default()),
+            extra: Map::new(),
+        }
+    }
+}
+
+impl Work {
+    /// this work as CSL-JSON item
+    pub fn to_csl(&self) -> CslItem {
+        CslItem::from(self)
+    }
+}
+
+/// CSL variables may be numbers or strings
+fn deserialize_opt_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => None,
+        Some(Value::String(s)) => Some(s),
+        Some(Value::Number(n)) => Some(n.to_string()),
+        Some(other) => return Err(de::Error::custom(format!("expected a string, got {}", other))),
+    })
+}
+
+fn deserialize_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_opt_string(deserializer)?.ok_or_else(|| de::Error::custom("expected a string"))
+}
+
+/// date parts may be numbers or numeric strings
+fn deserialize_date_parts<'de, D>(deserializer: D) -> Result<Vec<Vec<i32>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let parts: Vec<Vec<Value>> = Deserialize::deserialize(deserializer)?;
+    parts
+        .into_iter()
+        .map(|date| {
+            date.into_iter()
+                .map(|part| match &part {
+                    Value::Number(n) => n.as_i64().map(|n| n as i32),
+                    Value::String(s) => s.trim().parse().ok(),
+                    _ => None,
+                })
+                .map(|part| part.ok_or_else(|| de::Error::custom("invalid date part")))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::work_fixture;
+    use serde_json::json;
+
+    #[test]
+    fn work_to_csl() {
+        let csl = work_fixture(json!({
+            "DOI": "10.1000/xyz",
+            "title": ["On <i>things</i>"],
+            "type": "proceedings-article",
+            "container-title": ["Proceedings of Things"],
+            "page": "10-20",
+            "author": [
+                {"family": "van der Berg", "given": "Anna", "sequence": "first", "affiliation": []},
+                {"name": "The Consortium", "sequence": "additional", "affiliation": []}
+            ],
+            "published-online": {"date-parts": [[2019, 4, 2]]},
+        }))
+        .to_csl();
+
+        assert_eq!(CslType::PaperConference, csl.type_);
+        assert_eq!(Some("Berg"), csl.author[0].family.as_deref());
+        assert_eq!(Some("van der"), csl.author[0].non_dropping_particle.as_deref());
+        assert_eq!(Some("The Consortium"), csl.author[1].literal.as_deref());
+        assert_eq!(Some("10"), csl.page_first.as_deref());
+
+        let json = serde_json::to_value(&csl).unwrap();
+        assert_eq!(json!("paper-conference"), json["type"]);
+        assert_eq!(json!("On things"), json["title"]);
+        assert_eq!(json!("Proceedings of Things"), json["container-title"]);
+        assert_eq!(json!({"date-parts": [[2019, 4, 2]]}), json["issued"]);
+        assert_eq!(json!("10.1000/xyz"), json["DOI"]);
+    }
+
+    #[test]
+    fn csl_to_partial_work() {
+        let item: CslItem = serde_json::from_value(json!({
+            "id": "item-1",
+            "type": "article-journal",
+            "author": [{"family": "Beethoven", "given": "Ludwig", "dropping-particle": "van"}],
+            "container-title": "Journal of Music",
+            "volume": 12,
+            "page": "3–9",
+            "issued": {"date-parts": [["2019", "4"]]},
+            "DOI": "10.1000/abc",
+            "ISSN": "0317-8471",
+            "keyword": "music, history",
+            "note": "kept"
+        }))
+        .unwrap();
+
+        assert_eq!(Some(("3", Some("9"))), item.page_range());
+        assert_eq!(Some(&json!("kept")), item.extra.get("note"));
+        let work = item.to_partial_work();
+        assert_eq!(Some(Type::JournalArticle), work.type_);
+        assert_eq!(Some("10.1000/abc".parse().unwrap()), work.doi);
+        assert_eq!(Some("12".to_string()), work.volume);
+        let author = &work.author.unwrap()[0];
+        assert_eq!(Some("van Beethoven"), author.family.as_deref());
+        assert_eq!(ContributorSequence::First, author.sequence);
+        assert_eq!(
+            CrossrefDate::new(2019, Some(4), None),
+            work.issued
+        );
+        assert_eq!(Some(vec!["music".to_string(), "history".to_string()]), work.subject);
+        assert_eq!(1, work.issn.unwrap().len());
+    }
+}
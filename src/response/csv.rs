@@ -0,0 +1,412 @@
+//! Offline export of `Work` items as [CSV](https://www.rfc-editor.org/rfc/rfc4180) or TSV tables,
+//! one row per work with a configurable set of columns.
+
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+use crate::error::ErrorKind;
+use crate::response::work::{Contributor, Work};
+use crate::xml;
+
+/// A column of the table, the value of a `Work` in a single cell
+#[derive(Debug, Clone, Copy)]
+pub enum Column {
+    /// the DOI
+    Doi,
+    /// the first title, with its subtitle
+    Title,
+    /// the first author as `Family, Given`
+    FirstAuthor,
+    /// all authors as `Family, Given`, joined
+    Authors,
+    /// the first container title, like the journal
+    ContainerTitle,
+    /// all ISSNs, joined
+    Issn,
+    /// the year of [Work::publication_date]
+    Year,
+    /// the type, like `journal-article`
+    Type,
+    /// the publisher
+    Publisher,
+    /// the number of references of the work
+    ReferencesCount,
+    /// the number of works citing the work
+    IsReferencedByCount,
+    /// the names of the funders, joined
+    Funders,
+    /// the distinct license urls, joined
+    License,
+    /// the subjects, joined
+    Subjects,
+    /// the url of the work
+    Url,
+    /// a custom column with its header and value
+    Custom(&'static str, fn(&Work) -> Option<String>),
+}
+
+impl Column {
+    /// the default columns of a [CsvWriter]
+    pub const DEFAULT: &'static [Column] = &[
+        Column::Doi,
+        Column::Title,
+        Column::Authors,
+        Column::ContainerTitle,
+        Column::Issn,
+        Column::Year,
+        Column::Type,
+        Column::ReferencesCount,
+        Column::IsReferencedByCount,
+        Column::Funders,
+        Column::License,
+        Column::Subjects,
+    ];
+
+    /// the header of the column, also the name [Column::from_str] accepts
+    pub fn header(&self) -> &'static str {
+        match self {
+            Column::Doi => "doi",
+            Column::Title => "title",
+            Column::FirstAuthor => "first-author",
+            Column::Authors => "authors",
+            Column::ContainerTitle => "container-title",
+            Column::Issn => "issn",
+            Column::Year => "year",
+            Column::Type => "type",
+            Column::Publisher => "publisher",
+            Column::ReferencesCount => "references-count",
+            Column::IsReferencedByCount => "is-referenced-by-count",
+            Column::Funders => "funders",
+            Column::License => "license",
+            Column::Subjects => "subjects",
+            Column::Url => "url",
+            Column::Custom(header, _) => header,
+        }
+    }
+
+    /// the values of the column for `work`, a single value for single-valued columns
+    pub fn values(&self, work: &Work) -> Vec<String> {
+        let authors = || work.author.iter().flatten().filter_map(Contributor::display_name);
+        match self {
+            Column::Doi => vec![work.doi.to_string()],
+            Column::Title => work.full_title().into_iter().collect(),
+            Column::FirstAuthor => authors().take(1).collect(),
+            Column::Authors => authors().collect(),
+            Column::ContainerTitle => work
+                .container_title
+                .iter()
+                .flatten()
+                .take(1)
+                .map(|t| xml::strip_tags(t))
+                .collect(),
+            Column::Issn => work.issn.iter().flatten().map(ToString::to_string).collect(),
+            Column::Year => work
+                .publication_date()
+                .map(|date| date.year().to_string())
+                .into_iter()
+                .collect(),
+            Column::Type => vec![work.type_.as_str().to_string()],
+            Column::Publisher => vec![work.publisher.clone()],
+            Column::ReferencesCount => work.references_count.map(|c| c.to_string()).into_iter().collect(),
+            Column::IsReferencedByCount => work
+                .is_referenced_by_count
+                .map(|c| c.to_string())
+                .into_iter()
+                .collect(),
+            Column::Funders => work.funder.iter().flatten().map(|f| f.name.clone()).collect(),
+            Column::License => {
+                let mut urls: Vec<String> = Vec::new();
+                for license in work.license.iter().flatten() {
+                    if !urls.contains(&license.url) {
+                        urls.push(license.url.clone());
+                    }
+                }
+                urls
+            }
+            Column::Subjects => work.subject.clone().unwrap_or_default(),
+            Column::Url => work.url.clone().into_iter().collect(),
+            Column::Custom(_, value) => value(work).into_iter().collect(),
+        }
+    }
+}
+
+impl fmt::Display for Column {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.header())
+    }
+}
+
+impl FromStr for Column {
+    type Err = ErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let column = match s.trim().to_ascii_lowercase().replace('_', "-").as_str() {
+            "doi" => Column::Doi,
+            "title" => Column::Title,
+            "first-author" => Column::FirstAuthor,
+            "authors" | "author" => Column::Authors,
+            "container-title" => Column::ContainerTitle,
+            "issn" => Column::Issn,
+            "year" => Column::Year,
+            "type" => Column::Type,
+            "publisher" => Column::Publisher,
+            "references-count" => Column::ReferencesCount,
+            "is-referenced-by-count" | "citations" => Column::IsReferencedByCount,
+            "funders" | "funder" => Column::Funders,
+            "license" | "licence" => Column::License,
+            "subjects" | "subject" => Column::Subjects,
+            "url" => Column::Url,
+            _ => {
+                return Err(ErrorKind::InvalidField {
+                    msg: format!("unknown column `{}`", s),
+                })
+            }
+        };
+        Ok(column)
+    }
+}
+
+/// Writes a table of `Work` items as CSV or TSV, one row per work as soon as it is written
+#[derive(Debug)]
+pub struct CsvWriter<W> {
+    out: W,
+    columns: Vec<Column>,
+    delimiter: u8,
+    separator: String,
+    max_values: Option<usize>,
+    header: bool,
+    escape_formulas: bool,
+    records: usize,
+}
+
+impl<W: io::Write> CsvWriter<W> {
+    /// a writer for comma separated values with the [Column::DEFAULT] columns
+    pub fn new(out: W) -> Self {
+        CsvWriter {
+            out,
+            columns: Column::DEFAULT.to_vec(),
+            delimiter: b',',
+            separator: "; ".to_string(),
+            max_values: None,
+            header: true,
+            escape_formulas: false,
+            records: 0,
+        }
+    }
+
+    /// a writer for tab separated values with the [Column::DEFAULT] columns
+    pub fn tsv(out: W) -> Self {
+        CsvWriter::new(out).delimiter(b'\t')
+    }
+
+    /// the columns of the table, in order
+    pub fn columns<I: IntoIterator<Item = Column>>(mut self, columns: I) -> Self {
+        self.columns = columns.into_iter().collect();
+        self
+    }
+
+    /// the byte between cells, `,` by default
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// the string multiple values of a cell are joined with, `; ` by default
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// keep at most `max` values of a multi-valued cell and append `et al.` if there are more
+    pub fn max_values(mut self, max: usize) -> Self {
+        self.max_values = Some(max);
+        self
+    }
+
+    /// whether the first row holds the headers of the columns, `true` by default
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// prefix cells starting with `=`, `+`, `-` or `@` with `'` so spreadsheet applications
+    /// do not evaluate them as formulas, `false` by default
+    pub fn escape_formulas(mut self, escape: bool) -> Self {
+        self.escape_formulas = escape;
+        self
+    }
+
+    /// write the row of a single `work`, preceded by the header row on the first call
+    pub fn write(&mut self, work: &Work) -> io::Result<()> {
+        if self.records == 0 {
+            self.write_header()?;
+        }
+        let cells: Vec<String> = self.columns.iter().map(|column| self.cell(column, work)).collect();
+        self.write_row(&cells)?;
+        self.records += 1;
+        Ok(())
+    }
+
+    /// write the rows of all `works`
+    pub fn write_all<'a, I: IntoIterator<Item = &'a Work>>(&mut self, works: I) -> io::Result<()> {
+        for work in works {
+            self.write(work)?;
+        }
+        Ok(())
+    }
+
+    /// the number of rows written so far, without the header
+    pub fn records(&self) -> usize {
+        self.records
+    }
+
+    /// flush and return the underlying writer, the header row is written if no work was
+    pub fn into_inner(mut self) -> io::Result<W> {
+        if self.records == 0 {
+            self.write_header()?;
+        }
+        self.out.flush()?;
+        Ok(self.out)
+    }
+
+    /// the joined values of `column`
+    fn cell(&self, column: &Column, work: &Work) -> String {
+        let values = column.values(work);
+        match self.max_values {
+            Some(max) if values.len() > max => {
+                let mut values = values[..max].to_vec();
+                values.push("et al.".to_string());
+                values.join(&self.separator)
+            }
+            _ => values.join(&self.separator),
+        }
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        if !self.header {
+            return Ok(());
+        }
+        let headers: Vec<String> = self.columns.iter().map(|c| c.header().to_string()).collect();
+        self.write_row(&headers)
+    }
+
+    fn write_row(&mut self, cells: &[String]) -> io::Result<()> {
+        let delimiter = self.delimiter as char;
+        let row: Vec<String> = cells.iter().map(|cell| self.escape(cell)).collect();
+        self.out.write_all(row.join(&delimiter.to_string()).as_bytes())?;
+        // RFC 4180 ends records with CRLF, TSV with LF
+        let end: &[u8] = if self.delimiter == b'\t' { b"\n" } else { b"\r\n" };
+        self.out.write_all(end)
+    }
+
+    /// quote a CSV cell if needed, TSV can't quote, so tabs and line breaks become spaces
+    fn escape(&self, cell: &str) -> String {
+        if self.escape_formulas && cell.starts_with(['=', '+', '-', '@']) {
+            return self.escape(&format!("'{}", cell));
+        }
+        if self.delimiter == b'\t' {
+            return cell.replace(['\t', '\r', '\n'], " ");
+        }
+        if cell.contains([self.delimiter as char, '"', '\r', '\n']) {
+            format!("\"{}\"", cell.replace('"', "\"\""))
+        } else {
+            cell.to_string()
+        }
+    }
+}
+
+impl Work {
+    /// the values of `columns` for this work, multiple values are joined with `; `
+    pub fn to_row(&self, columns: &[Column]) -> Vec<String> {
+        columns.iter().map(|column| column.values(self).join("; ")).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::work_fixture;
+    use serde_json::json;
+
+    fn work() -> Work {
+        work_fixture(json!({
+            "title": ["Pots, \"cracked\" and whole"],
+            "container-title": ["Journal of Psychoceramics"],
+            "ISSN": ["0264-3561", "1234-5679"],
+            "author": [
+                {"given": "Josiah", "family": "Carberry", "sequence": "first", "affiliation": []},
+                {"given": "Ann", "family": "Smith", "sequence": "additional", "affiliation": []},
+                {"name": "Psychoceramics Consortium", "sequence": "additional", "affiliation": []}
+            ],
+            "funder": [{"name": "National Science Foundation"}],
+            "issued": {"date-parts": [[2019, 2, 1]]},
+            "is-referenced-by-count": 7,
+        }))
+    }
+
+    #[test]
+    fn write_csv() {
+        let columns = "doi,title,first-author,authors,issn,year,citations,funders"
+            .split(',')
+            .map(|c| c.parse().unwrap());
+        let mut writer = CsvWriter::new(Vec::new()).columns(columns).max_values(2);
+        writer.write_all(&[work(), work()]).unwrap();
+        assert_eq!(2, writer.records());
+        let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+        assert_eq!(
+            "doi,title,first-author,authors,issn,year,is-referenced-by-count,funders",
+            lines[0]
+        );
+        assert_eq!(
+            "10.5555/12345678,\"Pots, \"\"cracked\"\" and whole\",\"Carberry, Josiah\",\"Carberry, Josiah; Smith, Ann; et al.\",0264-3561; 1234-5679,2019,7,National Science Foundation",
+            lines[1]
+        );
+        assert_eq!(4, lines.len());
+    }
+
+    #[test]
+    fn write_tsv() {
+        let mut writer = CsvWriter::tsv(Vec::new())
+            .columns(vec![Column::Doi, Column::Authors, Column::License])
+            .separator("|")
+            .header(false);
+        writer.write(&work()).unwrap();
+        let tsv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+        assert_eq!(
+            "10.5555/12345678\tCarberry, Josiah|Smith, Ann|Psychoceramics Consortium\t\n",
+            tsv
+        );
+    }
+
+    #[test]
+    fn write_header_without_works() {
+        let writer = CsvWriter::new(Vec::new()).columns(vec![Column::Doi, Column::Year]);
+        assert_eq!(b"doi,year\r\n".to_vec(), writer.into_inner().unwrap());
+
+        let writer = CsvWriter::new(Vec::new()).header(false);
+        assert!(writer.into_inner().unwrap().is_empty());
+    }
+
+    #[test]
+    fn escape_formulas() {
+        let mut work = work();
+        work.title = vec!["=HYPERLINK(\"http://example.com\", \"x\")".to_string()];
+        work.publisher = "@SUM(1+1)".to_string();
+        let columns = vec![Column::Title, Column::Publisher];
+
+        let mut writer = CsvWriter::new(Vec::new()).columns(columns.clone()).header(false);
+        writer.write(&work).unwrap();
+        let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+        assert_eq!("\"=HYPERLINK(\"\"http://example.com\"\", \"\"x\"\")\",@SUM(1+1)\r\n", csv);
+
+        let mut writer = CsvWriter::new(Vec::new())
+            .columns(columns)
+            .header(false)
+            .escape_formulas(true);
+        writer.write(&work).unwrap();
+        let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+        assert_eq!("\"'=HYPERLINK(\"\"http://example.com\"\", \"\"x\"\")\",'@SUM(1+1)\r\n", csv);
+    }
+}
@@ -0,0 +1,446 @@
+//! Parsing and rendering of the [JATS](https://jats.nlm.nih.gov/) markup crossref uses for abstracts
+
+use std::fmt;
+
+use crate::xml::{self, Element, Node};
+
+/// A parsed JATS abstract, like `<jats:p>Some <jats:italic>text</jats:italic></jats:p>`.
+///
+/// Parsing never fails, malformed markup is read as well as possible and unknown elements are
+/// replaced by their text.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Abstract {
+    /// the sections of the abstract, abstracts without `<jats:sec>` consist of a single section
+    pub sections: Vec<AbstractSection>,
+}
+
+/// A section of an `Abstract`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AbstractSection {
+    /// the title of the section, like `Background` or `Abstract`
+    pub title: Option<Paragraph>,
+    /// the paragraphs of the section
+    pub paragraphs: Vec<Paragraph>,
+}
+
+/// A paragraph of formatted text
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Paragraph(pub Vec<Inline>);
+
+/// Formatted text inside a `Paragraph`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum Inline {
+    Text(String),
+    Italic(Vec<Inline>),
+    Bold(Vec<Inline>),
+    Superscript(Vec<Inline>),
+    Subscript(Vec<Inline>),
+    Underline(Vec<Inline>),
+    Monospace(Vec<Inline>),
+    SmallCaps(Vec<Inline>),
+    Link { href: String, content: Vec<Inline> },
+}
+
+impl Abstract {
+    /// parse the JATS markup of an abstract
+    pub fn parse(jats: &str) -> Self {
+        let mut builder = Builder::default();
+        builder.blocks(&xml::parse(jats));
+        builder.finish()
+    }
+
+    /// the titles of all sections
+    pub fn titles(&self) -> impl Iterator<Item = String> + '_ {
+        self.sections
+            .iter()
+            .filter_map(|sec| sec.title.as_ref().map(Paragraph::to_plain_text))
+    }
+
+    /// all paragraphs of all sections
+    pub fn paragraphs(&self) -> impl Iterator<Item = &Paragraph> {
+        self.sections.iter().flat_map(|sec| sec.paragraphs.iter())
+    }
+
+    /// the abstract without any markup, blocks are separated by an empty line
+    pub fn to_plain_text(&self) -> String {
+        self.render(|title| title.to_plain_text(), Paragraph::to_plain_text)
+    }
+
+    /// the abstract as html, section titles become `<h3>`
+    pub fn to_html(&self) -> String {
+        self.render(
+            |title| format!("<h3>{}</h3>", title.to_html()),
+            |p| format!("<p>{}</p>", p.to_html()),
+        )
+    }
+
+    /// the abstract as markdown, section titles become `###` headings
+    pub fn to_markdown(&self) -> String {
+        self.render(
+            |title| format!("### {}", title.to_markdown()),
+            Paragraph::to_markdown,
+        )
+    }
+
+    fn render(
+        &self,
+        title: impl Fn(&Paragraph) -> String,
+        paragraph: impl Fn(&Paragraph) -> String,
+    ) -> String {
+        self.sections
+            .iter()
+            .flat_map(|sec| {
+                sec.title
+                    .iter()
+                    .map(&title)
+                    .chain(sec.paragraphs.iter().map(&paragraph))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+impl fmt::Display for Abstract {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_plain_text())
+    }
+}
+
+impl Paragraph {
+    /// the text without any markup
+    pub fn to_plain_text(&self) -> String {
+        fn render(inlines: &[Inline], out: &mut String) {
+            for inline in inlines {
+                match inline {
+                    Inline::Text(text) => out.push_str(text),
+                    other => render(other.children(), out),
+                }
+            }
+        }
+        let mut out = String::new();
+        render(&self.0, &mut out);
+        xml::collapse_whitespace(&out)
+    }
+
+    /// the text as html
+    pub fn to_html(&self) -> String {
+        fn render(inlines: &[Inline], out: &mut String) {
+            for inline in inlines {
+                let (open, close) = match inline {
+                    Inline::Text(text) => {
+                        out.push_str(&xml::escape(text));
+                        continue;
+                    }
+                    Inline::Link { href, content } => {
+                        out.push_str(&format!("<a href=\"{}\">", xml::escape(href)));
+                        render(content, out);
+                        out.push_str("</a>");
+                        continue;
+                    }
+                    Inline::Italic(_) => ("<i>", "</i>"),
+                    Inline::Bold(_) => ("<b>", "</b>"),
+                    Inline::Superscript(_) => ("<sup>", "</sup>"),
+                    Inline::Subscript(_) => ("<sub>", "</sub>"),
+                    Inline::Underline(_) => ("<u>", "</u>"),
+                    Inline::Monospace(_) => ("<code>", "</code>"),
+                    Inline::SmallCaps(_) => ("<span style=\"font-variant: small-caps\">", "</span>"),
+                };
+                out.push_str(open);
+                render(inline.children(), out);
+                out.push_str(close);
+            }
+        }
+        let mut out = String::new();
+        render(&self.0, &mut out);
+        out
+    }
+
+    /// the text as markdown, super- and subscripts are kept as inline html
+    pub fn to_markdown(&self) -> String {
+        fn render(inlines: &[Inline], out: &mut String) {
+            for inline in inlines {
+                let (open, close) = match inline {
+                    Inline::Text(text) => {
+                        for c in text.chars() {
+                            if matches!(c, '\\' | '*' | '_' | '[' | ']' | '`' | '<') {
+                                out.push('\\');
+                            }
+                            out.push(c);
+                        }
+                        continue;
+                    }
+                    Inline::Link { href, content } => {
+                        out.push('[');
+                        render(content, out);
+                        out.push_str("](");
+                        for c in href.chars() {
+                            match c {
+                                ' ' => out.push_str("%20"),
+                                '(' | ')' | '\\' | '<' | '>' => {
+                                    out.push('\\');
+                                    out.push(c);
+                                }
+                                c => out.push(c),
+                            }
+                        }
+                        out.push(')');
+                        continue;
+                    }
+                    Inline::Italic(_) => ("*", "*"),
+                    Inline::Bold(_) => ("**", "**"),
+                    Inline::Superscript(_) => ("<sup>", "</sup>"),
+                    Inline::Subscript(_) => ("<sub>", "</sub>"),
+                    Inline::Underline(_) => ("<u>", "</u>"),
+                    Inline::Monospace(_) => ("`", "`"),
+                    Inline::SmallCaps(_) => ("", ""),
+                };
+                out.push_str(open);
+                render(inline.children(), out);
+                out.push_str(close);
+            }
+        }
+        let mut out = String::new();
+        render(&self.0, &mut out);
+        out
+    }
+
+    fn is_empty(&self) -> bool {
+        self.to_plain_text().is_empty()
+    }
+
+    /// collapse whitespace and remove it from the start and end of the paragraph
+    fn normalize(mut self) -> Self {
+        fn collapse(inlines: &mut [Inline]) {
+            for inline in inlines {
+                match inline {
+                    Inline::Text(text) => {
+                        let trimmed = xml::collapse_whitespace(text);
+                        let start = if text.starts_with(char::is_whitespace) { " " } else { "" };
+                        let end = if text.ends_with(char::is_whitespace) && !trimmed.is_empty() {
+                            " "
+                        } else {
+                            ""
+                        };
+                        *text = format!("{}{}{}", start, trimmed, end);
+                    }
+                    other => collapse(other.children_mut()),
+                }
+            }
+        }
+        collapse(&mut self.0);
+        if let Some(Inline::Text(text)) = self.0.first_mut() {
+            *text = text.trim_start().to_string();
+        }
+        if let Some(Inline::Text(text)) = self.0.last_mut() {
+            *text = text.trim_end().to_string();
+        }
+        self.0.retain(|inline| !matches!(inline, Inline::Text(text) if text.is_empty()));
+        self
+    }
+}
+
+impl Inline {
+    fn children(&self) -> &[Inline] {
+        match self {
+            Inline::Text(_) => &[],
+            Inline::Link { content, .. } => content,
+            Inline::Italic(c)
+            | Inline::Bold(c)
+            | Inline::Superscript(c)
+            | Inline::Subscript(c)
+            | Inline::Underline(c)
+            | Inline::Monospace(c)
+            | Inline::SmallCaps(c) => c,
+        }
+    }
+
+    fn children_mut(&mut self) -> &mut [Inline] {
+        match self {
+            Inline::Text(_) => &mut [],
+            Inline::Link { content, .. } => content,
+            Inline::Italic(c)
+            | Inline::Bold(c)
+            | Inline::Superscript(c)
+            | Inline::Subscript(c)
+            | Inline::Underline(c)
+            | Inline::Monospace(c)
+            | Inline::SmallCaps(c) => c,
+        }
+    }
+}
+
+/// converts the xml nodes into sections and paragraphs
+#[derive(Default)]
+struct Builder {
+    sections: Vec<AbstractSection>,
+    current: AbstractSection,
+    /// inline content found outside of a `<jats:p>`
+    loose: Vec<Inline>,
+}
+
+impl Builder {
+    fn blocks(&mut self, nodes: &[Node]) {
+        for node in nodes {
+            match node {
+                Node::Element(el) => match el.name.as_str() {
+                    "abstract" | "trans-abstract" | "body" | "div" | "list" => {
+                        self.flush_loose();
+                        self.blocks(&el.children);
+                    }
+                    "sec" => {
+                        self.flush_loose();
+                        self.start_section(None);
+                        self.blocks(&el.children);
+                        self.start_section(None);
+                    }
+                    "title" => {
+                        self.flush_loose();
+                        let title = Paragraph(inlines(&el.children)).normalize();
+                        if self.current.title.is_some() || !self.current.paragraphs.is_empty() {
+                            self.start_section(Some(title));
+                        } else {
+                            self.current.title = Some(title);
+                        }
+                    }
+                    "p" | "list-item" => {
+                        self.flush_loose();
+                        self.paragraph(inlines(&el.children));
+                    }
+                    _ => self.loose.extend(inline(el)),
+                },
+                Node::Text(text) => self.loose.push(Inline::Text(text.clone())),
+            }
+        }
+    }
+
+    fn paragraph(&mut self, inlines: Vec<Inline>) {
+        let paragraph = Paragraph(inlines).normalize();
+        if !paragraph.is_empty() {
+            self.current.paragraphs.push(paragraph);
+        }
+    }
+
+    fn flush_loose(&mut self) {
+        let loose = std::mem::take(&mut self.loose);
+        self.paragraph(loose);
+    }
+
+    fn start_section(&mut self, title: Option<Paragraph>) {
+        let previous = std::mem::replace(
+            &mut self.current,
+            AbstractSection {
+                title,
+                paragraphs: Vec::new(),
+            },
+        );
+        if previous.title.is_some() || !previous.paragraphs.is_empty() {
+            self.sections.push(previous);
+        }
+    }
+
+    fn finish(mut self) -> Abstract {
+        self.flush_loose();
+        self.start_section(None);
+        Abstract {
+            sections: self.sections,
+        }
+    }
+}
+
+fn inlines(nodes: &[Node]) -> Vec<Inline> {
+    nodes
+        .iter()
+        .flat_map(|node| match node {
+            Node::Text(text) => vec![Inline::Text(text.clone())],
+            Node::Element(el) => inline(el),
+        })
+        .collect()
+}
+
+fn inline(el: &Element) -> Vec<Inline> {
+    let children = || inlines(&el.children);
+    vec![match el.name.as_str() {
+        "italic" | "i" | "em" => Inline::Italic(children()),
+        "bold" | "b" | "strong" => Inline::Bold(children()),
+        "sup" => Inline::Superscript(children()),
+        "sub" => Inline::Subscript(children()),
+        "underline" | "u" => Inline::Underline(children()),
+        "monospace" | "tt" | "code" => Inline::Monospace(children()),
+        "sc" => Inline::SmallCaps(children()),
+        "ext-link" | "uri" | "a" => match el.attr("href").map(str::trim).filter(|h| is_safe_href(h)) {
+            Some(href) => Inline::Link {
+                href: href.to_string(),
+                content: children(),
+            },
+            None => return children(),
+        },
+        "break" | "br" => Inline::Text(" ".to_string()),
+        _ => return children(),
+    }]
+}
+
+/// only links with a http(s), ftp or mailto scheme are kept, anything else like `javascript:` is rendered as text
+fn is_safe_href(href: &str) -> bool {
+    href.split_once(':').is_some_and(|(scheme, _)| {
+        ["http", "https", "ftp", "mailto"]
+            .iter()
+            .any(|allowed| scheme.eq_ignore_ascii_case(allowed))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_sections() {
+        let jats = r#"<jats:title>Abstract</jats:title>
+            <jats:sec><jats:title>Background</jats:title><jats:p>We study   <jats:italic>E. coli</jats:italic> &amp; H<jats:sub>2</jats:sub>O.</jats:p></jats:sec>
+            <jats:sec><jats:title>Results</jats:title><jats:p>See <jats:ext-link xlink:href="https://example.org">here</jats:ext-link>.</jats:p><jats:p>x<jats:sup>2</jats:sup></jats:p></jats:sec>"#;
+        let abs = Abstract::parse(jats);
+
+        assert_eq!(vec!["Abstract", "Background", "Results"], abs.titles().collect::<Vec<_>>());
+        assert_eq!(3, abs.paragraphs().count());
+        assert_eq!(
+            "Abstract\n\nBackground\n\nWe study E. coli & H2O.\n\nResults\n\nSee here.\n\nx2",
+            abs.to_plain_text()
+        );
+        assert_eq!(
+            "<h3>Abstract</h3>\n\n<h3>Background</h3>\n\n<p>We study <i>E. coli</i> &amp; H<sub>2</sub>O.</p>\n\n<h3>Results</h3>\n\n<p>See <a href=\"https://example.org\">here</a>.</p>\n\n<p>x<sup>2</sup></p>",
+            abs.to_html()
+        );
+        assert_eq!(
+            "### Abstract\n\n### Background\n\nWe study *E. coli* & H<sub>2</sub>O.\n\n### Results\n\nSee [here](https://example.org).\n\nx<sup>2</sup>",
+            abs.to_markdown()
+        );
+    }
+
+    #[test]
+    fn unsafe_links_are_text() {
+        let jats = r#"<jats:p><jats:ext-link xlink:href="javascript:alert(1)">click</jats:ext-link> and <jats:ext-link xlink:href="https://example.org/a (b)">this</jats:ext-link></jats:p>"#;
+        let abs = Abstract::parse(jats);
+
+        assert_eq!(
+            "<p>click and <a href=\"https://example.org/a (b)\">this</a></p>",
+            abs.to_html()
+        );
+        assert_eq!(
+            "click and [this](https://example.org/a%20\\(b\\))",
+            abs.to_markdown()
+        );
+    }
+
+    #[test]
+    fn parse_plain_and_malformed() {
+        assert_eq!("Just text.", Abstract::parse("  Just text. ").to_plain_text());
+
+        let abs = Abstract::parse("<jats:p>Dose < 5 mg <jats:bold>unclosed</jats:p><jats:p>next &unknown;");
+        assert_eq!(
+            vec!["Dose < 5 mg unclosed", "next &unknown;"],
+            abs.paragraphs().map(Paragraph::to_plain_text).collect::<Vec<_>>()
+        );
+        assert_eq!("<p>Dose &lt; 5 mg <b>unclosed</b></p>", abs.to_html().lines().next().unwrap());
+    }
+}
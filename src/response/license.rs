@@ -0,0 +1,364 @@
+//! Classification of the licenses of a `Work` and open-access analysis on top of it
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::query::ContentVersion;
+use crate::response::work::{License, Work, WorkList};
+
+/// The kind of a license, derived from its url
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LicenseKind {
+    /// Creative Commons Attribution
+    CcBy,
+    /// Creative Commons Attribution-ShareAlike
+    CcBySa,
+    /// Creative Commons Attribution-NoDerivatives
+    CcByNd,
+    /// Creative Commons Attribution-NonCommercial
+    CcByNc,
+    /// Creative Commons Attribution-NonCommercial-ShareAlike
+    CcByNcSa,
+    /// Creative Commons Attribution-NonCommercial-NoDerivatives
+    CcByNcNd,
+    /// Creative Commons Zero or the public domain mark
+    Cc0,
+    /// a publisher license that permits text and data mining, but no reuse
+    PublisherTdm,
+    /// any other license, like the terms of use of a publisher
+    Unknown,
+}
+
+/// url fragments of publisher licenses that permit text and data mining
+const TDM_LICENSES: &[&str] = &[
+    "elsevier.com/tdm/userlicense",
+    "doi.wiley.com/10.1002/tdm_license",
+    "springer.com/tdm",
+    "springernature.com/gp/researchers/text-and-data-mining",
+    "iopscience.iop.org/info/page/text-and-data-mining",
+    "doi.org/10.15223/policy-",
+    "bmj.com/company/legal-information/tdm",
+    "sage-tdm",
+    "tandfonline.com/action/showcopyright",
+];
+
+impl LicenseKind {
+    /// the kind of the license at `url`
+    pub fn from_url(url: &str) -> Self {
+        let url = url.trim().to_ascii_lowercase();
+        if let Some((_, rest)) = url.split_once("creativecommons.org/") {
+            let mut segments = rest.split('/');
+            return match (segments.next(), segments.next()) {
+                (Some("publicdomain"), Some("zero" | "mark")) => LicenseKind::Cc0,
+                (Some("licenses"), Some("by")) => LicenseKind::CcBy,
+                (Some("licenses"), Some("by-sa")) => LicenseKind::CcBySa,
+                (Some("licenses"), Some("by-nd")) => LicenseKind::CcByNd,
+                (Some("licenses"), Some("by-nc")) => LicenseKind::CcByNc,
+                (Some("licenses"), Some("by-nc-sa")) => LicenseKind::CcByNcSa,
+                (Some("licenses"), Some("by-nc-nd")) => LicenseKind::CcByNcNd,
+                _ => LicenseKind::Unknown,
+            };
+        }
+        if TDM_LICENSES.iter().any(|fragment| url.contains(fragment)) {
+            LicenseKind::PublisherTdm
+        } else {
+            LicenseKind::Unknown
+        }
+    }
+
+    /// whether this is one of the Creative Commons licenses, which make a work open access
+    pub fn is_open(&self) -> bool {
+        !matches!(self, LicenseKind::PublisherTdm | LicenseKind::Unknown)
+    }
+
+    /// how much reuse the license allows, higher is more permissive: CC0 before CC BY, any Creative
+    /// Commons license before a publisher license
+    pub fn permissiveness(&self) -> u8 {
+        match self {
+            LicenseKind::Cc0 => 8,
+            LicenseKind::CcBy => 7,
+            LicenseKind::CcBySa => 6,
+            LicenseKind::CcByNd => 5,
+            LicenseKind::CcByNc => 4,
+            LicenseKind::CcByNcSa => 3,
+            LicenseKind::CcByNcNd => 2,
+            LicenseKind::PublisherTdm => 1,
+            LicenseKind::Unknown => 0,
+        }
+    }
+
+    /// whether the license permits commercial reuse
+    pub fn permits_commercial_use(&self) -> bool {
+        matches!(
+            self,
+            LicenseKind::CcBy | LicenseKind::CcBySa | LicenseKind::CcByNd | LicenseKind::Cc0
+        )
+    }
+}
+
+impl License {
+    /// the kind of this license, see [LicenseKind::from_url]
+    pub fn kind(&self) -> LicenseKind {
+        LicenseKind::from_url(&self.url)
+    }
+
+    /// the first day this license applies, the publication date plus `delay-in-days`
+    pub fn start_date(&self) -> NaiveDate {
+        self.start.first_day()
+    }
+
+    /// whether the license already applies on `date`, `false` during an embargo
+    pub fn is_active_on(&self, date: NaiveDate) -> bool {
+        self.start_date() <= date
+    }
+
+    /// whether this license covers `version`, licenses for an `unspecified` version cover all
+    pub fn applies_to(&self, version: &ContentVersion) -> bool {
+        &self.content_version == version || self.content_version == ContentVersion::Unspecified
+    }
+}
+
+impl Work {
+    /// the open licenses of this work that cover `version`
+    pub fn open_licenses(&self, version: &ContentVersion) -> impl Iterator<Item = &License> + '_ {
+        let version = version.clone();
+        self.license
+            .iter()
+            .flatten()
+            .filter(move |license| license.applies_to(&version) && license.kind().is_open())
+    }
+
+    /// the first day `version` of this work is openly licensed, after any embargo
+    pub fn open_access_date(&self, version: &ContentVersion) -> Option<NaiveDate> {
+        self.open_licenses(version).map(License::start_date).min()
+    }
+
+    /// the first day each content version of this work is openly licensed, an open license for an
+    /// `unspecified` version counts for every version like in [License::applies_to]
+    pub fn open_access_dates(&self) -> HashMap<ContentVersion, NaiveDate> {
+        let mut versions = vec![
+            ContentVersion::Vor,
+            ContentVersion::Am,
+            ContentVersion::Tdm,
+            ContentVersion::Unspecified,
+        ];
+        for license in self.license.iter().flatten() {
+            if !versions.contains(&license.content_version) {
+                versions.push(license.content_version.clone());
+            }
+        }
+        versions
+            .into_iter()
+            .filter_map(|version| {
+                let date = self.open_access_date(&version)?;
+                Some((version, date))
+            })
+            .collect()
+    }
+
+    /// the open license of `version` that applies on `date`, the most permissive if there are several
+    pub fn open_license_on(&self, version: &ContentVersion, date: NaiveDate) -> Option<&License> {
+        self.open_licenses(version)
+            .filter(|license| license.is_active_on(date))
+            .max_by_key(|license| license.kind().permissiveness())
+    }
+
+    /// whether the version of record is openly licensed on `date`
+    pub fn is_open_access_on(&self, date: NaiveDate) -> bool {
+        self.open_license_on(&ContentVersion::Vor, date).is_some()
+    }
+}
+
+/// Counts of the licenses of many works on a given day, like a facet over the license kinds
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LicenseSummary {
+    /// the day the licenses were evaluated for
+    pub date: NaiveDate,
+    /// the number of works
+    pub total: usize,
+    /// works whose version of record is openly licensed on `date`
+    pub open_access: usize,
+    /// works with an open license for the version of record that starts after `date`
+    pub embargoed: usize,
+    /// works without any license
+    pub unlicensed: usize,
+    /// works per kind of the most permissive license of the version of record that applies on `date`
+    pub by_kind: HashMap<LicenseKind, usize>,
+    /// works per license url, like the `license` facet of the crossref api
+    pub by_url: HashMap<String, usize>,
+}
+
+impl LicenseSummary {
+    /// an empty summary for `date`
+    pub fn new(date: NaiveDate) -> Self {
+        LicenseSummary {
+            date,
+            total: 0,
+            open_access: 0,
+            embargoed: 0,
+            unlicensed: 0,
+            by_kind: HashMap::new(),
+            by_url: HashMap::new(),
+        }
+    }
+
+    /// the summary of all `works` on `date`
+    pub fn of<'a, I: IntoIterator<Item = &'a Work>>(works: I, date: NaiveDate) -> Self {
+        let mut summary = LicenseSummary::new(date);
+        summary.extend(works);
+        summary
+    }
+
+    /// count `work`
+    pub fn add(&mut self, work: &Work) {
+        self.total += 1;
+        let licenses = work.license.as_deref().unwrap_or_default();
+        if licenses.is_empty() {
+            self.unlicensed += 1;
+        }
+        let mut urls: Vec<&str> = licenses.iter().map(|l| l.url.as_str()).collect();
+        urls.sort_unstable();
+        urls.dedup();
+        for url in urls {
+            *self.by_url.entry(url.to_string()).or_default() += 1;
+        }
+
+        let vor = ContentVersion::Vor;
+        if work.is_open_access_on(self.date) {
+            self.open_access += 1;
+        } else if work.open_access_date(&vor).is_some() {
+            self.embargoed += 1;
+        }
+        let kind = licenses
+            .iter()
+            .filter(|l| l.applies_to(&vor) && l.is_active_on(self.date))
+            .map(License::kind)
+            .max_by_key(LicenseKind::permissiveness);
+        if let Some(kind) = kind {
+            *self.by_kind.entry(kind).or_default() += 1;
+        }
+    }
+
+    /// the share of open access works, `0.0` for an empty summary
+    pub fn open_access_ratio(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.open_access as f64 / self.total as f64
+        }
+    }
+}
+
+impl<'a> Extend<&'a Work> for LicenseSummary {
+    fn extend<I: IntoIterator<Item = &'a Work>>(&mut self, works: I) {
+        for work in works {
+            self.add(work);
+        }
+    }
+}
+
+impl WorkList {
+    /// the license summary of the items of this page on `date`
+    pub fn license_summary(&self, date: NaiveDate) -> LicenseSummary {
+        LicenseSummary::of(&self.items, date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::work_fixture;
+    use serde_json::json;
+
+    fn work(doi: &str, licenses: serde_json::Value) -> Work {
+        work_fixture(json!({"DOI": doi, "license": licenses}))
+    }
+
+    fn license(url: &str, version: &str, start: [u32; 3]) -> serde_json::Value {
+        json!({"URL": url, "content-version": version, "delay-in-days": 0, "start": {"date-parts": [start]}})
+    }
+
+    fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn classify_licenses() {
+        let kind = LicenseKind::from_url;
+        assert_eq!(LicenseKind::CcBy, kind("https://creativecommons.org/licenses/by/4.0/"));
+        assert_eq!(LicenseKind::CcByNcNd, kind("http://creativecommons.org/licenses/by-nc-nd/3.0/deed.en"));
+        assert_eq!(LicenseKind::Cc0, kind("https://creativecommons.org/publicdomain/zero/1.0/"));
+        assert_eq!(LicenseKind::PublisherTdm, kind("https://www.elsevier.com/tdm/userlicense/1.0/"));
+        assert_eq!(LicenseKind::Unknown, kind("https://www.elsevier.com/legal/userlicense"));
+        assert!(LicenseKind::CcByNc.is_open());
+        assert!(!LicenseKind::CcByNc.permits_commercial_use());
+    }
+
+    #[test]
+    fn open_access_on() {
+        let embargoed = work(
+            "10.1000/a",
+            json!([
+                license("https://www.elsevier.com/legal/userlicense", "vor", [2019, 1, 1]),
+                license("http://creativecommons.org/licenses/by-nc/4.0/", "am", [2019, 1, 1]),
+                license("http://creativecommons.org/licenses/by/4.0/", "vor", [2020, 1, 1]),
+            ]),
+        );
+        assert!(!embargoed.is_open_access_on(day(2019, 6, 1)));
+        assert!(embargoed.is_open_access_on(day(2020, 1, 1)));
+        assert_eq!(Some(day(2020, 1, 1)), embargoed.open_access_date(&ContentVersion::Vor));
+        assert_eq!(Some(&day(2019, 1, 1)), embargoed.open_access_dates().get(&ContentVersion::Am));
+
+        let works = vec![
+            embargoed,
+            work("10.1000/b", json!([license("https://creativecommons.org/licenses/by/4.0/", "unspecified", [2018, 1, 1])])),
+            work("10.1000/c", json!([])),
+        ];
+        let summary = LicenseSummary::of(&works, day(2019, 6, 1));
+        assert_eq!(3, summary.total);
+        assert_eq!(1, summary.open_access);
+        assert_eq!(1, summary.embargoed);
+        assert_eq!(1, summary.unlicensed);
+        assert_eq!(Some(&1), summary.by_kind.get(&LicenseKind::CcBy));
+        assert_eq!(Some(&1), summary.by_kind.get(&LicenseKind::Unknown));
+        assert_eq!(Some(&1), summary.by_url.get("https://creativecommons.org/licenses/by/4.0/"));
+        assert_eq!(4, summary.by_url.len());
+    }
+
+    #[test]
+    fn most_permissive_license() {
+        let work = work(
+            "10.1000/a",
+            json!([
+                license("http://creativecommons.org/licenses/by-nc/4.0/", "vor", [2019, 1, 1]),
+                license("https://creativecommons.org/publicdomain/zero/1.0/", "vor", [2019, 1, 1]),
+            ]),
+        );
+        let license = work.open_license_on(&ContentVersion::Vor, day(2019, 6, 1)).unwrap();
+        assert_eq!(LicenseKind::Cc0, license.kind());
+        let summary = LicenseSummary::of([&work], day(2019, 6, 1));
+        assert_eq!(Some(&1), summary.by_kind.get(&LicenseKind::Cc0));
+    }
+
+    #[test]
+    fn unspecified_license_covers_all_versions() {
+        let work = work(
+            "10.1000/a",
+            json!([
+                license("http://creativecommons.org/licenses/by/4.0/", "unspecified", [2018, 1, 1]),
+                license("http://creativecommons.org/licenses/by-nc/4.0/", "am", [2017, 1, 1]),
+            ]),
+        );
+        let dates = work.open_access_dates();
+        assert_eq!(Some(&day(2018, 1, 1)), dates.get(&ContentVersion::Vor));
+        assert_eq!(Some(&day(2017, 1, 1)), dates.get(&ContentVersion::Am));
+        assert_eq!(Some(&day(2018, 1, 1)), dates.get(&ContentVersion::Unspecified));
+        for (version, date) in &dates {
+            assert_eq!(Some(*date), work.open_access_date(version));
+        }
+    }
+}
@@ -1,22 +1,36 @@
 mod journal;
 pub use journal::*;
 use crate::error::ErrorKind;
+use crate::ident::{self, Doi, FunderId, MemberId};
 use crate::query::facet::Facet;
 use crate::query::facet::FacetCount;
-use crate::query::Visibility;
+use crate::query::{Type, Visibility};
 use crate::response::work::*;
 use failure::Fail;
 use serde::de::Deserializer;
 use serde::{Deserialize, Serialize};
-use serde_json::{from_value, Value};
+use serde_json::value::RawValue;
+use serde_json::{from_value, Map, Value};
 use std::collections::HashMap;
 use std::fmt;
 
 //pub use crate::response::journal::Journal;
 /// provides the types for a work response
 pub mod work;
-
-pub use crate::response::work::{Work, WorkList};
+pub mod jats;
+pub mod bibtex;
+pub mod ris;
+pub mod csl;
+pub mod license;
+pub mod unixref;
+pub mod csv;
+pub use license::{LicenseKind, LicenseSummary};
+pub use crate::response::jats::Abstract;
+
+pub use crate::response::work::{
+    CrossrefDate, DatePrecision, ParseMode, ParseWarning, PartialWork, PartialWorkList, Work,
+    WorkList,
+};
 
 /// Represents the whole crossref response for a any request.
 #[derive(Debug, Clone, Serialize)]
@@ -33,10 +47,110 @@ pub struct Response {
     pub message: Option<Message>,
 }
 
+/// The parts of a response that surround a list message, kept on every list so they are not
+/// lost once the list is taken out of its `Response`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResponseMeta {
+    /// the status of the request, `ok` if it succeeded
+    pub status: String,
+    /// the version of the service created this message
+    pub message_version: String,
+    /// the http headers the response was served with, names are lowercase
+    pub headers: HashMap<String, String>,
+}
+
+impl ResponseMeta {
+    /// the value of the http header `name`, matched case insensitive
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// the number of requests allowed per `rate_limit_interval`, from the `x-rate-limit-limit` header
+    pub fn rate_limit(&self) -> Option<usize> {
+        self.header("x-rate-limit-limit")?.trim().parse().ok()
+    }
+
+    /// the interval of the rate limit, from the `x-rate-limit-interval` header (e.g. `1s`)
+    pub fn rate_limit_interval(&self) -> Option<&str> {
+        self.header("x-rate-limit-interval")
+    }
+}
+
+/// A typed view of everything crossref sends along with the items of a list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListEnvelope<'a> {
+    /// the status of the request, `ok` if it succeeded
+    pub status: &'a str,
+    /// the version of the service created this message
+    pub message_version: &'a str,
+    /// the number of items that match the request
+    pub total_results: usize,
+    /// the number of items per page
+    pub items_per_page: Option<usize>,
+    /// the cursor of the next page, only set for `/works` requests with a `cursor`
+    pub next_cursor: Option<&'a str>,
+    /// the offset and search terms of the request, if a query was set
+    pub query: Option<&'a QueryResponse>,
+    /// the http headers the response was served with, names are lowercase
+    pub headers: &'a HashMap<String, String>,
+}
+
+impl<'a> ListEnvelope<'a> {
+    /// the index of the first item of this page, `0` if crossref did not echo the query
+    pub fn start_index(&self) -> usize {
+        self.query.map(|q| q.start_index).unwrap_or_default()
+    }
+
+    /// the search terms crossref echoed for the request
+    pub fn search_terms(&self) -> Option<&'a str> {
+        self.query.and_then(|q| q.search_terms.as_deref())
+    }
+
+    /// whether more items follow after this page
+    ///
+    /// Cursor pages always echo `start-index: 0`, deep paging only ends with an empty page, so a
+    /// page with a `next_cursor` has more unless it has no items.
+    pub fn has_more(&self, items_on_page: usize) -> bool {
+        match self.next_cursor {
+            Some(_) => items_on_page > 0,
+            None => self.start_index() + items_on_page < self.total_results,
+        }
+    }
+}
+
 impl TryFrom<serde_json::Value> for Response {
     type Error = ErrorKind;
 
     fn try_from(value: Value) -> Result<Self, Self::Error> {
+        Response::parse(value, ParseMode::Strict)
+    }
+}
+
+impl Response {
+    /// Parse the whole crossref response from the raw json bytes, see [Response::parse].
+    ///
+    /// `Work` and `WorkList` messages are deserialized straight from `json`
+    /// without building a `serde_json::Value` of the whole response first.
+    pub fn from_slice(json: &[u8], mode: ParseMode) -> Result<Self, ErrorKind> {
+        let envelope = Envelope::from_slice(json)?;
+        let message = envelope.message.ok_or_else(|| ErrorKind::MissingField {
+            msg: "message".to_string(),
+        })?;
+        let message = Message::from_json(envelope.message_type.clone(), message.get(), mode)?;
+
+        Ok(Response {
+            status: envelope.status,
+            message_type: envelope.message_type,
+            message_version: envelope.message_version,
+            message: Some(message),
+        }
+        .with_meta())
+    }
+
+    /// Parse the whole crossref response, `mode` decides how malformed `Work` fields are handled
+    pub fn parse(value: Value, mode: ParseMode) -> Result<Self, ErrorKind> {
         match value {
             Value::Object(map) => {
                 let status = map.get("status").ok_or_else(|| ErrorKind::MissingField {
@@ -52,12 +166,20 @@ impl TryFrom<serde_json::Value> for Response {
                                 msg: "message-type".to_string(),
                             },
                         )?)?;
-                let message_version = map.get("message-version").map_or("1.0.0", |v| v.as_str().unwrap());
+                let message_version = match map.get("message-version") {
+                    None => default_msg_version(),
+                    Some(v) => v
+                        .as_str()
+                        .ok_or_else(|| ErrorKind::InvalidField {
+                            msg: "message-version".to_string(),
+                        })?
+                        .to_string(),
+                };
                 let message = map.get("message").ok_or_else(|| ErrorKind::MissingField {
                     msg: "message".to_string(),
                 })?;
 
-                let message = Message::try_from((message_type.clone(), message.clone()))?;
+                let message = Message::parse(message_type.clone(), message.clone(), mode)?;
 
                 Ok(Response {
                     status: status
@@ -67,16 +189,77 @@ impl TryFrom<serde_json::Value> for Response {
                         })?
                         .to_string(),
                     message_type,
-                    message_version: message_version.to_string(),
+                    message_version,
                     message: Some(message),
-                })
+                }
+                .with_meta())
             }
             _ => Err(ErrorKind::InvalidField {
                 msg: "response".to_string(),
             }),
         }
     }
+
+    /// Store the `headers` of the http response on the list message, see [ResponseMeta::headers]
+    pub fn attach_headers(&mut self, headers: HashMap<String, String>) {
+        if let Some(meta) = self.message.as_mut().and_then(Message::meta_mut) {
+            meta.headers = headers;
+        }
+    }
+
+    /// copies status and version onto the list message
+    fn with_meta(mut self) -> Self {
+        if let Some(meta) = self.message.as_mut().and_then(Message::meta_mut) {
+            meta.status = self.status.clone();
+            meta.message_version = self.message_version.clone();
+        }
+        self
+    }
+}
+
+/// The fields of a response around the still unparsed `message`
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct Envelope<'a> {
+    status: String,
+    message_type: MessageType,
+    #[serde(default = "default_msg_version")]
+    message_version: String,
+    #[serde(borrow)]
+    message: Option<&'a RawValue>,
+}
+
+impl<'a> Envelope<'a> {
+    fn from_slice(json: &'a [u8]) -> Result<Self, ErrorKind> {
+        serde_json::from_slice(json).map_err(|e| ErrorKind::InvalidField {
+            msg: format!("error parsing response: {}", e),
+        })
+    }
+}
+
+impl PartialWorkList {
+    /// Parse a whole crossref response whose message is a `work-list` of selected elements
+    pub(crate) fn from_slice(json: &[u8]) -> Result<Self, ErrorKind> {
+        let envelope = Envelope::from_slice(json)?;
+        if envelope.message_type != MessageType::WorkList {
+            return Err(ErrorKind::UnexpectedItem {
+                expected: MessageType::WorkList,
+                got: envelope.message_type,
+            });
+        }
+        let message = envelope.message.ok_or(ErrorKind::MissingMessage {
+            expected: MessageType::WorkList,
+        })?;
+        let mut list: PartialWorkList =
+            serde_json::from_str(message.get()).map_err(|e| ErrorKind::InvalidField {
+                msg: format!("error parsing partial work list: {}", e),
+            })?;
+        list.meta.status = envelope.status;
+        list.meta.message_version = envelope.message_version;
+        Ok(list)
+    }
 }
+
 /// at some routes the `msg_version` is missing, this returns the default version for a crossref response
 fn default_msg_version() -> String {
     "1.0.0".to_string()
@@ -170,6 +353,8 @@ impl<'de> Deserialize<'de> for Response {
                     items_per_page: list_resp.items_per_page,
                     query: list_resp.query,
                     items,
+                    next_cursor: list_resp.next_cursor,
+                    meta: ResponseMeta::default(),
                 })
             }};
         }
@@ -185,6 +370,8 @@ impl<'de> Deserialize<'de> for Response {
                 query: list_resp.query,
                 items,
                 next_cursor: list_resp.next_cursor,
+                warnings: Vec::new(),
+                meta: ResponseMeta::default(),
             }))
         }
 
@@ -212,7 +399,8 @@ impl<'de> Deserialize<'de> for Response {
             message_type: fragment.message_type,
             message_version: fragment.message_version,
             message,
-        })
+        }
+        .with_meta())
     }
 }
 
@@ -234,6 +422,12 @@ macro_rules! impl_list_response {
             pub query: Option<QueryResponse>,
             /// all actual message items of the response
             pub items: Vec<$ty>,
+            /// the cursor of the next page
+            #[serde(default)]
+            pub next_cursor: Option<String>,
+            /// status, version and http headers of the response this list was part of
+            #[serde(skip)]
+            pub meta: ResponseMeta,
         }
     )+
     };
@@ -245,6 +439,36 @@ impl_list_response!(
     FunderList<Funder>,
 );
 
+/// this macro generates the `envelope` accessor of the list messages
+macro_rules! impl_list_envelope {
+    ($($name:ident,)*) => {
+    $(
+        impl $name {
+            /// the metadata crossref returned along with the items
+            pub fn envelope(&self) -> ListEnvelope<'_> {
+                ListEnvelope {
+                    status: &self.meta.status,
+                    message_version: &self.meta.message_version,
+                    total_results: self.total_results,
+                    items_per_page: self.items_per_page,
+                    next_cursor: self.next_cursor.as_deref(),
+                    query: self.query.as_ref(),
+                    headers: &self.meta.headers,
+                }
+            }
+        }
+    )+
+    };
+}
+impl_list_envelope!(
+    TypeList,
+    MemberList,
+    JournalList,
+    FunderList,
+    WorkList,
+    PartialWorkList,
+);
+
 /// the different payloads of a response
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
@@ -282,7 +506,78 @@ pub enum Message {
 impl TryFrom<(MessageType, serde_json::Value)> for Message {
     type Error = ErrorKind;
     fn try_from(value: (MessageType, serde_json::Value)) -> Result<Self, Self::Error> {
-        match value {
+        Message::parse(value.0, value.1, ParseMode::Strict)
+    }
+}
+
+impl Message {
+    /// Parse the message from its json text, `Work` and `WorkList` messages skip the `Value` step.
+    pub fn from_json(message_type: MessageType, json: &str, mode: ParseMode) -> Result<Self, ErrorKind> {
+        match message_type {
+            MessageType::Work => Ok(Message::Work(Box::new(Work::from_json(json, mode)?))),
+            MessageType::WorkList => Ok(Message::WorkList(WorkList::from_json(json, mode)?)),
+            message_type => {
+                let value = serde_json::from_str(json).map_err(|e| ErrorKind::InvalidField {
+                    msg: format!("error parsing message as {}: {}", message_type.as_str(), e),
+                })?;
+                Message::parse(message_type, value, mode)
+            }
+        }
+    }
+
+    /// Store the json of every `Work`, `Member`, `Funder` and `Journal` of the message in its `raw` field.
+    ///
+    /// `value` is the json `message` this message was parsed from.
+    pub fn attach_raw(&mut self, value: &Value) {
+        let items = || value.get("items").and_then(Value::as_array).into_iter().flatten();
+        match self {
+            Message::Work(work) => work.raw = Some(value.clone()),
+            Message::Member(member) => member.raw = Some(value.clone()),
+            Message::Funder(funder) => funder.raw = Some(value.clone()),
+            Message::Journal(journal) => journal.raw = Some(value.clone()),
+            Message::WorkList(list) => {
+                // items that were skipped while parsing leniently have no counterpart, match by DOI
+                let mut raw: HashMap<Doi, &Value> = items()
+                    .filter_map(|v| Some((v.get("DOI")?.as_str()?.parse().ok()?, v)))
+                    .collect();
+                for work in list.items.iter_mut() {
+                    work.raw = raw.remove(&work.doi).cloned();
+                }
+            }
+            Message::MemberList(list) => {
+                for (member, raw) in list.items.iter_mut().zip(items()) {
+                    member.raw = Some(raw.clone());
+                }
+            }
+            Message::FunderList(list) => {
+                for (funder, raw) in list.items.iter_mut().zip(items()) {
+                    funder.raw = Some(raw.clone());
+                }
+            }
+            Message::JournalList(list) => {
+                for (journal, raw) in list.items.iter_mut().zip(items()) {
+                    journal.raw = Some(raw.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// the metadata of a list message, `None` for single items
+    pub fn meta_mut(&mut self) -> Option<&mut ResponseMeta> {
+        match self {
+            Message::TypeList(list) => Some(&mut list.meta),
+            Message::WorkList(list) => Some(&mut list.meta),
+            Message::MemberList(list) => Some(&mut list.meta),
+            Message::JournalList(list) => Some(&mut list.meta),
+            Message::FunderList(list) => Some(&mut list.meta),
+            _ => None,
+        }
+    }
+
+    /// Parse the `message` of a response, `mode` decides how malformed `Work` fields are handled
+    pub fn parse(message_type: MessageType, value: Value, mode: ParseMode) -> Result<Self, ErrorKind> {
+        match (message_type, value) {
             (MessageType::ValidationFailure, value) => {
                 let failures: Failures = from_value(value).map_err(|e| {
                     ErrorKind::InvalidField {
@@ -324,13 +619,13 @@ impl TryFrom<(MessageType, serde_json::Value)> for Message {
                 Ok(Message::TypeList(type_list))
             }
             (MessageType::Work, value) => {
-                let work = Work::try_from(value)?;
+                let work = Work::parse(&value, mode)?;
                 Ok(Message::Work(Box::new(work)))
             }
             (MessageType::WorkList, value) => {
-                let list_resp = WorkList::try_from(value).map_err(|e| {
+                let list_resp = WorkList::parse(&value, mode).map_err(|e| {
                     ErrorKind::InvalidField {
-                        msg: "error parsing message as work-list".to_string(),
+                        msg: format!("error parsing message as work-list: {}", e),
                     }
                 })?;
                 Ok(Message::WorkList(list_resp))
@@ -412,18 +707,20 @@ impl Into<CrossrefType> for crate::query::types::Type {
 pub struct WorkAgency {
     /// the DOI fo the work that belongs to the `agency`
     #[serde(rename = "DOI")]
-    doi: String,
+    pub doi: Doi,
     /// the agency that owns the work with `doi`
-    agency: Agency,
+    pub agency: Agency,
 }
 
 /// response item for the `/prefix/{id}/` route
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[allow(missing_docs)]
 pub struct Prefix {
-    pub member: String,
+    /// crossref serves the member in the form `http://id.crossref.org/member/MEMBER_ID`
+    pub member: MemberId,
     pub name: String,
-    pub prefix: String,
+    /// crossref serves the prefix in the form `http://id.crossref.org/prefix/DOI_PREFIX`
+    pub prefix: ident::Prefix,
 }
 
 /// all possible `message-type` of a response
@@ -643,44 +940,226 @@ impl Failure {
 }
 
 /// response item for the `/funder/{id}` route
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
-#[serde(rename_all = "kebab-case", default)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
 #[allow(missing_docs)]
 pub struct Funder {
-    pub hierarchy_names: HashMap<String, Option<String>>,
-    pub hierarchy: HashMap<String, HashMap<String, HashMap<String, bool>>>,
-    pub id: String,
+    #[serde(default)]
+    pub hierarchy_names: HashMap<FunderId, Option<String>>,
+    #[serde(default)]
+    pub hierarchy: FunderHierarchy,
+    pub id: FunderId,
+    #[serde(default)]
     pub location: String,
+    #[serde(default)]
     pub work_count: Option<usize>,
+    #[serde(default)]
     pub descendant_work_count: Option<usize>,
-    pub descendants: Vec<String>,
+    #[serde(default)]
+    pub descendants: Vec<FunderId>,
+    #[serde(default)]
     pub name: String,
+    #[serde(default)]
     pub alt_names: Vec<String>,
+    #[serde(default)]
     pub uri: String,
-    pub replaces: Vec<String>,
-    pub replaced_by: Vec<String>,
+    #[serde(default)]
+    pub replaces: Vec<FunderId>,
+    #[serde(default)]
+    pub replaced_by: Vec<FunderId>,
+    #[serde(default)]
     pub tokens: Vec<String>,
+    /// fields crossref returned that are not modeled by `Funder`
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+    /// the unparsed json of the funder, only set if the client is configured to keep it
+    #[serde(skip)]
+    pub raw: Option<Value>,
+}
+
+impl Funder {
+    /// the name of the funder `id` of the hierarchy
+    pub fn hierarchy_name(&self, id: &FunderId) -> Option<&str> {
+        self.hierarchy_names.get(id).and_then(|name| name.as_deref())
+    }
+
+    /// the node of this funder in its hierarchy
+    pub fn node(&self) -> Option<&FunderNode> {
+        self.hierarchy.find(&self.id)
+    }
+
+    /// the parent of this funder, `None` if it is at the top of the hierarchy
+    pub fn parent(&self) -> Option<&FunderNode> {
+        self.hierarchy.parent_of(&self.id)
+    }
+
+    /// the ancestors of this funder, starting with the top of the hierarchy
+    pub fn ancestors(&self) -> Vec<&FunderNode> {
+        self.hierarchy.ancestors_of(&self.id)
+    }
+
+    /// the direct children of this funder
+    pub fn children(&self) -> &[FunderNode] {
+        self.node().map(|node| node.children.as_slice()).unwrap_or_default()
+    }
+}
+
+/// The part of the funder hierarchy crossref returns for a `Funder`.
+///
+/// Crossref truncates the hierarchy, nodes with `more` set have children that are not included.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FunderHierarchy {
+    /// the funders at the top of the returned hierarchy
+    pub roots: Vec<FunderNode>,
+}
+
+/// A funder in a `FunderHierarchy`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunderNode {
+    /// the id of the funder
+    pub id: FunderId,
+    /// the sub-organizations of the funder
+    pub children: Vec<FunderNode>,
+    /// whether the funder has children that were not included in the response
+    pub more: bool,
+}
+
+impl FunderHierarchy {
+    /// all nodes of the hierarchy, parents before their children
+    pub fn iter(&self) -> impl Iterator<Item = &FunderNode> {
+        self.roots.iter().flat_map(|root| std::iter::once(root).chain(root.descendants()))
+    }
+
+    /// the node of the funder `id`
+    pub fn find(&self, id: &FunderId) -> Option<&FunderNode> {
+        self.iter().find(|node| node.id == *id)
+    }
+
+    /// the parent node of the funder `id`
+    pub fn parent_of(&self, id: &FunderId) -> Option<&FunderNode> {
+        self.iter()
+            .find(|node| node.children.iter().any(|child| child.id == *id))
+    }
+
+    /// the ancestors of the funder `id`, starting with the top of the hierarchy
+    pub fn ancestors_of(&self, id: &FunderId) -> Vec<&FunderNode> {
+        let mut ancestors = Vec::new();
+        let mut current = id;
+        while let Some(parent) = self.parent_of(current) {
+            ancestors.push(parent);
+            current = &parent.id;
+        }
+        ancestors.reverse();
+        ancestors
+    }
+}
+
+impl FunderNode {
+    /// all nodes below this funder, parents before their children
+    pub fn descendants(&self) -> Box<dyn Iterator<Item = &FunderNode> + '_> {
+        Box::new(
+            self.children
+                .iter()
+                .flat_map(|child| std::iter::once(child).chain(child.descendants())),
+        )
+    }
+
+    fn from_map(id: FunderId, map: &Map<String, Value>) -> Result<Self, String> {
+        let mut more = false;
+        let mut children = Vec::new();
+        for (key, value) in map {
+            match value {
+                Value::Bool(b) if key == "more" => more = *b,
+                Value::Object(map) => {
+                    let id = key.parse().map_err(|e| format!("{}", e))?;
+                    children.push(FunderNode::from_map(id, map)?);
+                }
+                value => return Err(format!("unexpected value `{}` in funder hierarchy", value)),
+            }
+        }
+        Ok(FunderNode { id, children, more })
+    }
+
+    fn to_map(&self) -> Map<String, Value> {
+        let mut map: Map<String, Value> = self
+            .children
+            .iter()
+            .map(|child| (child.id.to_string(), Value::Object(child.to_map())))
+            .collect();
+        if self.more {
+            map.insert("more".to_string(), Value::Bool(true));
+        }
+        map
+    }
+}
+
+impl Serialize for FunderHierarchy {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let map: Map<String, Value> = self
+            .roots
+            .iter()
+            .map(|root| (root.id.to_string(), Value::Object(root.to_map())))
+            .collect();
+        map.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FunderHierarchy {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let map = Map::<String, Value>::deserialize(deserializer)?;
+        let roots = map
+            .iter()
+            .map(|(id, value)| {
+                let id = id.parse().map_err(|e| format!("{}", e))?;
+                match value {
+                    Value::Object(map) => FunderNode::from_map(id, map),
+                    value => Err(format!("unexpected value `{}` in funder hierarchy", value)),
+                }
+            })
+            .collect::<Result<_, _>>()
+            .map_err(serde::de::Error::custom)?;
+        Ok(FunderHierarchy { roots })
+    }
 }
 
 /// response item for the `/member/{id}` route
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
-#[serde(rename_all = "kebab-case", default)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
 #[allow(missing_docs)]
 pub struct Member {
+    #[serde(default)]
     pub primary_name: String,
+    #[serde(default)]
     pub last_status_check_time: usize,
+    #[serde(default)]
     pub counts: Counts,
+    #[serde(default)]
     pub breakdowns: Breakdowns,
-    pub prefixes: Vec<String>,
+    #[serde(default)]
+    pub prefixes: Vec<ident::Prefix>,
+    #[serde(default)]
     pub coverage: Coverage,
+    #[serde(default)]
     pub prefix: Vec<RefPrefix>,
-    pub id: usize,
+    pub id: MemberId,
+    #[serde(default)]
     pub tokens: Vec<String>,
-    pub counts_type: HashMap<String, HashMap<String, usize>>,
-    pub coverage_type: Value,
-    pub flags: HashMap<String, bool>,
+    #[serde(default)]
+    pub counts_type: CountsByType,
+    #[serde(default)]
+    pub coverage_type: CoverageByType,
+    #[serde(default)]
+    pub flags: Flags,
+    #[serde(default)]
     pub location: String,
+    #[serde(default)]
     pub names: Vec<String>,
+    /// fields crossref returned that are not modeled by `Member`
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+    /// the unparsed json of the member, only set if the client is configured to keep it
+    #[serde(skip)]
+    pub raw: Option<Value>,
 }
 
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
@@ -692,11 +1171,38 @@ pub struct Counts {
     pub backfile_dois: usize,
 }
 
+/// the number of DOIs of a member for each `Type` of work
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+#[allow(missing_docs)]
+pub struct CountsByType {
+    #[serde(deserialize_with = "null_as_default")]
+    pub all: HashMap<Type, usize>,
+    #[serde(deserialize_with = "null_as_default")]
+    pub current: HashMap<Type, usize>,
+    #[serde(deserialize_with = "null_as_default")]
+    pub backfile: HashMap<Type, usize>,
+}
+
+/// the coverage of a member for each `Type` of work
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+#[allow(missing_docs)]
+pub struct CoverageByType {
+    #[serde(deserialize_with = "null_as_default")]
+    pub all: HashMap<Type, TypeCoverage>,
+    #[serde(deserialize_with = "null_as_default")]
+    pub current: HashMap<Type, TypeCoverage>,
+    #[serde(deserialize_with = "null_as_default")]
+    pub backfile: HashMap<Type, TypeCoverage>,
+}
+
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case", default)]
 #[allow(missing_docs)]
 pub struct Breakdowns {
-    pub dois_by_issued_year: Vec<Vec<u32>>,
+    /// pairs of issued year and the number of DOIs issued in that year
+    pub dois_by_issued_year: Vec<(i32, usize)>,
 }
 
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
@@ -725,38 +1231,116 @@ pub struct Coverage {
     pub abstracts_current: f32,
     pub open_references_current: f32,
     pub references_current: f32,
+    pub descriptions_current: f32,
+    pub descriptions_backfile: f32,
+    pub ror_ids_current: f32,
+    pub ror_ids_backfile: f32,
+}
+
+/// the coverage of a journal for all, current and backfile DOIs
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+#[allow(missing_docs)]
+pub struct CoverageType {
+    pub all: Option<TypeCoverage>,
+    pub current: Option<TypeCoverage>,
+    pub backfile: Option<TypeCoverage>,
+}
+
+/// the share of DOIs that include the respective metadata
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case", default)]
+#[allow(missing_docs)]
+pub struct TypeCoverage {
+    pub last_status_check_time: Option<i64>,
+    pub affiliations: f32,
+    pub abstracts: f32,
+    pub orcids: f32,
+    pub licenses: f32,
+    pub references: f32,
+    pub funders: f32,
+    pub similarity_checking: f32,
+    pub award_numbers: f32,
+    pub ror_ids: f32,
+    pub update_policies: f32,
+    pub resource_links: f32,
+    pub descriptions: f32,
 }
 
+/// which metadata crossref has deposits for
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case", default)]
 #[allow(missing_docs)]
+pub struct Flags {
+    pub deposits: bool,
+    pub deposits_articles: bool,
+    pub deposits_abstracts_current: bool,
+    pub deposits_abstracts_backfile: bool,
+    pub deposits_affiliations_current: bool,
+    pub deposits_affiliations_backfile: bool,
+    pub deposits_award_numbers_current: bool,
+    pub deposits_award_numbers_backfile: bool,
+    pub deposits_descriptions_current: bool,
+    pub deposits_descriptions_backfile: bool,
+    pub deposits_funders_current: bool,
+    pub deposits_funders_backfile: bool,
+    pub deposits_licenses_current: bool,
+    pub deposits_licenses_backfile: bool,
+    pub deposits_open_references_current: bool,
+    pub deposits_open_references_backfile: bool,
+    pub deposits_orcids_current: bool,
+    pub deposits_orcids_backfile: bool,
+    pub deposits_references_current: bool,
+    pub deposits_references_backfile: bool,
+    pub deposits_resource_links_current: bool,
+    pub deposits_resource_links_backfile: bool,
+    pub deposits_ror_ids_current: bool,
+    pub deposits_ror_ids_backfile: bool,
+    pub deposits_similarity_checking_current: bool,
+    pub deposits_similarity_checking_backfile: bool,
+    pub deposits_update_policies_current: bool,
+    pub deposits_update_policies_backfile: bool,
+}
+
+/// deserializes `null` into the default value of `T`, crossref sends `null` for empty metadata
+pub(crate) fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[allow(missing_docs)]
 pub struct RefPrefix {
-    pub value: String,
+    pub value: ident::Prefix,
+    #[serde(default)]
     pub name: String,
+    #[serde(default)]
     pub public_references: bool,
+    #[serde(default)]
     pub reference_visibility: Option<Visibility>,
 }
 
-/// response item for the `/journal/{id}` route
-//#[derive(Debug, Clone, Deserialize, Serialize)]
-//#[serde(rename_all = "kebab-case")]
-//#[allow(missing_docs)]
-//pub struct Journal {
-//    /// could not determine type, possible PartialDateParts
-//    pub last_status_check_time: Option<Value>,
-//    pub counts: Option<Value>,
-//    pub breakdowns: Option<Value>,
-//    pub publisher: Option<String>,
-//    pub coverage: Option<Value>,
-//    pub title: Option<String>,
-//    pub subjects: Vec<Value>,
-//    pub coverage_type: Option<Value>,
-//    pub flags: Option<Value>,
-//    #[serde(rename = "ISSN")]
-//    pub issn: Vec<String>,
-//    pub issn_type: Vec<String>,
-//}
-
+/// a strictly parsed journal article, `fields` are added to or replace the minimal required fields
+#[cfg(test)]
+pub(crate) fn work_fixture(fields: Value) -> Work {
+    let mut value = serde_json::json!({
+        "DOI": "10.5555/12345678",
+        "publisher": "Acme",
+        "title": ["Toward a Unified Theory of High-Energy Metaphysics"],
+        "type": "journal-article",
+        "member": "78",
+        "created": {"date-parts": [[2019, 1, 1]], "date-time": "2019-01-01T00:00:00Z", "timestamp": 1546300800000u64},
+        "indexed": {"date-parts": [[2019, 1, 1]], "date-time": "2019-01-01T00:00:00Z", "timestamp": 1546300800000u64},
+    });
+    if let (Some(map), Value::Object(fields)) = (value.as_object_mut(), fields) {
+        map.extend(fields);
+    }
+    Work::parse(&value, ParseMode::Strict).unwrap()
+}
 
 #[cfg(test)]
 mod tests {
@@ -856,6 +1440,58 @@ mod tests {
         assert!(funder.is_funder());
     }
 
+    #[test]
+    fn funder_hierarchy() {
+        let funder: Funder = from_value(json!({
+            "id": "100006132",
+            "hierarchy-names": {"100000015": "U.S. Department of Energy", "100006132": "Office of Science", "100006151": null},
+            "hierarchy": {"100000015": {"100006132": {"100006151": {"more": true}, "100006192": {}}, "100006130": {"more": true}}}
+        }))
+        .unwrap();
+
+        let id = |s: &str| s.parse::<FunderId>().unwrap();
+        assert_eq!(id("100000015"), funder.parent().unwrap().id);
+        assert_eq!(Some("U.S. Department of Energy"), funder.hierarchy_name(&id("100000015")));
+        assert_eq!(None, funder.hierarchy_name(&id("100006151")));
+
+        let children: Vec<_> = funder.children().iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(vec!["100006151", "100006192"], children);
+        assert!(funder.children()[0].more);
+
+        let all: Vec<_> = funder.hierarchy.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(vec!["100000015", "100006130", "100006132", "100006151", "100006192"], all);
+        let ancestors: Vec<_> = funder
+            .hierarchy
+            .ancestors_of(&id("100006151"))
+            .iter()
+            .map(|n| n.id.as_str())
+            .collect();
+        assert_eq!(vec!["100000015", "100006132"], ancestors);
+        let ancestors: Vec<_> = funder.ancestors().iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(vec!["100000015"], ancestors);
+
+        let roundtrip: FunderHierarchy =
+            from_value(serde_json::to_value(&funder.hierarchy).unwrap()).unwrap();
+        assert_eq!(funder.hierarchy, roundtrip);
+    }
+
+    #[test]
+    fn member_typed_counts_and_coverage() {
+        let member: Member = from_value(json!({
+            "id": 78,
+            "counts-type": {"all": {"journal-article": 120, "book-chapter": 3}, "current": {}, "backfile": null},
+            "coverage-type": {"all": {"journal-article": {"last-status-check-time": 1700000000000u64, "references": 0.75}}, "current": null, "backfile": null},
+            "flags": {"deposits": true, "deposits-orcids-current": true}
+        }))
+        .unwrap();
+
+        assert_eq!(Some(&120), member.counts_type.all.get(&Type::JournalArticle));
+        assert!(member.counts_type.backfile.is_empty());
+        assert_eq!(0.75, member.coverage_type.all[&Type::JournalArticle].references);
+        assert!(member.flags.deposits_orcids_current);
+        assert!(!member.flags.deposits_abstracts_current);
+    }
+
     #[test]
     fn prefix_msg_deserialize() {
         let prefix_str = r#"{"status":"ok","message-type":"prefix","message-version":"1.0.0","message":{"member":"http:\/\/id.crossref.org\/member\/78","name":"Elsevier BV","prefix":"http:\/\/id.crossref.org\/prefix\/10.1016"}}"#;
@@ -892,6 +1528,28 @@ mod tests {
         assert!(journal_list.is_journal_list());
     }
 
+    #[test]
+    fn journal_typed_msg_deserialize() {
+        let journal_str = r#"{"status":"ok","message-type":"journal","message-version":"1.0.0","message":{"last-status-check-time":1700000000000,"counts":{"current-dois":12,"backfile-dois":100,"total-dois":112},"breakdowns":{"dois-by-issued-year":[[2021,10],[2022,2]]},"publisher":"Wiley","coverage":{"affiliations-current":0.5,"references-current":1.0,"ror-ids-current":0.25},"title":"Economic Geography","subjects":[{"ASJC":3305,"name":"Geography, Planning and Development"}],"coverage-type":{"all":{"last-status-check-time":1700000000000,"references":0.9,"orcids":0.2},"current":null,"backfile":null},"flags":{"deposits":true,"deposits-references-current":true},"ISSN":["0013-0095","1944-8287"],"issn-type":[{"value":"0013-0095","type":"print"},{"value":"1944-8287","type":"electronic"}]}}"#;
+
+        let journal = match from_str::<Response>(journal_str).unwrap().message {
+            Some(Message::Journal(journal)) => journal,
+            msg => panic!("expected a journal, got {:?}", msg),
+        };
+
+        assert_eq!(112, journal.counts.total_dois);
+        assert_eq!(vec![(2021, 10), (2022, 2)], journal.breakdowns.dois_by_issued_year);
+        assert_eq!(0.25, journal.coverage.ror_ids_current);
+        assert_eq!(Some(3305), journal.subjects[0].asjc);
+        assert_eq!(0.9, journal.coverage_type.all.as_ref().unwrap().references);
+        assert!(journal.coverage_type.current.is_none());
+        assert!(journal.flags.deposits_references_current);
+        assert!(!journal.flags.deposits_orcids_current);
+        assert_eq!(IssnKind::Electronic, journal.issn_type[1].type_);
+        assert_eq!(1700000000000, journal.last_status_check_time.unwrap().timestamp_millis());
+        assert!(journal.extra.is_empty());
+    }
+
     #[test]
     fn journal_msg_deserialize() {
         let journal_str = r#"{"status":"ok","message-type":"journal","message-version":"1.0.0","message":{"last-status-check-time":null,"counts":null,"breakdowns":null,"publisher":"Fundacao Educacional de Criciuma- FUCRI","coverage":null,"title":"A INFLU\u00caNCIA DA PUBLICIDADE NA TRANSI\u00c7\u00c3O NUTRICIONAL UMA S\u00cdNTESE PARA ENTENDER A OBESIDADE","subjects":[],"coverage-type":null,"flags":null,"ISSN":[],"issn-type":[]}}"#;
@@ -932,4 +1590,174 @@ mod tests {
 
         assert!(work.is_work());
     }
+
+    fn work_list_value() -> Value {
+        let work = |doi: &str| {
+            json!({
+                "publisher": "American Psychological Association (APA)",
+                "title": ["How the Mind Hurts and Heals the Body."],
+                "DOI": doi,
+                "member": "15",
+                "type": "journal-article",
+                "created": {"date-parts": [[2004, 1, 21]], "date-time": "2004-01-21T14:31:19Z", "timestamp": 1074695479000u64},
+                "indexed": {"date-parts": [[2019, 2, 14]], "date-time": "2019-02-14T05:10:15Z", "timestamp": 1550121015066u64},
+                "reference-count": 105,
+                "new-field": {"introduced": "later"}
+            })
+        };
+        let mut broken = work("10.5555/12345678");
+        broken.as_object_mut().unwrap().remove("title");
+        json!({
+            "facets": {},
+            "total-results": 3,
+            "items": [work("10.1037/0003-066X.59.1.29"), broken, work("10.1037/abc")]
+        })
+    }
+
+    #[test]
+    fn partial_work_list_from_slice() {
+        let bytes = to_vec(&json!({"status": "ok", "message-type": "work-list", "message-version": "1.0.0", "message": {
+            "total-results": 1,
+            "items": [{"DOI": "10.1037/0003-066x.59.1.29", "title": ["How the Mind Hurts and Heals the Body."]}],
+        }}))
+        .unwrap();
+        let list = PartialWorkList::from_slice(&bytes).unwrap();
+        assert_eq!(1, list.items.len());
+        assert_eq!("ok", list.meta.status);
+        assert_eq!("1.0.0", list.meta.message_version);
+
+        let bytes = to_vec(&json!({"status": "ok", "message-type": "work", "message": {}})).unwrap();
+        assert!(matches!(
+            PartialWorkList::from_slice(&bytes),
+            Err(ErrorKind::UnexpectedItem { .. })
+        ));
+    }
+
+    #[test]
+    fn from_slice_matches_parse() {
+        let response = |message: Value| {
+            json!({"status": "ok", "message-type": "work-list", "message-version": "1.0.0", "message": message})
+        };
+        let bytes = to_vec(&response(work_list_value())).unwrap();
+
+        assert!(Response::from_slice(&bytes, ParseMode::Strict).is_err());
+
+        let streamed = Response::from_slice(&bytes, ParseMode::Lenient).unwrap();
+        let parsed = Response::parse(response(work_list_value()), ParseMode::Lenient).unwrap();
+        let (Some(Message::WorkList(streamed)), Some(Message::WorkList(parsed))) =
+            (streamed.message, parsed.message)
+        else {
+            panic!("expected work lists");
+        };
+        assert_eq!(2, streamed.items.len());
+        assert_eq!(parsed.warnings, streamed.warnings);
+        assert_eq!("items[1]", streamed.warnings[0].path);
+        assert_eq!(to_value(&parsed.items).unwrap(), to_value(&streamed.items).unwrap());
+    }
+
+    #[test]
+    fn from_slice_single_work() {
+        let work = work_list_value()["items"][0].clone();
+        let bytes = to_vec(&json!({"status": "ok", "message-type": "work", "message": work})).unwrap();
+        let resp = Response::from_slice(&bytes, ParseMode::Strict).unwrap();
+
+        assert_eq!("1.0.0", resp.message_version);
+        match resp.message {
+            Some(Message::Work(work)) => assert_eq!(Some(&json!(105)), work.extra.get("reference-count")),
+            msg => panic!("expected a work, got {:?}", msg),
+        }
+    }
+
+    #[test]
+    fn work_keeps_unknown_fields() {
+        let value = json!({
+            "facets": {},
+            "total-results": 1,
+            "items": [work_list_value()["items"][0]]
+        });
+        let list = WorkList::try_from(value).unwrap();
+        let work = &list.items[0];
+
+        assert_eq!(Some(&json!(105)), work.extra.get("reference-count"));
+        assert_eq!(Some(&json!({"introduced": "later"})), work.extra.get("new-field"));
+        assert!(work.extra.get("publisher").is_none());
+
+        let value = to_value(work).unwrap();
+        assert_eq!(json!(105), value["reference-count"]);
+        assert_eq!(json!("later"), value["new-field"]["introduced"]);
+    }
+
+    #[test]
+    fn member_keeps_unknown_fields() {
+        let value = json!({"id": 183, "primary-name": "Society", "new-field": [1, 2]});
+        let member: Member = from_value(value).unwrap();
+
+        assert_eq!(Some(&json!([1, 2])), member.extra.get("new-field"));
+        assert_eq!(json!([1, 2]), to_value(&member).unwrap()["new-field"]);
+    }
+
+    #[test]
+    fn attach_raw_work_list() {
+        let value = work_list_value();
+        let mut message =
+            Message::parse(MessageType::WorkList, value.clone(), ParseMode::Lenient).unwrap();
+        message.attach_raw(&value);
+
+        match message {
+            Message::WorkList(list) => {
+                assert_eq!(2, list.items.len());
+                assert_eq!(Some(&value["items"][0]), list.items[0].raw.as_ref());
+                assert_eq!(Some(&value["items"][2]), list.items[1].raw.as_ref());
+            }
+            _ => panic!("expected a work-list"),
+        }
+    }
+
+    #[test]
+    fn list_envelope() {
+        let mut page = work_list_value();
+        page["items"] = json!([page["items"][0]]);
+        page["items-per-page"] = json!(1);
+        page["next-cursor"] = json!("AoJ/4");
+        page["query"] = json!({"start-index": 0, "search-terms": "allen"});
+        let bytes = to_vec(&json!({
+            "status": "ok", "message-type": "work-list", "message-version": "1.0.0", "message": page
+        }))
+        .unwrap();
+        let mut resp = Response::from_slice(&bytes, ParseMode::Strict).unwrap();
+        resp.attach_headers(HashMap::from([
+            ("x-rate-limit-limit".to_string(), "50".to_string()),
+            ("x-rate-limit-interval".to_string(), "1s".to_string()),
+        ]));
+
+        let Some(Message::WorkList(list)) = resp.message else { panic!("expected a work-list") };
+        let envelope = list.envelope();
+        assert_eq!("ok", envelope.status);
+        assert_eq!("1.0.0", envelope.message_version);
+        assert_eq!(3, envelope.total_results);
+        assert_eq!(Some(1), envelope.items_per_page);
+        assert_eq!(Some("AoJ/4"), envelope.next_cursor);
+        assert_eq!(Some("allen"), envelope.search_terms());
+        assert!(envelope.has_more(list.items.len()));
+        // a later cursor page still echoes `start-index: 0`, only an empty page ends deep paging
+        assert!(envelope.has_more(3));
+        assert!(!envelope.has_more(0));
+        let offset_page = ListEnvelope {
+            next_cursor: None,
+            ..envelope
+        };
+        assert!(offset_page.has_more(2));
+        assert!(!offset_page.has_more(3));
+        assert_eq!(Some(50), list.meta.rate_limit());
+        assert_eq!(Some("1s"), list.meta.header("X-Rate-Limit-Interval"));
+
+        let members = json!({
+            "status": "ok", "message-type": "member-list", "message-version": "1.0.0",
+            "message": {"total-results": 0, "items": []}
+        });
+        let resp: Response = from_value(members).unwrap();
+        let Some(Message::MemberList(list)) = resp.message else { panic!("expected a member-list") };
+        assert_eq!("ok", list.envelope().status);
+        assert_eq!(0, list.envelope().start_index());
+    }
 }
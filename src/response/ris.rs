@@ -0,0 +1,366 @@
+//! Offline export of `Work` items in the tagged formats of reference managers:
+//! [RIS](https://en.wikipedia.org/wiki/RIS_(file_format)) and EndNote tagged (refer).
+
+use std::fmt::Write as _;
+use std::io;
+
+use crate::query::Type;
+use crate::response::work::{split_page_range, Contributor, Work};
+use crate::xml;
+
+/// The tagged format a `RisWriter` writes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaggedFormat {
+    /// `TY  - JOUR` ... `ER  - `
+    #[default]
+    Ris,
+    /// `%0 Journal Article` ..., records are separated by an empty line
+    EndNote,
+}
+
+/// A field of a tagged record, independent of the format it is written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Author,
+    Editor,
+    Title,
+    SecondaryTitle,
+    Year,
+    Date,
+    Volume,
+    Issue,
+    Pages,
+    Publisher,
+    SerialNumber,
+    Doi,
+    Url,
+    Link,
+    Abstract,
+    Keyword,
+    Language,
+}
+
+impl Field {
+    /// the EndNote tag of the field in a record of the RIS type `ris_type`
+    fn endnote_tag(&self, ris_type: &str) -> Option<&'static str> {
+        Some(match self {
+            Field::Author => "%A",
+            Field::Editor => "%E",
+            Field::Title => "%T",
+            // the journal of an article, the book or proceedings of a chapter or paper
+            Field::SecondaryTitle if ris_type == "JOUR" => "%J",
+            Field::SecondaryTitle => "%B",
+            Field::Year => "%D",
+            Field::Volume => "%V",
+            Field::Issue => "%N",
+            Field::Pages => "%P",
+            Field::Publisher => "%I",
+            Field::SerialNumber => "%@",
+            Field::Doi => "%R",
+            Field::Url | Field::Link => "%U",
+            Field::Abstract => "%X",
+            Field::Keyword => "%K",
+            Field::Language => "%G",
+            Field::Date => return None,
+        })
+    }
+
+    fn ris_tag(&self) -> &'static str {
+        match self {
+            Field::Author => "AU",
+            Field::Editor => "ED",
+            Field::Title => "TI",
+            Field::SecondaryTitle => "T2",
+            Field::Year => "PY",
+            Field::Date => "DA",
+            Field::Volume => "VL",
+            Field::Issue => "IS",
+            // split into `SP` and `EP` when written
+            Field::Pages => "SP",
+            Field::Publisher => "PB",
+            Field::SerialNumber => "SN",
+            Field::Doi => "DO",
+            Field::Url => "UR",
+            Field::Link => "L1",
+            Field::Abstract => "AB",
+            Field::Keyword => "KW",
+            Field::Language => "LA",
+        }
+    }
+}
+
+/// Writes `Work` items as RIS or EndNote tagged records into `out`.
+///
+/// # Example
+///
+/// ```edition2018
+/// use crossref_rs::response::ris::RisWriter;
+/// # fn run(works: &[crossref_rs::Work]) -> std::io::Result<()> {
+/// let mut writer = RisWriter::new(std::fs::File::create("works.ris")?);
+/// writer.write_all(works)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RisWriter<W> {
+    out: W,
+    format: TaggedFormat,
+    records: usize,
+}
+
+impl<W: io::Write> RisWriter<W> {
+    /// a writer for RIS records
+    pub fn new(out: W) -> Self {
+        RisWriter::with_format(out, TaggedFormat::Ris)
+    }
+
+    /// a writer for EndNote tagged records
+    pub fn endnote(out: W) -> Self {
+        RisWriter::with_format(out, TaggedFormat::EndNote)
+    }
+
+    /// a writer for records in `format`
+    pub fn with_format(out: W, format: TaggedFormat) -> Self {
+        RisWriter {
+            out,
+            format,
+            records: 0,
+        }
+    }
+
+    /// write the record of a single `work`
+    pub fn write(&mut self, work: &Work) -> io::Result<()> {
+        if self.format == TaggedFormat::EndNote && self.records > 0 {
+            self.out.write_all(b"\n")?;
+        }
+        self.out.write_all(render(work, self.format).as_bytes())?;
+        self.records += 1;
+        Ok(())
+    }
+
+    /// write the records of all `works`
+    pub fn write_all<'a, I: IntoIterator<Item = &'a Work>>(&mut self, works: I) -> io::Result<()> {
+        for work in works {
+            self.write(work)?;
+        }
+        Ok(())
+    }
+
+    /// the number of records written so far
+    pub fn records(&self) -> usize {
+        self.records
+    }
+
+    /// flush and return the underlying writer
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+impl Work {
+    /// this work as RIS record
+    pub fn to_ris(&self) -> String {
+        render(self, TaggedFormat::Ris)
+    }
+
+    /// this work as EndNote tagged record
+    pub fn to_endnote(&self) -> String {
+        render(self, TaggedFormat::EndNote)
+    }
+}
+
+/// the RIS type tag and the EndNote reference type of `type_`
+fn record_type(type_: &Type) -> (&'static str, &'static str) {
+    match type_ {
+        Type::JournalArticle => ("JOUR", "Journal Article"),
+        Type::BookChapter
+        | Type::BookSection
+        | Type::BookPart
+        | Type::BookTrack
+        | Type::ReferenceEntry => ("CHAP", "Book Section"),
+        Type::Book | Type::Monograph | Type::EditedBook | Type::ReferenceBook => ("BOOK", "Book"),
+        Type::ProceedingsArticle | Type::Proceedings => ("CONF", "Conference Paper"),
+        Type::Dissertation => ("THES", "Thesis"),
+        Type::Report => ("RPRT", "Report"),
+        Type::Dataset => ("DATA", "Dataset"),
+        _ => ("GEN", "Generic"),
+    }
+}
+
+fn fields(work: &Work) -> Vec<(Field, String)> {
+    let mut fields = Vec::new();
+    let mut push = |field: Field, value: String| {
+        let value = xml::collapse_whitespace(&value);
+        if !value.is_empty() {
+            fields.push((field, value));
+        }
+    };
+
+    for author in work.author.iter().flatten().filter_map(Contributor::display_name) {
+        push(Field::Author, author);
+    }
+    for editor in work.editor.iter().flatten().filter_map(Contributor::display_name) {
+        push(Field::Editor, editor);
+    }
+    if let Some(title) = work.full_title() {
+        push(Field::Title, title);
+    }
+    if let Some(container) = work.container_title.as_ref().and_then(|c| c.first()) {
+        push(Field::SecondaryTitle, xml::strip_tags(container));
+    }
+    if let Some(date) = work.publication_date() {
+        push(Field::Year, date.year().to_string());
+        // RIS dates are `YYYY/MM/DD/`, missing parts stay empty
+        let part = |p: Option<u32>| p.map(|p| format!("{:02}", p)).unwrap_or_default();
+        push(
+            Field::Date,
+            format!("{}/{}/{}/", date.year(), part(date.month()), part(date.day())),
+        );
+    }
+    if let Some(volume) = &work.volume {
+        push(Field::Volume, volume.clone());
+    }
+    if let Some(issue) = &work.issue {
+        push(Field::Issue, issue.clone());
+    }
+    if let Some(pages) = work.page.as_ref().or(work.article_number.as_ref()) {
+        push(Field::Pages, pages.clone());
+    }
+    push(Field::Publisher, work.publisher.clone());
+    for issn in work.issn.iter().flatten() {
+        push(Field::SerialNumber, issn.to_string());
+    }
+    for isbn in work.isbn.iter().flatten() {
+        push(Field::SerialNumber, isbn.to_string());
+    }
+    push(Field::Doi, work.doi.to_string());
+    if let Some(url) = &work.url {
+        push(Field::Url, url.clone());
+    }
+    for license in work.license.iter().flatten() {
+        push(Field::Url, license.url.clone());
+    }
+    for link in work.link.iter().flatten() {
+        push(Field::Link, link.url.clone());
+    }
+    if let Some(abstract_) = work.parsed_abstract() {
+        push(Field::Abstract, abstract_.to_plain_text());
+    }
+    for subject in work.subject.iter().flatten() {
+        push(Field::Keyword, subject.clone());
+    }
+    if let Some(language) = &work.language {
+        push(Field::Language, language.clone());
+    }
+    fields
+}
+
+fn render(work: &Work, format: TaggedFormat) -> String {
+    let (ris_type, endnote_type) = record_type(&work.type_);
+    let mut out = String::new();
+    match format {
+        TaggedFormat::Ris => {
+            // RIS lines end with CRLF
+            let mut line = |tag: &str, value: &str| {
+                let _ = write!(out, "{}  - {}\r\n", tag, value);
+            };
+            line("TY", ris_type);
+            for (field, value) in fields(work) {
+                if field == Field::Pages {
+                    let (start, end) = split_page_range(&value);
+                    line("SP", start);
+                    if let Some(end) = end {
+                        line("EP", end);
+                    }
+                } else {
+                    line(field.ris_tag(), &value);
+                }
+            }
+            line("ER", "");
+        }
+        TaggedFormat::EndNote => {
+            let _ = writeln!(out, "%0 {}", endnote_type);
+            for (field, value) in fields(work) {
+                if let Some(tag) = field.endnote_tag(ris_type) {
+                    let _ = writeln!(out, "{} {}", tag, value);
+                }
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::work_fixture;
+    use serde_json::json;
+
+    fn work() -> Work {
+        work_fixture(json!({
+            "DOI": "10.1000/xyz",
+            "title": ["A <i>study</i>"],
+            "type": "book-chapter",
+            "container-title": ["The Book"],
+            "page": "10-20",
+            "ISBN": ["9780306406157"],
+            "URL": "http://dx.doi.org/10.1000/xyz",
+            "abstract": "<jats:p>First   paragraph.</jats:p><jats:p>Second.</jats:p>",
+            "subject": ["Biology", "Ecology"],
+            "license": [{"URL": "http://creativecommons.org/licenses/by/4.0/", "content-version": "vor",
+                         "delay-in-days": 0, "start": {"date-parts": [[2019, 1, 1]]}}],
+            "author": [
+                {"family": "Smith", "given": "Jane", "sequence": "first", "affiliation": []},
+                {"family": "Jones", "sequence": "additional", "affiliation": []}
+            ],
+            "editor": [{"name": "Editors Inc", "sequence": "first", "affiliation": []}],
+            "issued": {"date-parts": [[2019, 4]]},
+        }))
+    }
+
+    #[test]
+    fn work_to_ris() {
+        let ris = work().to_ris();
+        let lines: Vec<&str> = ris.split("\r\n").collect();
+
+        assert_eq!("TY  - CHAP", lines[0]);
+        assert_eq!("AU  - Smith, Jane", lines[1]);
+        assert_eq!("AU  - Jones", lines[2]);
+        assert_eq!("ED  - Editors Inc", lines[3]);
+        assert_eq!("TI  - A study", lines[4]);
+        assert_eq!("T2  - The Book", lines[5]);
+        assert!(lines.contains(&"DA  - 2019/04//"));
+        assert!(lines.contains(&"SP  - 10"));
+        assert!(lines.contains(&"EP  - 20"));
+        assert!(lines.contains(&"SN  - 9780306406157"));
+        assert!(lines.contains(&"UR  - http://creativecommons.org/licenses/by/4.0/"));
+        assert!(lines.contains(&"AB  - First paragraph. Second."));
+        assert!(lines.contains(&"KW  - Ecology"));
+        assert!(ris.ends_with("ER  - \r\n"));
+    }
+
+    #[test]
+    fn write_endnote() {
+        let mut writer = RisWriter::endnote(Vec::new());
+        writer.write_all(&[work(), work()]).unwrap();
+        assert_eq!(2, writer.records());
+        let out = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+
+        let records: Vec<&str> = out.split("\n\n").collect();
+        assert_eq!(2, records.len());
+        assert!(records[0].starts_with("%0 Book Section\n%A Smith, Jane\n%A Jones\n%E Editors Inc\n"));
+        assert!(records[0].contains("%P 10-20\n"));
+        assert!(records[0].contains("%B The Book\n"));
+        assert!(records[0].contains("%R 10.1000/xyz\n"));
+        assert!(!records[0].contains("2019/04"));
+
+        let mut article = work();
+        article.type_ = Type::JournalArticle;
+        article.container_title = Some(vec!["Journal of Things".to_string()]);
+        let record = article.to_endnote();
+        assert!(record.starts_with("%0 Journal Article\n"));
+        assert!(record.contains("%J Journal of Things\n"));
+        assert!(!record.contains("%B "));
+    }
+}
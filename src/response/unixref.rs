@@ -0,0 +1,937 @@
+//! Parsing of the crossref deposit xml served by content negotiation, [UNIXREF](https://data.crossref.org/reports/help/schema_doc/unixref1.1/unixref1.1.html)
+//! for [crate::cn::CnFormat::CrossrefXml] and UNIXSD for [crate::cn::CnFormat::CrossrefTdm].
+//!
+//! Each `doi_record` is mapped onto a `Work` like the one of the REST api. The xml holds metadata
+//! the json does not expose in full, which is kept in the extension structs of [UnixrefRecord]:
+//! the funder and award groups, the component list and the crossmark record.
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::error::ErrorKind;
+use crate::ident::MemberId;
+use crate::response::work::{ParseMode, ParseWarning, Work};
+use crate::xml::{self, Element};
+
+/// A funder of a [FundGroup]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Funder {
+    /// the name of the funder as deposited
+    pub name: String,
+    /// the Open Funder Registry identifier, usually a `10.13039` DOI url
+    pub id: Option<String>,
+}
+
+/// A `fundgroup` of the fundref program: funders that jointly granted the awards of the group
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct FundGroup {
+    /// the funders, a nested funder name is kept as its own entry after its parent
+    pub funders: Vec<Funder>,
+    /// the award numbers
+    pub awards: Vec<String>,
+}
+
+/// An entry of the `component_list` of a record, like a figure or supplementary data
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ComponentItem {
+    /// the DOI of the component
+    pub doi: Option<String>,
+    /// the title of the component
+    pub title: Option<String>,
+    /// the description of the component
+    pub description: Option<String>,
+    /// the mime type of the component, from `format/@mime_type`
+    pub mime_type: Option<String>,
+    /// how the component relates to the work, like `isPartOf`
+    pub parent_relation: Option<String>,
+    /// the url the DOI of the component resolves to
+    pub resource: Option<String>,
+}
+
+/// An update registered in a [Crossmark] record, like a correction or retraction
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CrossmarkUpdate {
+    /// the type of the update, like `correction`
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// the DOI of the updated work
+    pub doi: String,
+    /// the date of the update as deposited, `YYYY-MM-DD`
+    pub date: Option<String>,
+}
+
+/// A custom metadata assertion of a [Crossmark] record, like a publication history date
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CrossmarkAssertion {
+    /// the name of the assertion, like `received`
+    pub name: String,
+    /// the display label
+    pub label: Option<String>,
+    /// the asserted value
+    pub value: String,
+    /// the name of the group of the assertion
+    pub group_name: Option<String>,
+    /// the display label of the group
+    pub group_label: Option<String>,
+    /// the display order
+    pub order: Option<i32>,
+    /// a link with details on the assertion
+    pub href: Option<String>,
+}
+
+/// The crossmark record of a work
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Crossmark {
+    /// the crossmark version
+    pub version: Option<String>,
+    /// the DOI of the crossmark policy page of the publisher
+    pub policy: Option<String>,
+    /// the domains the crossmark button may be shown on
+    pub domains: Vec<String>,
+    /// whether the button may only be shown on `domains`
+    pub domain_exclusive: bool,
+    /// the updates of the work
+    pub updates: Vec<CrossmarkUpdate>,
+    /// the custom metadata assertions
+    pub assertions: Vec<CrossmarkAssertion>,
+}
+
+/// A `doi_record` of a UNIXREF or UNIXSD document
+#[derive(Debug, Clone)]
+pub struct UnixrefRecord {
+    /// the record as `Work`, parsed in `ParseMode::Lenient`; the citation list is in `reference`.
+    /// A record without a member id or creation date holds placeholders for these required fields,
+    /// each with a `ParseWarning`, see [UnixrefRecord::member] and [UnixrefRecord::created].
+    pub work: Work,
+    /// the member id of the UNIXSD `crm-item`s, UNIXREF does not have it
+    pub member: Option<MemberId>,
+    /// when the DOI was created, from the UNIXSD `crm-item`s or the `timestamp` of the record
+    pub created: Option<NaiveDateTime>,
+    /// the fundref groups, flattened into `work.funder`
+    pub funding: Vec<FundGroup>,
+    /// the component list
+    pub components: Vec<ComponentItem>,
+    /// the crossmark record, reflected in `work.update_to`, `update_policy`, `content_domain` and `assertion`
+    pub crossmark: Option<Crossmark>,
+}
+
+impl UnixrefRecord {
+    /// Parse all `doi_record`s of a UNIXREF or UNIXSD document
+    ///
+    /// # Errors
+    ///
+    /// Fails with `MissingField` if the document has no `doi_record` and with `InvalidField` if
+    /// crossref reports an error, like for an unknown DOI.
+    pub fn parse_all(xml: &str) -> std::result::Result<Vec<Self>, ErrorKind> {
+        let root = xml::parse_document(xml).ok_or_else(|| ErrorKind::MissingField {
+            msg: "doi_record".to_string(),
+        })?;
+        if root.name == "doi_record" {
+            return Ok(vec![Self::from_element(&root, &[])?]);
+        }
+
+        let mut records = Vec::new();
+        collect_records(&root, &mut records);
+        if records.is_empty() {
+            let unresolved = root
+                .descendants_named("query")
+                .into_iter()
+                .find(|q| q.attr("status").is_some_and(|s| s != "resolved"));
+            if let Some(query) = unresolved {
+                return Err(ErrorKind::InvalidField {
+                    msg: format!(
+                        "query for {} is {}: {}",
+                        query.child_text("doi").unwrap_or_default(),
+                        query.attr("status").unwrap_or_default(),
+                        query.child_text("msg").unwrap_or_default()
+                    ),
+                });
+            }
+            return Err(ErrorKind::MissingField {
+                msg: "doi_record".to_string(),
+            });
+        }
+        records
+            .into_iter()
+            .map(|(record, crm)| Self::from_element(record, &crm))
+            .collect()
+    }
+
+    /// Parse the first `doi_record` of a UNIXREF or UNIXSD document, see [UnixrefRecord::parse_all]
+    pub fn parse(xml: &str) -> std::result::Result<Self, ErrorKind> {
+        Ok(Self::parse_all(xml)?.remove(0))
+    }
+
+    fn from_element(record: &Element, crm: &[&Element]) -> std::result::Result<Self, ErrorKind> {
+        let crossref = record.child("crossref").unwrap_or(record);
+        if let Some(error) = crossref.child("error") {
+            return Err(ErrorKind::InvalidField {
+                msg: format!("crossref error: {}", error.text()),
+            });
+        }
+        let top = crossref.elements().next().ok_or_else(|| ErrorKind::MissingField {
+            msg: "crossref".to_string(),
+        })?;
+        let crm = |name: &str| {
+            crm.iter()
+                .find(|item| item.attr("name") == Some(name))
+                .map(|item| item.text())
+                .filter(|text| !text.is_empty())
+        };
+
+        let metadata = top.elements().find(|el| el.name.ends_with("_metadata"));
+        let item = item_element(top, metadata);
+        let funding = fund_groups(top);
+        let components = item
+            .child("component_list")
+            .map(|list| list.children_named("component").map(component).collect())
+            .unwrap_or_default();
+        let crossmark = top.find("crossmark").map(crossmark);
+
+        let mut map = Map::new();
+        let doi = item
+            .child("doi_data")
+            .or_else(|| top.find("doi_data"))
+            .and_then(|data| data.child_text("doi"))
+            .unwrap_or_default();
+        map.insert("DOI".into(), json!(doi));
+        map.insert("URL".into(), json!(format!("https://doi.org/{}", doi)));
+        if let Some((prefix, _)) = doi.split_once('/') {
+            map.insert("prefix".into(), json!(prefix));
+        }
+        if let Some(resource) = item.child("doi_data").and_then(|d| d.child_text("resource")) {
+            map.insert("resource".into(), json!({"primary": {"URL": resource}}));
+        }
+
+        let publisher = crm("publisher-name")
+            .or_else(|| top.find("publisher_name").map(Element::text))
+            .unwrap_or_default();
+        map.insert("publisher".into(), json!(publisher));
+        let member = crm("member-id").and_then(|id| id.parse::<MemberId>().ok());
+        map.insert("member".into(), json!(member.map_or(0, |member| member.id())));
+        map.insert("type".into(), json!(work_type(top, item)));
+
+        let titles = item.child("titles");
+        let texts = |name: &str| -> Vec<String> {
+            titles
+                .map(|t| t.children_named(name).map(Element::text).collect())
+                .unwrap_or_default()
+        };
+        map.insert("title".into(), json!(texts("title")));
+        insert_list(&mut map, "subtitle", texts("subtitle"));
+        insert_list(&mut map, "original-title", texts("original_language_title"));
+
+        if let Some(metadata) = metadata.filter(|m| !std::ptr::eq(*m, item)) {
+            let container = metadata
+                .child_text("full_title")
+                .or_else(|| metadata.child_text("proceedings_title"))
+                .or_else(|| metadata.find("titles").and_then(|t| t.child_text("title")));
+            insert_list(&mut map, "container-title", container.into_iter().collect());
+            let abbrev = metadata.child_text("abbrev_title");
+            insert_list(&mut map, "short-container-title", abbrev.into_iter().collect());
+        }
+        if let Some(metadata) = metadata {
+            let issn: Vec<&Element> = metadata.descendants_named("issn");
+            insert_list(&mut map, "ISSN", issn.iter().map(|i| i.text()).collect());
+            let issn_type: Vec<Value> = issn
+                .iter()
+                .map(|i| {
+                    let kind = match i.attr("media_type") {
+                        Some("electronic") => "electronic",
+                        _ => "print",
+                    };
+                    json!({"value": i.text(), "type": kind})
+                })
+                .collect();
+            insert_list(&mut map, "issn-type", issn_type);
+            let isbn = metadata.descendants_named("isbn");
+            insert_list(&mut map, "ISBN", isbn.iter().map(|i| i.text()).collect());
+            let language = metadata.attr("language").or_else(|| item.attr("language"));
+            if let Some(language) = language {
+                map.insert("language".into(), json!(language));
+            }
+        }
+
+        let journal_issue = top.child("journal_issue");
+        let volume = journal_issue
+            .and_then(|i| i.find("volume"))
+            .map(Element::text)
+            .or_else(|| metadata.and_then(|m| m.child_text("volume")));
+        insert_text(&mut map, "volume", volume);
+        insert_text(&mut map, "issue", journal_issue.and_then(|i| i.child_text("issue")));
+        if let Some(pages) = item.child("pages") {
+            let page = match (pages.child_text("first_page"), pages.child_text("last_page")) {
+                (Some(first), Some(last)) => Some(format!("{}-{}", first, last)),
+                (first, _) => first,
+            };
+            insert_text(&mut map, "page", page);
+        }
+        let article_number = item
+            .child("publisher_item")
+            .and_then(|p| {
+                p.children_named("item_number")
+                    .find(|n| n.attr("item_number_type") == Some("article_number"))
+            })
+            .map(Element::text);
+        insert_text(&mut map, "article-number", article_number);
+
+        let dates: Vec<&Element> = [Some(item), journal_issue, metadata]
+            .iter()
+            .flatten()
+            .flat_map(|el| el.children_named("publication_date"))
+            .collect();
+        let print = dates
+            .iter()
+            .find(|d| d.attr("media_type") != Some("online"))
+            .and_then(|d| date_parts(d));
+        let online = dates
+            .iter()
+            .find(|d| d.attr("media_type") == Some("online"))
+            .and_then(|d| date_parts(d));
+        let posted = item.child("posted_date").and_then(date_parts);
+        let issued = [&print, &online, &posted]
+            .iter()
+            .filter_map(|d| d.as_ref())
+            .min()
+            .cloned()
+            .or_else(|| item.child("approval_date").and_then(date_parts))
+            .or_else(|| item.find("review_date").and_then(date_parts));
+        for (key, parts) in [
+            ("published-print", &print),
+            ("published-online", &online),
+            ("posted", &posted),
+            ("issued", &issued),
+        ] {
+            if let Some(parts) = parts {
+                map.insert(key.into(), json!({ "date-parts": [parts] }));
+            }
+        }
+        if let Some(accepted) = item.child("acceptance_date").and_then(date_parts) {
+            map.insert("accepted".into(), json!({ "date-parts": [accepted] }));
+        }
+
+        let timestamp = record.attr("timestamp").and_then(date_time);
+        let created = crm("created").and_then(|d| date_time(&d)).or(timestamp);
+        let updated = crm("last-update").and_then(|d| date_time(&d)).or(timestamp);
+        map.insert("created".into(), date_json(created.unwrap_or_default()));
+        map.insert("indexed".into(), date_json(updated.or(created).unwrap_or_default()));
+        if let Some(updated) = updated {
+            map.insert("deposited".into(), date_json(updated));
+        }
+        if let Some(count) = crm("citedby-count").and_then(|c| c.parse::<i32>().ok()) {
+            map.insert("is-referenced-by-count".into(), json!(count));
+        }
+
+        if let Some(contributors) = item.child("contributors") {
+            for role in ["author", "editor", "chair", "translator"] {
+                let people: Vec<Value> = contributors
+                    .elements()
+                    .filter(|c| c.attr("contributor_role").unwrap_or("author") == role)
+                    .map(contributor)
+                    .collect();
+                insert_list(&mut map, role, people);
+            }
+        }
+        if let Some(abstract_) = item.children_named("abstract").next() {
+            map.insert("abstract".into(), json!(abstract_.inner_xml().trim()));
+        }
+
+        if let Some(citations) = item.child("citation_list") {
+            let references: Vec<Value> = citations.children_named("citation").map(citation).collect();
+            map.insert("references-count".into(), json!(references.len()));
+            insert_list(&mut map, "reference", references);
+        }
+        let funders: Vec<Value> = funding
+            .iter()
+            .flat_map(|group| {
+                group.funders.iter().map(move |funder| {
+                    json!({
+                        "name": funder.name,
+                        "DOI": funder.id,
+                        "award": group.awards,
+                        "doi-asserted-by": "publisher",
+                    })
+                })
+            })
+            .collect();
+        insert_list(&mut map, "funder", funders);
+        insert_list(&mut map, "license", licenses(top, issued.as_deref()));
+        insert_list(&mut map, "link", links(item));
+        if let Some(crossmark) = &crossmark {
+            insert_crossmark(&mut map, crossmark);
+        }
+
+        let mut work = Work::parse(&Value::Object(map), ParseMode::Lenient)?;
+        let mut placeholder = |path: &str, reason: &str| {
+            work.warnings.push(ParseWarning {
+                doi: Some(doi.clone()),
+                path: path.to_string(),
+                reason: reason.to_string(),
+            })
+        };
+        if member.is_none() {
+            placeholder("member", "the record has no member id, `0` is a placeholder");
+        }
+        if created.is_none() {
+            placeholder("created", "the record has no creation date, the unix epoch is a placeholder");
+        }
+        Ok(UnixrefRecord {
+            work,
+            member,
+            created,
+            funding,
+            components,
+            crossmark,
+        })
+    }
+}
+
+/// collect the `doi_record`s below `el` with the UNIXSD `crm-item`s of their parent
+fn collect_records<'a>(el: &'a Element, out: &mut Vec<(&'a Element, Vec<&'a Element>)>) {
+    for child in el.elements() {
+        if child.name == "doi_record" {
+            out.push((child, el.children_named("crm-item").collect()));
+        } else {
+            collect_records(child, out);
+        }
+    }
+}
+
+/// the element that holds the titles, contributors and `doi_data` of the work
+fn item_element<'a>(top: &'a Element, metadata: Option<&'a Element>) -> &'a Element {
+    [
+        "journal_article",
+        "content_item",
+        "conference_paper",
+        "dataset",
+        "component",
+    ]
+    .iter()
+    .find_map(|name| top.find(name))
+    .or_else(|| top.child("journal_issue").filter(|i| i.child("doi_data").is_some()))
+    .or(metadata)
+    .unwrap_or(top)
+}
+
+/// the `Type` of the work, like the REST api derives it from the deposit
+fn work_type(top: &Element, item: &Element) -> &'static str {
+    match (top.name.as_str(), item.name.as_str()) {
+        ("journal", "journal_article") => "journal-article",
+        ("journal", "journal_issue") => "journal-issue",
+        ("journal", _) => "journal",
+        ("book", "content_item") => match item.attr("component_type") {
+            Some("section") => "book-section",
+            Some("part") => "book-part",
+            Some("track") => "book-track",
+            Some("reference_entry") => "reference-entry",
+            _ => "book-chapter",
+        },
+        ("book", _) => match top.attr("book_type") {
+            Some("edited_book") => "edited-book",
+            Some("monograph") => "monograph",
+            Some("reference") => "reference-book",
+            _ => "book",
+        },
+        ("conference", "conference_paper") => "proceedings-article",
+        ("conference", _) => "proceedings",
+        ("dissertation", _) => "dissertation",
+        ("report-paper", _) => "report",
+        ("standard", _) => "standard",
+        ("database", _) => "dataset",
+        ("sa_component", _) => "component",
+        ("posted_content", _) => "posted-content",
+        ("peer_review", _) => "peer-review",
+        _ => "other",
+    }
+}
+
+/// the `[year, month, day]` of a date element like `publication_date`
+fn date_parts(el: &Element) -> Option<Vec<u32>> {
+    let part = |name: &str| el.child_text(name).and_then(|v| v.parse::<u32>().ok());
+    let mut parts = vec![part("year")?];
+    if let Some(month) = part("month").filter(|m| (1..=12).contains(m)) {
+        parts.push(month);
+        parts.extend(part("day"));
+    }
+    Some(parts)
+}
+
+/// the first day of date parts of any precision
+fn first_day(parts: &[u32]) -> Option<NaiveDate> {
+    let part = |i: usize| parts.get(i).copied().unwrap_or(1);
+    NaiveDate::from_ymd_opt(*parts.first()? as i32, part(1), part(2))
+}
+
+/// parse the `created` and `last-update` of UNIXSD or the `timestamp` of a `doi_record`
+fn date_time(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim();
+    DateTime::parse_from_rfc3339(value)
+        .map(|d| d.naive_utc())
+        .ok()
+        .or_else(|| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").ok())
+        .or_else(|| {
+            // deposit timestamps like `201901011200000000`
+            let digits = value.get(..14)?;
+            NaiveDateTime::parse_from_str(digits, "%Y%m%d%H%M%S").ok()
+        })
+}
+
+/// a `Date` of the REST api
+fn date_json(date: NaiveDateTime) -> Value {
+    json!({
+        "date-parts": [[date.year(), date.month(), date.day()]],
+        "date-time": date.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        "timestamp": date.and_utc().timestamp_millis().max(0),
+    })
+}
+
+fn insert_list(map: &mut Map<String, Value>, key: &str, values: Vec<impl Into<Value>>) {
+    if !values.is_empty() {
+        let values: Vec<Value> = values.into_iter().map(Into::into).collect();
+        map.insert(key.to_string(), Value::Array(values));
+    }
+}
+
+fn insert_text(map: &mut Map<String, Value>, key: &str, value: Option<String>) {
+    if let Some(value) = value.filter(|v| !v.is_empty()) {
+        map.insert(key.to_string(), json!(value));
+    }
+}
+
+/// a `person_name` or `organization` as `Contributor` json
+fn contributor(el: &Element) -> Value {
+    let mut affiliations: Vec<String> = el
+        .children_named("affiliation")
+        .map(Element::text)
+        .collect();
+    affiliations.extend(el.descendants_named("institution_name").into_iter().map(Element::text));
+    let affiliation: Vec<Value> = affiliations
+        .into_iter()
+        .map(|name| json!({ "name": name }))
+        .collect();
+    let sequence = el.attr("sequence").unwrap_or("additional");
+
+    if el.name == "organization" {
+        return json!({"name": el.text(), "affiliation": affiliation, "sequence": sequence});
+    }
+    let mut map = Map::new();
+    insert_text(&mut map, "given", el.child_text("given_name"));
+    insert_text(&mut map, "family", el.child_text("surname"));
+    insert_text(&mut map, "suffix", el.child_text("suffix"));
+    if let Some(orcid) = el.child("ORCID") {
+        map.insert("ORCID".into(), json!(orcid.text()));
+        map.insert(
+            "authenticated-orcid".into(),
+            json!(orcid.attr("authenticated") == Some("true")),
+        );
+    }
+    map.insert("affiliation".into(), Value::Array(affiliation));
+    map.insert("sequence".into(), json!(sequence));
+    Value::Object(map)
+}
+
+/// a `citation` as `Reference` json
+fn citation(el: &Element) -> Value {
+    let mut map = Map::new();
+    map.insert("key".into(), json!(el.attr("key").unwrap_or_default()));
+    if let Some(doi) = el.child("doi") {
+        map.insert("DOI".into(), json!(doi.text()));
+        let asserted_by = match doi.attr("provider") {
+            Some("crossref") => "crossref",
+            _ => "publisher",
+        };
+        map.insert("doi-asserted-by".into(), json!(asserted_by));
+    }
+    for (key, name) in [
+        ("issue", "issue"),
+        ("first-page", "first_page"),
+        ("volume", "volume"),
+        ("edition", "edition_number"),
+        ("component", "component_number"),
+        ("standard-designator", "std_designator"),
+        ("standards-body", "standards_body_name"),
+        ("author", "author"),
+        ("unstructured", "unstructured_citation"),
+        ("journal-title", "journal_title"),
+        ("article-title", "article_title"),
+        ("series-title", "series_title"),
+        ("volume-title", "volume_title"),
+        ("ISSN", "issn"),
+        ("ISBN", "isbn"),
+    ] {
+        let text = el.child(name).or_else(|| el.find(name)).map(Element::text);
+        insert_text(&mut map, key, text);
+    }
+    insert_text(&mut map, "year", el.child_text("cYear").or_else(|| el.child_text("year")));
+    Value::Object(map)
+}
+
+/// the groups of all fundref programs of the record
+fn fund_groups(top: &Element) -> Vec<FundGroup> {
+    let mut groups = Vec::new();
+    for program in top.descendants_named("program") {
+        if program.attr("name") != Some("fundref") {
+            continue;
+        }
+        let fundgroups: Vec<&Element> = program
+            .children_named("assertion")
+            .filter(|a| a.attr("name") == Some("fundgroup"))
+            .collect();
+        // some deposits list the funders directly in the program
+        let fundgroups = if fundgroups.is_empty() { vec![program] } else { fundgroups };
+        for group in fundgroups {
+            let assertions = group.descendants_named("assertion");
+            let named = |name: &'static str| {
+                assertions
+                    .iter()
+                    .filter(move |a| a.attr("name") == Some(name))
+            };
+            let funders = named("funder_name")
+                .map(|funder| Funder {
+                    name: funder.own_text(),
+                    id: funder
+                        .children_named("assertion")
+                        .find(|a| a.attr("name") == Some("funder_identifier"))
+                        .map(Element::text),
+                })
+                .collect();
+            let awards = named("award_number").map(|a| a.text()).collect();
+            groups.push(FundGroup { funders, awards });
+        }
+    }
+    groups
+}
+
+/// the `license_ref`s of the AccessIndicators program as `License` json
+fn licenses(top: &Element, issued: Option<&[u32]>) -> Vec<Value> {
+    let issued = issued.and_then(first_day);
+    let mut licenses = Vec::new();
+    for program in top.descendants_named("program") {
+        if program.attr("name") != Some("AccessIndicators") {
+            continue;
+        }
+        for license in program.children_named("license_ref") {
+            let start = license
+                .attr("start_date")
+                .and_then(|d| NaiveDate::parse_from_str(d.trim(), "%Y-%m-%d").ok())
+                .or(issued);
+            let Some(start) = start else { continue };
+            let delay = issued.map_or(0, |issued| (start - issued).num_days().max(0));
+            licenses.push(json!({
+                "URL": license.text(),
+                "content-version": license.attr("applies_to").unwrap_or("unspecified"),
+                "delay-in-days": delay,
+                "start": { "date-parts": [[start.year(), start.month(), start.day()]] },
+            }));
+        }
+    }
+    licenses
+}
+
+/// the resources of the `collection`s of the `doi_data` as `ResourceLink` json
+fn links(item: &Element) -> Vec<Value> {
+    let Some(doi_data) = item.child("doi_data") else {
+        return Vec::new();
+    };
+    let mut links = Vec::new();
+    for collection in doi_data.children_named("collection") {
+        let application = match collection.attr("property") {
+            Some("text-mining") => "text-mining",
+            Some("crawler-based") => "similarity-checking",
+            Some("syndication") => "syndication",
+            _ => "unspecified",
+        };
+        for resource in collection.descendants_named("resource") {
+            links.push(json!({
+                "URL": resource.text(),
+                "content-type": resource.attr("mime_type").unwrap_or("unspecified"),
+                "content-version": resource.attr("content_version").unwrap_or("vor"),
+                "intended-application": application,
+            }));
+        }
+    }
+    links
+}
+
+fn component(el: &Element) -> ComponentItem {
+    let doi_data = el.child("doi_data");
+    ComponentItem {
+        doi: doi_data.and_then(|d| d.child_text("doi")),
+        title: el.child("titles").and_then(|t| t.child_text("title")),
+        description: el.child_text("description"),
+        mime_type: el.child("format").and_then(|f| f.attr("mime_type")).map(str::to_string),
+        parent_relation: el.attr("parent_relation").map(str::to_string),
+        resource: doi_data.and_then(|d| d.child_text("resource")),
+    }
+}
+
+fn crossmark(el: &Element) -> Crossmark {
+    let domains = el
+        .child("crossmark_domains")
+        .map(|d| d.descendants_named("domain").into_iter().map(Element::text).collect())
+        .unwrap_or_default();
+    let updates = el
+        .child("updates")
+        .map(|u| {
+            u.children_named("update")
+                .map(|update| CrossmarkUpdate {
+                    type_: update.attr("type").unwrap_or_default().to_string(),
+                    doi: update.text(),
+                    date: update.attr("date").map(str::to_string),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let assertions = el
+        .child("custom_metadata")
+        .map(|m| {
+            m.children_named("assertion")
+                .map(|a| CrossmarkAssertion {
+                    name: a.attr("name").unwrap_or_default().to_string(),
+                    label: a.attr("label").map(str::to_string),
+                    value: a.text(),
+                    group_name: a.attr("group_name").map(str::to_string),
+                    group_label: a.attr("group_label").map(str::to_string),
+                    order: a.attr("order").and_then(|o| o.parse().ok()),
+                    href: a.attr("href").map(str::to_string),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    Crossmark {
+        version: el.child_text("crossmark_version"),
+        policy: el.child_text("crossmark_policy"),
+        domains,
+        domain_exclusive: el.child_text("crossmark_domain_exclusive").as_deref() == Some("true"),
+        updates,
+        assertions,
+    }
+}
+
+/// mirror the crossmark record into the `Work` fields of the REST api
+fn insert_crossmark(map: &mut Map<String, Value>, crossmark: &Crossmark) {
+    if let Some(policy) = &crossmark.policy {
+        let url = if policy.starts_with("http") {
+            policy.clone()
+        } else {
+            format!("https://doi.org/{}", policy)
+        };
+        map.insert("update-policy".into(), json!(url));
+    }
+    map.insert(
+        "content-domain".into(),
+        json!({"domain": crossmark.domains, "crossmark-restriction": crossmark.domain_exclusive}),
+    );
+    let updates: Vec<Value> = crossmark
+        .updates
+        .iter()
+        .filter_map(|update| {
+            let date = NaiveDate::parse_from_str(update.date.as_deref()?, "%Y-%m-%d").ok()?;
+            Some(json!({
+                "DOI": update.doi,
+                "type": update.type_,
+                "updated": {"date-parts": [[date.year(), date.month(), date.day()]]},
+            }))
+        })
+        .collect();
+    insert_list(map, "update-to", updates);
+    let assertions: Vec<Value> = crossmark
+        .assertions
+        .iter()
+        .map(|a| {
+            let mut assertion = json!({
+                "name": a.name,
+                "value": a.value,
+                "label": a.label,
+                "order": a.order,
+                "URL": a.href,
+            });
+            if let Some(group) = &a.group_name {
+                assertion["group"] = json!({"name": group, "label": a.group_label});
+            }
+            assertion
+        })
+        .collect();
+    insert_list(map, "assertion", assertions);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::{ContentVersion, IntendedApplication, Type};
+
+    const UNIXSD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<crossref_result xmlns="http://www.crossref.org/qrschema/3.0" version="3.0">
+  <query_result><body>
+    <query status="resolved">
+      <doi type="journal_article">10.5555/12345678</doi>
+      <crm-item name="publisher-name" type="string">Acme Publishing</crm-item>
+      <crm-item name="member-id" type="number">7822</crm-item>
+      <crm-item name="citedby-count" type="number">3</crm-item>
+      <crm-item name="created" type="date">2019-01-15T10:00:00Z</crm-item>
+      <crm-item name="last-update" type="date">2020-03-01T08:30:00Z</crm-item>
+      <doi_record>
+        <crossref xmlns:jats="http://www.ncbi.nlm.nih.gov/JATS1" xmlns:fr="http://www.crossref.org/fundref.xsd" xmlns:ai="http://www.crossref.org/AccessIndicators.xsd">
+          <journal>
+            <journal_metadata language="en">
+              <full_title>Journal of Psychoceramics</full_title>
+              <abbrev_title>J. Psychoceram.</abbrev_title>
+              <issn media_type="print">0264-3561</issn>
+            </journal_metadata>
+            <journal_issue>
+              <publication_date media_type="print"><year>2019</year></publication_date>
+              <journal_volume><volume>5</volume></journal_volume>
+              <issue>11</issue>
+            </journal_issue>
+            <journal_article publication_type="full_text">
+              <titles><title>Toward a Unified Theory of High-Energy Metaphysics</title></titles>
+              <contributors>
+                <person_name sequence="first" contributor_role="author">
+                  <given_name>Josiah</given_name><surname>Carberry</surname>
+                  <affiliation>Brown University</affiliation>
+                  <ORCID authenticated="true">https://orcid.org/0000-0002-1825-0097</ORCID>
+                </person_name>
+                <organization sequence="additional" contributor_role="author">Psychoceramics Consortium</organization>
+              </contributors>
+              <jats:abstract><jats:p>Cracked pots &amp; theory.</jats:p></jats:abstract>
+              <publication_date media_type="online"><month>02</month><day>01</day><year>2019</year></publication_date>
+              <pages><first_page>1</first_page><last_page>3</last_page></pages>
+              <crossmark>
+                <crossmark_version>1</crossmark_version>
+                <crossmark_policy>10.5555/crossmark_policy</crossmark_policy>
+                <crossmark_domains><crossmark_domain><domain>psychoceramics.labs.crossref.org</domain></crossmark_domain></crossmark_domains>
+                <crossmark_domain_exclusive>false</crossmark_domain_exclusive>
+                <updates><update type="correction" date="2019-03-01">10.5555/87654321</update></updates>
+                <custom_metadata>
+                  <assertion name="received" label="Received" group_name="publication_history" group_label="Publication History" order="0">2018-10-01</assertion>
+                  <fr:program name="fundref">
+                    <fr:assertion name="fundgroup">
+                      <fr:assertion name="funder_name">National Science Foundation
+                        <fr:assertion name="funder_identifier">https://doi.org/10.13039/100000001</fr:assertion>
+                      </fr:assertion>
+                      <fr:assertion name="award_number">CHE-1234</fr:assertion>
+                      <fr:assertion name="award_number">CHE-5678</fr:assertion>
+                    </fr:assertion>
+                  </fr:program>
+                  <ai:program name="AccessIndicators">
+                    <ai:license_ref applies_to="vor" start_date="2019-08-01">https://creativecommons.org/licenses/by/4.0/</ai:license_ref>
+                  </ai:program>
+                </custom_metadata>
+              </crossmark>
+              <doi_data>
+                <doi>10.5555/12345678</doi>
+                <resource>https://psychoceramics.labs.crossref.org/10.5555-12345678.html</resource>
+                <collection property="text-mining">
+                  <item><resource mime_type="application/pdf">https://psychoceramics.labs.crossref.org/12345678.pdf</resource></item>
+                </collection>
+              </doi_data>
+              <citation_list>
+                <citation key="ref1">
+                  <journal_title>Journal of Metaphysics</journal_title><author>Smith</author>
+                  <volume>2</volume><first_page>10</first_page><cYear>2001</cYear>
+                  <doi provider="crossref">10.5555/ref1</doi>
+                </citation>
+                <citation key="ref2"><unstructured_citation>Jones, On Pots, 1999.</unstructured_citation></citation>
+              </citation_list>
+              <component_list>
+                <component parent_relation="isPartOf">
+                  <titles><title>Figure 1</title></titles>
+                  <format mime_type="image/png"/>
+                  <doi_data><doi>10.5555/12345678.f1</doi><resource>https://example.org/f1</resource></doi_data>
+                </component>
+              </component_list>
+            </journal_article>
+          </journal>
+        </crossref>
+      </doi_record>
+    </query>
+  </body></query_result>
+</crossref_result>"#;
+
+    #[test]
+    fn parse_unixsd() {
+        let record = UnixrefRecord::parse(UNIXSD).unwrap();
+        let work = &record.work;
+        assert!(work.warnings.is_empty(), "{:?}", work.warnings);
+        assert_eq!("10.5555/12345678", work.doi.as_str());
+        assert_eq!(Type::JournalArticle, work.type_);
+        assert_eq!("Acme Publishing", work.publisher);
+        assert_eq!(Some(3), work.is_referenced_by_count);
+        assert_eq!(Some(vec!["Journal of Psychoceramics".to_string()]), work.container_title);
+        assert_eq!(Some("5"), work.volume.as_deref());
+        assert_eq!(Some("11"), work.issue.as_deref());
+        assert_eq!(Some("1-3"), work.page.as_deref());
+        assert_eq!("2019-01-15T10:00:00Z", work.created.date_time);
+        assert_eq!(Some("<p>Cracked pots &amp; theory.</p>"), work.abstract_.as_deref());
+
+        let authors = work.author.as_ref().unwrap();
+        assert_eq!(2, authors.len());
+        assert_eq!(Some("Carberry"), authors[0].family.as_deref());
+        assert!(authors[0].orcid.is_some());
+        assert_eq!("Brown University", authors[0].affiliation[0].name);
+        assert_eq!(Some("Psychoceramics Consortium"), authors[1].name.as_deref());
+
+        let references = work.reference.as_ref().unwrap();
+        assert_eq!(Some(2), work.references_count);
+        assert_eq!(Some("10.5555/ref1"), references[0].doi.as_deref());
+        assert_eq!(Some("2001"), references[0].year.as_deref());
+        assert_eq!(Some("Jones, On Pots, 1999."), references[1].unstructured.as_deref());
+
+        assert_eq!(
+            vec![FundGroup {
+                funders: vec![Funder {
+                    name: "National Science Foundation".to_string(),
+                    id: Some("https://doi.org/10.13039/100000001".to_string()),
+                }],
+                awards: vec!["CHE-1234".to_string(), "CHE-5678".to_string()],
+            }],
+            record.funding
+        );
+        let funder = &work.funder.as_ref().unwrap()[0];
+        assert_eq!("10.13039/100000001", funder.doi.as_ref().unwrap().as_str());
+
+        let license = &work.license.as_ref().unwrap()[0];
+        assert_eq!(ContentVersion::Vor, license.content_version);
+        assert_eq!(212, license.delay_in_days);
+        let link = &work.link.as_ref().unwrap()[0];
+        assert_eq!(IntendedApplication::TextMining, link.intended_application);
+
+        let crossmark = record.crossmark.as_ref().unwrap();
+        assert_eq!(1, crossmark.assertions.len());
+        assert_eq!("10.5555/87654321", work.update_to.as_ref().unwrap()[0].doi.as_str());
+        let assertion = &work.assertion.as_ref().unwrap()[0];
+        assert_eq!("publication_history", assertion.group.as_ref().unwrap().name);
+
+        assert_eq!(1, record.components.len());
+        assert_eq!(Some("image/png"), record.components[0].mime_type.as_deref());
+    }
+
+    #[test]
+    fn unixref_errors() {
+        let missing = "<doi_records><doi_record><crossref><error>10.5555/missing</error></crossref></doi_record></doi_records>";
+        assert!(matches!(UnixrefRecord::parse(missing), Err(ErrorKind::InvalidField { .. })));
+        assert!(matches!(UnixrefRecord::parse("not xml"), Err(ErrorKind::MissingField { .. })));
+    }
+
+    #[test]
+    fn unixref_placeholders() {
+        let unixref = r#"<doi_records><doi_record><crossref><journal><journal_article>
+            <titles><title>Pots</title></titles>
+            <publication_date><year>2019</year></publication_date>
+            <doi_data><doi>10.5555/1</doi><resource>https://example.org/1</resource></doi_data>
+          </journal_article></journal></crossref></doi_record></doi_records>"#;
+        let record = UnixrefRecord::parse(unixref).unwrap();
+        assert_eq!(None, record.member);
+        assert_eq!(None, record.created);
+        let paths: Vec<&str> = record.work.warnings.iter().map(|w| w.path.as_str()).collect();
+        assert_eq!(vec!["member", "created"], paths);
+
+        let record = UnixrefRecord::parse(UNIXSD).unwrap();
+        assert_eq!(Some(MemberId::new(7822)), record.member);
+        assert_eq!("2019-01-15 10:00:00", record.created.unwrap().to_string());
+    }
+}
@@ -4,20 +4,24 @@ use core::hash;
 use std::collections::HashMap;
 
 use crate::error::{ErrorKind, Result};
-use crate::response::{FacetMap, QueryResponse};
+use crate::ident::{Doi, Isbn, Issn, MemberId, Orcid, Prefix};
+use crate::query::{
+    ContentVersion, DoiAssertedBy, IntendedApplication, RelationIdType, RelationType, Type,
+};
+use crate::response::{Abstract, FacetMap, QueryResponse, ResponseMeta};
 use crate::{Crossref, WorkListQuery, WorksQuery};
 use chrono::{Datelike, NaiveDate};
 use failure::Error;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::value::RawValue;
+use serde_json::{Map, Value};
 
 use super::FacetItem;
 
-/// A hashmap containing relation name, `Relation` pairs.
+/// A hashmap containing relation type, `Relation` pairs.
 /// [crossref rest-api-doc](https://github.com/CrossRef/rest-api-doc/blob/master/api_format.md#relations)
-/// However it seems, that the value of the relation name can also be an array.
-/// Therefor the `serde_json::Value` type is used instead to prevent an invalid length error
-pub type Relations = std::collections::HashMap<String, Value>;
+/// Crossref returns either a single relation or an array for a relation type, both end up in the `Vec`.
+pub type Relations = std::collections::HashMap<RelationType, Vec<Relation>>;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
@@ -34,86 +38,122 @@ pub struct WorkList {
     pub items: Vec<Work>,
     /// deep page through `/works` result sets
     pub next_cursor: Option<String>,
+    /// the items that were skipped while parsing in `ParseMode::Lenient`
+    #[serde(skip)]
+    pub warnings: Vec<ParseWarning>,
+    /// status, version and http headers of the response this list was part of
+    #[serde(skip)]
+    pub meta: ResponseMeta,
 }
 
 impl TryFrom<serde_json::Value> for WorkList {
     type Error = ErrorKind;
 
     fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-      match value {
-        Value::Object(map) => {
-          let facets: HashMap<String, FacetItem> = map
-          .get("facets")
-          .ok_or(ErrorKind::MissingField {
-              msg: "facets".to_string(),
-          })?
-            .as_object()
-            .ok_or(ErrorKind::InvalidTypeName {
-                name: "facets".to_string(),
-            })?
+        WorkList::parse(&value, ParseMode::Strict)
+    }
+}
+
+impl WorkList {
+    /// Parse a `WorkList` from the json `value`.
+    ///
+    /// In `ParseMode::Lenient` items that are no valid `Work` are dropped and recorded in `warnings`.
+    pub fn parse(value: &Value, mode: ParseMode) -> std::result::Result<Self, ErrorKind> {
+        let map = value.as_object().ok_or_else(|| ErrorKind::InvalidMessageType {
+            name: value.to_string(),
+        })?;
+        let values = map
+            .get("items")
+            .and_then(Value::as_array)
+            .ok_or_else(|| ErrorKind::MissingField {
+                msg: "items".to_string(),
+            })?;
+        // everything but the items, which are parsed one by one
+        let mut envelope: Map<String, Value> = map
             .iter()
-            .map(|(k, v)| (k.to_string(), FacetItem::try_from(v.clone()).unwrap()))
+            .filter(|(key, _)| key.as_str() != "items")
+            .map(|(key, value)| (key.clone(), value.clone()))
             .collect();
+        envelope.insert("items".to_string(), Value::Array(Vec::new()));
+        let mut list = WorkList::deserialize(&Value::Object(envelope)).map_err(|e| {
+            ErrorKind::InvalidField {
+                msg: format!("error parsing message as work-list: {}", e),
+            }
+        })?;
+
+        let mut warnings = Warnings::new(mode, None);
+        list.items.reserve(values.len());
+        for (i, value) in values.iter().enumerate() {
+            match Work::parse(value, mode) {
+                Ok(work) => list.items.push(work),
+                Err(e) => {
+                    warnings.doi = value.get("DOI").and_then(|v| v.as_str()).map(str::to_string);
+                    warnings.skip(format!("items[{}]", i), e)?;
+                }
+            }
+        }
+        list.warnings = warnings.warnings;
+        Ok(list)
+    }
 
-  
-
-              
-                
-     
-
-          let total_results = map
-              .get("total-results")
-              .ok_or(ErrorKind::MissingField {
-                  msg: "total-results".to_string(),
-              })?
-                .as_u64()
-                .map(|v| v as usize)
-                .ok_or(ErrorKind::InvalidTypeName {
-                    name: "total-results".to_string(),
-                })?;
-
-
-          let items_per_page = map
-              .get("items-per-page")
-              .and_then(|v| v.as_u64())
-              .map(|v| v as usize);
-
-          let query = map
-              .get("query")
-              .and_then(|v| v.as_object())
-              .map(|v| QueryResponse::try_from(Value::Object(v.clone())).unwrap());
-
-          let items: Vec<Work> = map
-              .get("items")
-                .ok_or(ErrorKind::MissingField {
-                    msg: "items".to_string(),
-                })?
-                .as_array() 
-                .ok_or(ErrorKind::InvalidTypeName {
-                    name: "items".to_string(),
-                })?
-                .iter()
-                .map(|v| Work::try_from(v.clone()).unwrap())
-                .collect();
-
-          let next_cursor = map
-              .get("next-cursor")
-              .and_then(|v| v.as_str())
-              .map(|v| v.to_string());
-
-          Ok(WorkList {
-              facets,
-              total_results,
-              items_per_page,
-              query,
-              items,
-              next_cursor,
-          })
+    /// Parse a `WorkList` from its json text.
+    ///
+    /// Unlike [WorkList::parse] this does not build a `serde_json::Value` of the page, the items are
+    /// deserialized one by one with [Work::from_json].
+    pub fn from_json(json: &str, mode: ParseMode) -> std::result::Result<Self, ErrorKind> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "kebab-case")]
+        struct Page<'a> {
+            #[serde(default)]
+            facets: FacetMap,
+            total_results: usize,
+            items_per_page: Option<usize>,
+            query: Option<QueryResponse>,
+            next_cursor: Option<String>,
+            #[serde(borrow)]
+            items: Vec<&'a RawValue>,
         }
-        _ => Err(ErrorKind::InvalidMessageType {
-            name: value.to_string(),
-        })?,
+
+        #[derive(Deserialize)]
+        struct DoiOnly {
+            #[serde(rename = "DOI")]
+            doi: Option<String>,
+        }
+
+        let page: Page = serde_json::from_str(json).map_err(|e| ErrorKind::InvalidField {
+            msg: format!("error parsing message as work-list: {}", e),
+        })?;
+        let mut warnings = Warnings::new(mode, None);
+        let mut items = Vec::with_capacity(page.items.len());
+        for (i, raw) in page.items.iter().enumerate() {
+            match Work::from_json(raw.get(), mode) {
+                Ok(work) => items.push(work),
+                Err(e) => {
+                    warnings.doi = serde_json::from_str::<DoiOnly>(raw.get())
+                        .ok()
+                        .and_then(|d| d.doi);
+                    warnings.skip(format!("items[{}]", i), e)?;
+                }
+            }
+        }
+
+        Ok(WorkList {
+            facets: page.facets,
+            total_results: page.total_results,
+            items_per_page: page.items_per_page,
+            query: page.query,
+            items,
+            next_cursor: page.next_cursor,
+            warnings: warnings.warnings,
+            meta: ResponseMeta::default(),
+        })
     }
+
+    /// all warnings of this page, the ones of the skipped items and the ones of the parsed `Work` items
+    pub fn all_warnings(&self) -> impl Iterator<Item = &ParseWarning> {
+        self.warnings
+            .iter()
+            .chain(self.items.iter().flat_map(|work| work.warnings.iter()))
     }
 }
 
@@ -145,19 +185,19 @@ pub struct Work {
     /// Currently always `Crossref`
     pub source: Option<String>,
     pub journal_issue: Option<Issue>,
-    /// DOI prefix identifier of the form `http://id.crossref.org/prefix/DOI_PREFIX`
-    pub prefix: Option<String>,
+    /// DOI prefix of the work, crossref serves it in the form `http://id.crossref.org/prefix/DOI_PREFIX`
+    pub prefix: Option<Prefix>,
     /// DOI of the work
     #[serde(rename = "DOI")]
-    pub doi: String,
+    pub doi: Doi,
     /// URL form of the work's DOI
     #[serde(rename = "URL")]
     pub url: Option<String>,
-    /// Member identifier of the form `http://id.crossref.org/member/MEMBER_ID`
-    pub member: String,
+    /// Member identifier, crossref serves it in the form `http://id.crossref.org/member/MEMBER_ID`
+    pub member: MemberId,
     /// Enumeration, one of the type ids from `https://api.crossref.org/v1/types`
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: Type,
     /// the day this work entry was created
     pub created: Date,
     /// Date on which the DOI was first registered
@@ -171,11 +211,14 @@ pub struct Work {
     /// Re-indexing does not imply a metadata change, see `deposited` for the most recent metadata change date
     pub indexed: Date,
     /// Earliest of `published-print` and `published-online`
-    pub issued: Option<PartialDate>,
+    #[serde(default, with = "date_parts::option")]
+    pub issued: Option<CrossrefDate>,
     /// ate on which posted content was made available online
-    pub posted: Option<PartialDate>,
+    #[serde(default, with = "date_parts::option")]
+    pub posted: Option<CrossrefDate>,
     /// Date on which a work was accepted, after being submitted, during a submission process
-    pub accepted: Option<PartialDate>,
+    #[serde(default, with = "date_parts::option")]
+    pub accepted: Option<CrossrefDate>,
     /// Work subtitles, including original language and translated
     pub subtitle: Option<Vec<String>>,
     /// Full titles of the containing work (usually a book or journal)
@@ -193,18 +236,20 @@ pub struct Work {
     /// the number of the corresponding article
     pub article_number: Option<String>,
     /// Date on which the work was published in print
-    pub published_print: Option<PartialDate>,
+    #[serde(default, with = "date_parts::option")]
+    pub published_print: Option<CrossrefDate>,
     /// Date on which the work was published online
-    pub published_online: Option<PartialDate>,
+    #[serde(default, with = "date_parts::option")]
+    pub published_online: Option<CrossrefDate>,
     /// Subject category names, a controlled vocabulary from Sci-Val.
     /// Available for most journal articles
     pub subject: Option<Vec<String>>,
     #[serde(rename = "ISSN")]
-    pub issn: Option<Vec<String>>,
+    pub issn: Option<Vec<Issn>>,
     /// List of ISSNs with ISSN type information
     pub issn_type: Option<Vec<ISSN>>,
     #[serde(rename = "ISBN")]
-    pub isbn: Option<Vec<String>>,
+    pub isbn: Option<Vec<Isbn>>,
     pub archive: Option<Vec<String>>,
     pub license: Option<Vec<License>>,
     pub funder: Option<Vec<FundingBody>>,
@@ -226,485 +271,738 @@ pub struct Work {
     /// Information on domains that support Crossmark for this work
     pub content_domain: Option<ContentDomain>,
     /// Relations to other works
+    #[serde(default, deserialize_with = "deserialize_relations")]
     pub relation: Option<Relations>,
     /// Peer review metadata
-    pub review: Option<Relations>,
+    pub review: Option<Review>,
+    /// the fields that were skipped while parsing in `ParseMode::Lenient`
+    #[serde(skip)]
+    pub warnings: Vec<ParseWarning>,
+    /// fields crossref returned that are not modeled by `Work`
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, Value>,
+    /// the unparsed json of the work, only set if the client is configured to keep it
+    #[serde(skip)]
+    pub raw: Option<Value>,
 }
 
 impl TryFrom<serde_json::Value> for Work {
     type Error = ErrorKind;
 
     fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let publisher = map
-                    .get("publisher")
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "publisher".to_string(),
-                    })?
-                    .as_str()
-                    .ok_or(ErrorKind::InvalidTypeName {
-                        name: "publisher".to_string(),
-                    })?
-                    .to_string();
-
-                let title: Vec<String> = map
-                    .get("title")
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "title".to_string(),
-                    })?
-                    .as_array()
-                    .ok_or(ErrorKind::InvalidTypeName {
-                        name: "title".to_string(),
-                    })?
-                    .iter()
-                    .map(|v| v.as_str().unwrap().to_string())
-                    .collect();
-
-                let original_title: Option<Vec<String>> = map
-                    .get("original-title")
-                    .and_then(|v| v.as_array())
-                    .map(|v| v.iter().map(|v| v.as_str().unwrap().to_string()).collect());
-
-                let language = map
-                    .get("language")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let short_title: Option<Vec<String>> = map
-                    .get("short-title")
-                    .and_then(|v| v.as_array())
-                    .map(|v| v.iter().map(|v| v.as_str().unwrap().to_string()).collect());
-
-                let abstract_: Option<String> = map
-                    .get("abstract")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let references_count = map
-                    .get("references-count")
-                    .and_then(|v| v.as_i64())
-                    .map(|v| v as i32);
-
-                let is_referenced_by_count = map
-                    .get("is-referenced-by-count")
-                    .and_then(|v| v.as_i64())
-                    .map(|v| v as i32);
-
-                let source = map
-                    .get("source")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let journal_issue = map
-                    .get("journal-issue")
-                    .and_then(|v| v.as_object())
-                    .map(|v| Issue::try_from(Value::Object(v.clone())).unwrap());
-
-                let prefix = map
-                    .get("prefix")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let doi = map
-                    .get("DOI")
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "DOI".to_string(),
-                    })?
-                    .as_str()
-                    .ok_or(ErrorKind::InvalidTypeName {
-                        name: "DOI".to_string(),
-                    })?
-                    .to_string();
-
-                let url = map
-                    .get("URL")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let member = map
-                    .get("member")
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "member".to_string(),
-                    })?
-                    .as_str()
-                    .ok_or(ErrorKind::InvalidTypeName {
-                        name: "member".to_string(),
-                    })?
-                    .to_string();
-
-                let type_ = map
-                    .get("type")
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "type".to_string(),
-                    })?
-                    .as_str()
-                    .ok_or(ErrorKind::InvalidTypeName {
-                        name: "type".to_string(),
-                    })?
-                    .to_string();
-
-                let created = map
-                    .get("created")
-                    .ok_or(
-                        ErrorKind::MissingField {
-                            msg: "created".to_string(),
-                        },
-                    )?
-                    .as_object()
-                    .ok_or(ErrorKind::InvalidTypeName {
-                        name: "created".to_string(),
-                    })
-                    .map(|v| Date::try_from(Value::Object(v.clone())).unwrap())?;
-
-                let date = map
-                    .get("date")
-                    .and_then(|v| v.as_object())
-                    .map(|v| Date::try_from(Value::Object(v.clone())).unwrap());
-
-                let deposited = map
-                    .get("deposited")
-                    .and_then(|v| v.as_object())
-                    .map(|v| Date::try_from(Value::Object(v.clone())).unwrap());
-
-                let score = map.get("score").and_then(|v| v.as_f64()).map(|v| v as f32);
-
-                let indexed = map
-                    .get("indexed")
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "indexed".to_string(),
-                    })?
-                    .as_object()
-                    .ok_or(ErrorKind::InvalidTypeName {
-                        name: "indexed".to_string(),
-                    })
-                    .map(|v| Date::try_from(Value::Object(v.clone())).unwrap())?;
-
-                let issued = map
-                    .get("issued")
-                    .and_then(|v| v.as_object())
-                    .map(|v| PartialDate::try_from(Value::Object(v.clone())).unwrap());
-
-                let posted = map
-                    .get("posted")
-                    .and_then(|v| v.as_object())
-                    .map(|v| PartialDate::try_from(Value::Object(v.clone())).unwrap());
-
-                let accepted = map
-                    .get("accepted")
-                    .and_then(|v| v.as_object())
-                    .map(|v| PartialDate::try_from(Value::Object(v.clone())).unwrap());
-
-                let subtitle = map
-                    .get("subtitle")
-                    .and_then(|v| v.as_array())
-                    .map(|v| v.iter().map(|v| v.as_str().unwrap().to_string()).collect());
-
-                let container_title = map
-                    .get("container-title")
-                    .and_then(|v| v.as_array())
-                    .map(|v| v.iter().map(|v| v.as_str().unwrap().to_string()).collect());
-
-                let short_container_title = map
-                    .get("short-container-title")
-                    .and_then(|v| v.as_array())
-                    .map(|v| v.iter().map(|v| v.as_str().unwrap().to_string()).collect());
-
-                let group_title = map
-                    .get("group-title")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let issue = map
-                    .get("issue")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let volume = map
-                    .get("volume")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let page = map
-                    .get("page")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let article_number = map
-                    .get("article-number")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let published_print = map
-                    .get("published-print")
-                    .and_then(|v| v.as_object())
-                    .map(|v| PartialDate::try_from(Value::Object(v.clone())).unwrap());
-
-                let published_online = map
-                    .get("published-online")
-                    .and_then(|v| v.as_object())
-                    .map(|v| PartialDate::try_from(Value::Object(v.clone())).unwrap());
-
-                let subject = map
-                    .get("subject")
-                    .and_then(|v| v.as_array())
-                    .map(|v| v.iter().map(|v| v.as_str().unwrap().to_string()).collect());
-
-                let issn = map
-                    .get("ISSN")
-                    .and_then(|v| v.as_array())
-                    .map(|v| v.iter().map(|v| v.as_str().unwrap().to_string()).collect());
-
-                let issn_type = map.get("issn-type").and_then(|v| v.as_array()).map(|v| {
-                    v.iter()
-                        .map(|v| ISSN::try_from(v.clone()).unwrap())
-                        .collect()
-                });
-
-                let isbn = map
-                    .get("ISBN")
-                    .and_then(|v| v.as_array())
-                    .map(|v| v.iter().map(|v| v.as_str().unwrap().to_string()).collect());
-
-                let archive = map
-                    .get("archive")
-                    .and_then(|v| v.as_array())
-                    .map(|v| v.iter().map(|v| v.as_str().unwrap().to_string()).collect());
-
-                let license = map.get("license").and_then(|v| v.as_array()).map(|v| {
-                    v.iter()
-                        .map(|v| License::try_from(v.clone()).unwrap())
-                        .collect()
-                });
-
-                let funder = map.get("funder").and_then(|v| v.as_array()).map(|v| {
-                    v.iter()
-                        .map(|v| FundingBody::try_from(v.clone()).unwrap())
-                        .collect()
-                });
-
-                let assertion = map.get("assertion").and_then(|v| v.as_array()).map(|v| {
-                    v.iter()
-                        .map(|v| Assertion::try_from(v.clone()).unwrap())
-                        .collect()
-                });
-
-                let author = map.get("author").and_then(|v| v.as_array()).map(|v| {
-                    v.iter()
-                        .map(|v| Contributor::try_from(v.clone()).unwrap())
-                        .collect()
-                });
-
-                let editor = map.get("editor").and_then(|v| v.as_array()).map(|v| {
-                    v.iter()
-                        .map(|v| Contributor::try_from(v.clone()).unwrap())
-                        .collect()
-                });
-
-                let chair = map.get("chair").and_then(|v| v.as_array()).map(|v| {
-                    v.iter()
-                        .map(|v| Contributor::try_from(v.clone()).unwrap())
-                        .collect()
-                });
-
-                let translator = map.get("translator").and_then(|v| v.as_array()).map(|v| {
-                    v.iter()
-                        .map(|v| Contributor::try_from(v.clone()).unwrap())
-                        .collect()
-                });
-
-                let update_to = map.get("update-to").and_then(|v| v.as_array()).map(|v| {
-                    v.iter()
-                        .map(|v| Update::try_from(v.clone()).unwrap())
-                        .collect()
-                });
-
-                let update_policy = map
-                    .get("update-policy")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let link = map.get("link").and_then(|v| v.as_array()).map(|v| {
-                    v.iter()
-                        .map(|v| ResourceLink::try_from(v.clone()).unwrap())
-                        .collect()
-                });
-
-                let clinical_trial_number = map
-                    .get("clinical-trial-number")
-                    .and_then(|v| v.as_array())
-                    .map(|v| {
-                        v.iter()
-                            .map(|v| ClinicalTrialNumber::try_from(v.clone()).unwrap())
-                            .collect()
-                    });
-
-                let alternative_id = map
-                    .get("alternative-id")
-                    .and_then(|v| v.as_array())
-                    .map(|v| v.iter().map(|v| v.as_str().unwrap().to_string()).collect());
-
-                let reference = map.get("reference").and_then(|v| v.as_array()).map(|v| {
-                    v.iter()
-                        .map(|v| Reference::try_from(v.clone()).unwrap())
-                        .collect()
-                });
-
-                let content_domain = map
-                    .get("content-domain")
-                    .and_then(|v| v.as_object())
-                    .map(|v| ContentDomain::try_from(Value::Object(v.clone())).unwrap());
-
-                let relation = map.get("relation").and_then(|v| v.as_object()).map(|v| {
-                    let mut map = HashMap::new();
-                    for (k, v) in v.iter() {
-                        map.insert(k.to_string(), v.clone());
-                    }
-                    map
-                });
-
-                let review = map.get("review").and_then(|v| v.as_object()).map(|v| {
-                    let mut map = HashMap::new();
-                    for (k, v) in v.iter() {
-                        map.insert(k.to_string(), v.clone());
-                    }
-                    map
-                });
-
-                Ok(Work {
-                    publisher,
-                    title,
-                    original_title,
-                    language,
-                    short_title,
-                    abstract_,
-                    references_count,
-                    is_referenced_by_count,
-                    source,
-                    journal_issue,
-                    prefix,
-                    doi,
-                    url,
-                    member,
-                    type_,
-                    created,
-                    date,
-                    deposited,
-                    score,
-                    indexed,
-                    issued,
-                    posted,
-                    accepted,
-                    subtitle,
-                    container_title,
-                    short_container_title,
-                    group_title,
-                    issue,
-                    volume,
-                    page,
-                    article_number,
-                    published_print,
-                    published_online,
-                    subject,
-                    issn,
-                    issn_type,
-                    isbn,
-                    archive,
-                    license,
-                    funder,
-                    assertion,
-                    author,
-                    editor,
-                    chair,
-                    translator,
-                    update_to,
-                    update_policy,
-                    link,
-                    clinical_trial_number,
-                    alternative_id,
-                    reference,
-                    content_domain,
-                    relation,
-                    review,
-                })
+        Work::parse(&value, ParseMode::Strict)
+    }
+}
+
+impl Work {
+    /// all json fields that are modeled by `Work`
+    pub const FIELDS: &'static [&'static str] = &[
+        "publisher",
+        "title",
+        "original-title",
+        "language",
+        "short-title",
+        "abstract",
+        "references-count",
+        "is-referenced-by-count",
+        "source",
+        "journal-issue",
+        "prefix",
+        "DOI",
+        "URL",
+        "member",
+        "type",
+        "created",
+        "date",
+        "deposited",
+        "score",
+        "indexed",
+        "issued",
+        "posted",
+        "accepted",
+        "subtitle",
+        "container-title",
+        "short-container-title",
+        "group-title",
+        "issue",
+        "volume",
+        "page",
+        "article-number",
+        "published-print",
+        "published-online",
+        "subject",
+        "ISSN",
+        "issn-type",
+        "ISBN",
+        "archive",
+        "license",
+        "funder",
+        "assertion",
+        "author",
+        "editor",
+        "chair",
+        "translator",
+        "update-to",
+        "update-policy",
+        "link",
+        "clinical-trial-number",
+        "alternative-id",
+        "reference",
+        "content-domain",
+        "relation",
+        "review",
+    ];
+
+    /// Parse a `Work` from its json text.
+    ///
+    /// Well-formed works are deserialized directly without building a `serde_json::Value`,
+    /// only works that fail to deserialize are parsed again with [Work::parse] to apply `mode`.
+    pub fn from_json(json: &str, mode: ParseMode) -> std::result::Result<Self, ErrorKind> {
+        match serde_json::from_str(json) {
+            Ok(work) => Ok(work),
+            Err(_) => {
+                let value: Value =
+                    serde_json::from_str(json).map_err(|e| ErrorKind::InvalidField {
+                        msg: format!("error parsing message as work: {}", e),
+                    })?;
+                Work::parse(&value, mode)
             }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
         }
     }
+
+    /// Parse a `Work` from the json `value`.
+    ///
+    /// In `ParseMode::Lenient` malformed optional fields become `None`, malformed elements of
+    /// lists are dropped and both are recorded in `warnings`.
+    /// Missing or malformed required fields fail in both modes.
+    pub fn parse(value: &Value, mode: ParseMode) -> std::result::Result<Self, ErrorKind> {
+        let map = value.as_object().ok_or_else(|| ErrorKind::InvalidMessageType {
+            name: value.to_string(),
+        })?;
+        let doi = map.get("DOI").and_then(|v| v.as_str()).map(str::to_string);
+        let mut warnings = Warnings::new(mode, doi);
+        let mut map = map.clone();
+        for key in ["author", "editor", "chair", "translator"] {
+            drop_malformed_orcids(&mut map, key, &mut warnings);
+        }
+        let mut dropped = Vec::new();
+        if mode == ParseMode::Lenient {
+            dropped = drop_malformed_fields(&mut map, &mut warnings);
+        }
+
+        let mut work = Work::deserialize(&Value::Object(map)).map_err(|e| {
+            // a required field that was dropped is reported as malformed, not as missing
+            let e = e.to_string();
+            match dropped.iter().find(|w| e.contains(&format!("missing field `{}`", w.path))) {
+                Some(w) => ErrorKind::InvalidField {
+                    msg: format!("{}: {}", w.path, w.reason),
+                },
+                None => ErrorKind::InvalidField {
+                    msg: format!("error parsing message as work: {}", e),
+                },
+            }
+        })?;
+        work.warnings = warnings.warnings;
+        Ok(work)
+    }
 }
 
-// AuthorYear (e.g. Smith2019) or Author&AuthorYear (e.g. Smith&Jones2019) or AuthorEtAlYear (e.g. SmithEtAl2019)
-fn to_citekey(authors: Vec<Contributor>, year: Option<Date>) -> Option<String> {
-    if let Some(authors) = {
-        if authors.len() == 0 {
-            return None;
-        } else if authors.len() == 1 {
-            authors[0].family.clone()
-        } else if authors.len() == 2 {
-            Some(format!(
-                "{}&{}",
-                authors[0].family.clone().unwrap_or("".to_string()),
-                authors[1].family.clone().unwrap_or("".to_string())
-            ))
-        } else {
-            Some(format!(
-                "{}EtAl",
-                authors[0].family.clone().unwrap_or("".to_string())
-            ))
+impl Work {
+    /// The date the work was published, following crossref's logic for `published`:
+    /// the earliest of `published-print` and `published-online`, falling back to `issued`.
+    /// If one of them lies within the period of the other, the more precise one is used.
+    pub fn publication_date(&self) -> Option<CrossrefDate> {
+        match (self.published_print, self.published_online) {
+            // a less precise date of the same period, like `2019` for `2019-03-05`, adds nothing
+            (Some(print), Some(online)) if print.contains(&online) => Some(online),
+            (Some(print), Some(online)) if online.contains(&print) => Some(print),
+            (Some(print), Some(online)) => {
+                Some(std::cmp::min_by_key(print, online, CrossrefDate::first_day))
+            }
+            (None, None) => self.issued,
+            (print, online) => print.or(online),
         }
-    } {
-        let Some(date) = year
-            .and_then(|d| d.date_parts.as_date())
-            .and_then(|d| Some(d.year()))
-        else {
-            return None;
-        };
+    }
+
+    /// the first title without markup, followed by the first subtitle separated by `: `
+    pub fn full_title(&self) -> Option<String> {
+        let title = crate::xml::strip_tags(self.title.first()?);
+        Some(match self.subtitle.as_ref().and_then(|s| s.first()) {
+            Some(subtitle) => format!("{}: {}", title, crate::xml::strip_tags(subtitle)),
+            None => title,
+        })
+    }
+
+    /// the first and the last page of `page`, or the article number if the work has no pages
+    pub fn page_range(&self) -> Option<(&str, Option<&str>)> {
+        self.page
+            .as_deref()
+            .or(self.article_number.as_deref())
+            .map(split_page_range)
+    }
+
+    /// the JATS `abstract` of the work, parsed into sections and paragraphs
+    pub fn parsed_abstract(&self) -> Option<Abstract> {
+        self.abstract_.as_deref().map(Abstract::parse)
+    }
+
+    /// all relations of type `ty` this work asserts or is the object of
+    pub fn relations_of(&self, ty: &RelationType) -> &[Relation] {
+        self.relation
+            .as_ref()
+            .and_then(|r| r.get(ty))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
 
-        Some(format!("{}{}", authors, date))
-    } else {
-        return None;
+    /// the works this work is a preprint of (`is-preprint-of`)
+    pub fn preprint_of(&self) -> &[Relation] {
+        self.relations_of(&RelationType::IsPreprintOf)
     }
+
+    /// the preprints of this work (`has-preprint`)
+    pub fn preprints(&self) -> &[Relation] {
+        self.relations_of(&RelationType::HasPreprint)
+    }
+
+    /// the works this work reviews (`is-review-of`)
+    pub fn reviews_of(&self) -> &[Relation] {
+        self.relations_of(&RelationType::IsReviewOf)
+    }
+
+    /// the reviews of this work (`has-review`)
+    pub fn reviews(&self) -> &[Relation] {
+        self.relations_of(&RelationType::HasReview)
+    }
+}
+
+/// `10-20` becomes `("10", Some("20"))`, en dashes are read like `-`
+pub(crate) fn split_page_range(pages: &str) -> (&str, Option<&str>) {
+    let pages = pages.trim();
+    match pages.split_once(['-', '–']) {
+        Some((first, last)) if !last.trim().is_empty() => (first.trim(), Some(last.trim())),
+        _ => (pages.trim_end_matches(['-', '–']), None),
+    }
+}
+
+/// Decides how malformed fields are handled while parsing a `Work`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    /// fail on the first malformed field
+    Strict,
+    /// skip malformed optional fields and record a `ParseWarning` for each of them
+    #[default]
+    Lenient,
 }
 
-/// Helper struct to represent dates in the cross ref api as nested arrays of numbers
+/// A malformed field that was skipped while parsing in `ParseMode::Lenient`
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
-pub struct DateParts(pub Vec<Vec<Option<u32>>>);
-
-impl DateParts {
-    /// converts the nested array of numbers into the corresponding [DateField]
-    /// standalone years are allowed.
-    /// if an array is empty, [None] will be returned
-    pub fn as_date(&self) -> Option<DateField> {
-        /// converts an array of numbers into chrono [NaiveDate] if it contains at least a single value
-        fn naive(v: &[Option<u32>]) -> Option<NaiveDate> {
-            match v.len() {
-                0 => None,
-                1 => NaiveDate::from_ymd_opt(v[0]? as i32, 0, 0),
-                2 => NaiveDate::from_ymd_opt(v[0]? as i32, v[1]?, 0),
-                3 => NaiveDate::from_ymd_opt(v[0]? as i32, v[1]?, v[2]?),
-                _ => None,
-            }
+pub struct ParseWarning {
+    /// the DOI of the affected record, if it could be determined
+    pub doi: Option<String>,
+    /// the json path of the skipped value, like `author[2]` or `items[4]`
+    pub path: String,
+    /// why the value was skipped
+    pub reason: String,
+}
+
+/// Collects the warnings of the values skipped while parsing
+struct Warnings {
+    mode: ParseMode,
+    doi: Option<String>,
+    warnings: Vec<ParseWarning>,
+}
+
+impl Warnings {
+    fn new(mode: ParseMode, doi: Option<String>) -> Self {
+        Warnings {
+            mode,
+            doi,
+            warnings: Vec::new(),
         }
+    }
 
-        match self.0.len() {
-            0 => None,
-            1 => Some(DateField::Single(naive(&self.0[0])?)),
-            2 => Some(DateField::Range {
-                from: naive(&self.0[0])?,
-                to: naive(&self.0[1])?,
+    /// records a warning in both modes
+    fn warn(&mut self, path: String, reason: impl std::fmt::Display) {
+        self.warnings.push(ParseWarning {
+            doi: self.doi.clone(),
+            path,
+            reason: reason.to_string(),
+        });
+    }
+
+    /// fails in `ParseMode::Strict`, records a warning in `ParseMode::Lenient`
+    fn skip(&mut self, path: String, e: ErrorKind) -> std::result::Result<(), ErrorKind> {
+        match self.mode {
+            ParseMode::Strict => Err(ErrorKind::InvalidField {
+                msg: format!("{}: {}", path, e),
             }),
-            _ => Some(DateField::Multi(
-                self.0
-                    .iter()
-                    .map(|x| naive(x))
-                    .collect::<Option<Vec<_>>>()?,
-            )),
+            ParseMode::Lenient => {
+                self.warn(path, e);
+                Ok(())
+            }
         }
     }
 }
 
+/// a malformed `ORCID` only drops the ORCID of the contributor and is recorded as warning in both
+/// modes
+fn drop_malformed_orcids(map: &mut Map<String, Value>, key: &str, warnings: &mut Warnings) {
+    let Some(Value::Array(contributors)) = map.get_mut(key) else {
+        return;
+    };
+    for (i, contributor) in contributors.iter_mut().enumerate() {
+        let Some(contributor) = contributor.as_object_mut() else {
+            continue;
+        };
+        if let Some(Err(e)) = contributor.get("ORCID").map(Orcid::deserialize) {
+            contributor.remove("ORCID");
+            warnings.warn(format!("{}[{}].ORCID", key, i), e);
+        }
+    }
+}
+
+/// the error of deserializing `value` as the field `key` of a work, the fields are checked against
+/// `PartialWork` because all of its fields are optional
+fn field_error(key: &str, value: &Value) -> Option<serde_json::Error> {
+    let field = Map::from_iter([(key.to_string(), value.clone())]);
+    PartialWork::deserialize(&Value::Object(field)).err()
+}
+
+/// removes the malformed fields of `map` and the malformed elements of its lists with a warning
+/// for each of them, returns the warnings of the removed fields
+fn drop_malformed_fields(map: &mut Map<String, Value>, warnings: &mut Warnings) -> Vec<ParseWarning> {
+    let mut dropped = Vec::new();
+    let keys: Vec<String> = map.keys().cloned().collect();
+    for key in keys {
+        let Some(e) = field_error(&key, &map[&key]) else {
+            continue;
+        };
+        // a list only drops its malformed elements
+        if let Value::Array(values) = &map[&key] {
+            let (valid, malformed): (Vec<_>, Vec<_>) = values
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (i, v.clone(), field_error(&key, &Value::Array(vec![v.clone()]))))
+                .partition(|(_, _, e)| e.is_none());
+            let valid = Value::Array(valid.into_iter().map(|(_, v, _)| v).collect());
+            if !malformed.is_empty() && field_error(&key, &valid).is_none() {
+                for (i, _, e) in malformed {
+                    warnings.warn(format!("{}[{}]", key, i), e.expect("partitioned by error"));
+                }
+                map.insert(key, valid);
+                continue;
+            }
+        }
+        map.remove(&key);
+        warnings.warn(key, e);
+        dropped.extend(warnings.warnings.last().cloned());
+    }
+    dropped
+}
+
+/// implements `TryFrom<Value>` of the nested types of a `Work` through their derived `Deserialize`
+macro_rules! impl_try_from_value {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl TryFrom<serde_json::Value> for $ty {
+                type Error = ErrorKind;
+
+                fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
+                    serde_json::from_value(value).map_err(|e| ErrorKind::InvalidField {
+                        msg: format!("error parsing {}: {}", stringify!($ty), e),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_value!(
+    FundingBody,
+    ClinicalTrialNumber,
+    Contributor,
+    Affiliation,
+    Date,
+    Update,
+    Assertion,
+    Issue,
+    AssertionGroup,
+    License,
+    ResourceLink,
+    Reference,
+    ISSN,
+    ContentDomain,
+    Relation,
+);
+
+/// accepts a single relation or a list of relations for each type
+fn deserialize_relations<'de, D>(deserializer: D) -> std::result::Result<Option<Relations>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(Relation),
+        Many(Vec<Relation>),
+    }
+
+    let relations: Option<HashMap<RelationType, OneOrMany>> = Option::deserialize(deserializer)?;
+    Ok(relations.map(|relations| {
+        relations
+            .into_iter()
+            .map(|(ty, rel)| match rel {
+                OneOrMany::One(rel) => (ty, vec![rel]),
+                OneOrMany::Many(rels) => (ty, rels),
+            })
+            .collect()
+    }))
+}
+
+/// crossref sends the review counters either as string or as number
+fn deserialize_counter<'de, D>(deserializer: D) -> std::result::Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Counter {
+        Number(u64),
+        Text(String),
+    }
+
+    Ok(Option::<Counter>::deserialize(deserializer)?.map(|c| match c {
+        Counter::Number(n) => n.to_string(),
+        Counter::Text(s) => s,
+    }))
+}
+
+/// A page of `PartialWork` items, returned for queries that project the `Work` fields with `select`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartialWorkList {
+    /// if facets where part in the request they are also included in the response
+    #[serde(default)]
+    pub facets: FacetMap,
+    /// the number of items that match the response
+    pub total_results: usize,
+    /// crossref responses for large number of items are divided in pages, number of elements to expect in `items`
+    pub items_per_page: Option<usize>,
+    /// if a query was set in the request, this will also be part in the response
+    pub query: Option<QueryResponse>,
+    /// all work items that are returned
+    pub items: Vec<PartialWork>,
+    /// deep page through `/works` result sets
+    pub next_cursor: Option<String>,
+    /// status, version and http headers of the response this list was part of
+    #[serde(skip)]
+    pub meta: ResponseMeta,
+}
+
+/// A `Work` where every field is optional.
+///
+/// Crossref only returns the elements that were requested with `select`,
+/// so fields that are mandatory for a complete `Work` may be missing.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case", default)]
+#[allow(missing_docs)]
+pub struct PartialWork {
+    pub publisher: Option<String>,
+    pub title: Option<Vec<String>>,
+    pub original_title: Option<Vec<String>>,
+    pub language: Option<String>,
+    pub short_title: Option<Vec<String>>,
+    #[serde(rename = "abstract")]
+    pub abstract_: Option<String>,
+    pub references_count: Option<i32>,
+    pub is_referenced_by_count: Option<i32>,
+    pub source: Option<String>,
+    pub journal_issue: Option<Issue>,
+    pub prefix: Option<Prefix>,
+    #[serde(rename = "DOI")]
+    pub doi: Option<Doi>,
+    #[serde(rename = "URL")]
+    pub url: Option<String>,
+    pub member: Option<MemberId>,
+    #[serde(rename = "type")]
+    pub type_: Option<Type>,
+    pub created: Option<Date>,
+    pub date: Option<Date>,
+    pub deposited: Option<Date>,
+    pub score: Option<f32>,
+    pub indexed: Option<Date>,
+    #[serde(default, with = "date_parts::option")]
+    pub issued: Option<CrossrefDate>,
+    #[serde(default, with = "date_parts::option")]
+    pub posted: Option<CrossrefDate>,
+    #[serde(default, with = "date_parts::option")]
+    pub accepted: Option<CrossrefDate>,
+    pub subtitle: Option<Vec<String>>,
+    pub container_title: Option<Vec<String>>,
+    pub short_container_title: Option<Vec<String>>,
+    pub group_title: Option<String>,
+    pub issue: Option<String>,
+    pub volume: Option<String>,
+    pub page: Option<String>,
+    pub article_number: Option<String>,
+    #[serde(default, with = "date_parts::option")]
+    pub published_print: Option<CrossrefDate>,
+    #[serde(default, with = "date_parts::option")]
+    pub published_online: Option<CrossrefDate>,
+    pub subject: Option<Vec<String>>,
+    #[serde(rename = "ISSN")]
+    pub issn: Option<Vec<Issn>>,
+    pub issn_type: Option<Vec<ISSN>>,
+    #[serde(rename = "ISBN")]
+    pub isbn: Option<Vec<Isbn>>,
+    pub archive: Option<Vec<String>>,
+    pub license: Option<Vec<License>>,
+    pub funder: Option<Vec<FundingBody>>,
+    pub assertion: Option<Vec<Assertion>>,
+    pub author: Option<Vec<Contributor>>,
+    pub editor: Option<Vec<Contributor>>,
+    pub chair: Option<Vec<Contributor>>,
+    pub translator: Option<Vec<Contributor>>,
+    pub update_to: Option<Vec<Update>>,
+    pub update_policy: Option<String>,
+    pub link: Option<Vec<ResourceLink>>,
+    pub clinical_trial_number: Option<Vec<ClinicalTrialNumber>>,
+    pub alternative_id: Option<Vec<String>>,
+    pub reference: Option<Vec<Reference>>,
+    pub content_domain: Option<ContentDomain>,
+    #[serde(default, deserialize_with = "deserialize_relations")]
+    pub relation: Option<Relations>,
+    pub review: Option<Review>,
+}
+
+/// (De)serializes a `CrossrefDate` in the `date-parts` shape of the crossref api, a nested array
+/// like `[[2019, 5, 1]]` of which only the first element is used
+pub(crate) mod date_parts {
+    use super::CrossrefDate;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(date: &CrossrefDate, serializer: S) -> Result<S::Ok, S::Error> {
+        [date.to_parts()].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<CrossrefDate, D::Error> {
+        parse(Vec::deserialize(deserializer)?)?.ok_or_else(|| D::Error::custom("missing date"))
+    }
+
+    /// the date of the first element, [None] for an empty `[[null]]` crossref sends for unknown dates
+    fn parse<E: Error>(parts: Vec<Vec<Option<u32>>>) -> Result<Option<CrossrefDate>, E> {
+        match parts.first().map(Vec::as_slice) {
+            None | Some([]) | Some([None]) => Ok(None),
+            Some(first) => CrossrefDate::from_parts(first)
+                .map(Some)
+                .ok_or_else(|| E::custom(format!("invalid date-parts `{:?}`", first))),
+        }
+    }
+
+    #[derive(Deserialize, Serialize)]
+    struct Object<T> {
+        #[serde(rename = "date-parts")]
+        date_parts: T,
+    }
+
+    /// a `CrossrefDate` in the `{"date-parts": [[2019, 5, 1]]}` object
+    pub mod object {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(date: &CrossrefDate, serializer: S) -> Result<S::Ok, S::Error> {
+            Object { date_parts: [date.to_parts()] }.serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<CrossrefDate, D::Error> {
+            let object = Object::<Vec<Vec<Option<u32>>>>::deserialize(deserializer)?;
+            parse(object.date_parts)?.ok_or_else(|| D::Error::custom("missing date"))
+        }
+    }
+
+    /// an optional `CrossrefDate` in the `{"date-parts": [[2019, 5, 1]]}` object, unknown dates
+    /// like `{"date-parts": [[null]]}` are `None`
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(
+            date: &Option<CrossrefDate>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            date.map(|date| Object { date_parts: [date.to_parts()] })
+                .serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<CrossrefDate>, D::Error> {
+            match Option::<Object<Vec<Vec<Option<u32>>>>>::deserialize(deserializer)? {
+                Some(object) => parse(object.date_parts),
+                None => Ok(None),
+            }
+        }
+    }
+}
+
+/// How precise a `CrossrefDate` is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DatePrecision {
+    /// only the year is known
+    Year,
+    /// year and month are known
+    Month,
+    /// the full date is known
+    Day,
+}
+
+/// A date from the crossref api that is precise to the year, month or day.
+///
+/// Less precise dates order before more precise dates of the same period,
+/// so `2019 < 2019-05 < 2019-05-01 < 2019-06`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CrossrefDate {
+    year: i32,
+    month: Option<u32>,
+    day: Option<u32>,
+}
+
+impl CrossrefDate {
+    /// create a new date, [None] if the date does not exist or a day is given without month
+    pub fn new(year: i32, month: Option<u32>, day: Option<u32>) -> Option<Self> {
+        match (month, day) {
+            (None, Some(_)) => return None,
+            (month, day) => {
+                NaiveDate::from_ymd_opt(year, month.unwrap_or(1), day.unwrap_or(1))?;
+            }
+        }
+        Some(CrossrefDate { year, month, day })
+    }
+
+    /// create a date from a single `date-parts` element like `[2019, 5]`
+    pub fn from_parts(parts: &[Option<u32>]) -> Option<Self> {
+        let year = |year: &u32| i32::try_from(*year).ok();
+        match parts {
+            [Some(y)] => CrossrefDate::new(year(y)?, None, None),
+            [Some(y), Some(month)] => CrossrefDate::new(year(y)?, Some(*month), None),
+            [Some(y), Some(month), Some(day)] => CrossrefDate::new(year(y)?, Some(*month), Some(*day)),
+            _ => None,
+        }
+    }
+
+    /// the year of the date
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// the month of the date, if known
+    pub fn month(&self) -> Option<u32> {
+        self.month
+    }
+
+    /// the day of the month, if known
+    pub fn day(&self) -> Option<u32> {
+        self.day
+    }
+
+    /// how precise the date is
+    pub fn precision(&self) -> DatePrecision {
+        match (self.month, self.day) {
+            (Some(_), Some(_)) => DatePrecision::Day,
+            (Some(_), None) => DatePrecision::Month,
+            _ => DatePrecision::Year,
+        }
+    }
+
+    /// the date as `NaiveDate`, only if it is precise to the day
+    pub fn to_naive_date(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year, self.month?, self.day?)
+    }
+
+    /// the first day of the period the date covers
+    pub fn first_day(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year, self.month.unwrap_or(1), self.day.unwrap_or(1))
+            .expect("validated on construction")
+    }
+
+    /// the last day of the period the date covers
+    pub fn last_day(&self) -> NaiveDate {
+        match (self.month, self.day) {
+            (Some(month), Some(day)) => NaiveDate::from_ymd_opt(self.year, month, day),
+            (Some(12), None) | (None, _) => NaiveDate::from_ymd_opt(self.year, 12, 31),
+            (Some(month), None) => NaiveDate::from_ymd_opt(self.year, month + 1, 1)
+                .and_then(|d| d.pred_opt()),
+        }
+        .expect("validated on construction")
+    }
+
+    /// whether `other` lies within the period this date covers, like `2019-05-01` in `2019`
+    pub fn contains(&self, other: &CrossrefDate) -> bool {
+        self.first_day() <= other.first_day() && other.last_day() <= self.last_day()
+    }
+
+    /// the `date-parts` element of the date
+    pub fn to_parts(&self) -> Vec<Option<u32>> {
+        std::iter::once(Some(self.year as u32))
+            .chain(self.month.map(Some))
+            .chain(self.day.map(Some))
+            .collect()
+    }
+}
+
+impl From<NaiveDate> for CrossrefDate {
+    fn from(date: NaiveDate) -> Self {
+        CrossrefDate {
+            year: date.year(),
+            month: Some(date.month()),
+            day: Some(date.day()),
+        }
+    }
+}
+
+/// formats the date as ISO-8601 partial date, e.g. `2019`, `2019-05` or `2019-05-01`
+impl std::fmt::Display for CrossrefDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for CrossrefDate {
+    type Err = ErrorKind;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || ErrorKind::InvalidField {
+            msg: format!("`{}` is not an ISO-8601 date", s),
+        };
+        let mut parts = s.splitn(3, '-').map(|p| p.parse::<u32>().map_err(|_| invalid()));
+        let year = parts.next().ok_or_else(invalid)??;
+        let month = parts.next().transpose()?;
+        let day = parts.next().transpose()?;
+        let year = i32::try_from(year).map_err(|_| invalid())?;
+        CrossrefDate::new(year, month, day).ok_or_else(invalid)
+    }
+}
+
+impl Serialize for CrossrefDate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CrossrefDate {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = <std::borrow::Cow<str>>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[allow(missing_docs)]
 pub struct FundingBody {
@@ -712,58 +1010,12 @@ pub struct FundingBody {
     pub name: String,
     /// Optional [Open Funder Registry](http://www.crossref.org/fundingdata/registry.html) DOI uniquely identifing the funding body
     #[serde(rename = "DOI")]
-    pub doi: Option<String>,
+    pub doi: Option<Doi>,
     /// Award number(s) for awards given by the funding body
     pub award: Option<Vec<String>>,
     /// Either `crossref` or `publisher`
     #[serde(rename = "doi-asserted-by")]
-    pub doi_asserted_by: Option<String>,
-}
-
-impl TryFrom<serde_json::Value> for FundingBody {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let name = map
-                    .get("name")
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "name".to_string(),
-                    })?
-                    .as_str()
-                    .ok_or(ErrorKind::InvalidTypeName {
-                        name: "name".to_string(),
-                    })?
-                    .to_string();
-
-                let doi = map
-                    .get("DOI")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let award = map
-                    .get("award")
-                    .and_then(|v| v.as_array())
-                    .map(|v| v.iter().map(|v| v.as_str().unwrap().to_string()).collect());
-
-                let doi_asserted_by = map
-                    .get("doi-asserted-by")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                Ok(FundingBody {
-                    name,
-                    doi,
-                    award,
-                    doi_asserted_by,
-                })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
-        }
-    }
+    pub doi_asserted_by: Option<DoiAssertedBy>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
@@ -779,49 +1031,12 @@ pub struct ClinicalTrialNumber {
     pub type_: Option<String>,
 }
 
-impl TryFrom<serde_json::Value> for ClinicalTrialNumber {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let clinical_trial_number = map
-                    .get("clinical-trial-number")
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "clinical-trial-number".to_string(),
-                    })?
-                    .as_str()
-                    .ok_or(ErrorKind::InvalidTypeName {
-                        name: "clinical-trial-number".to_string(),
-                    })?
-                    .to_string();
-
-                let registry = map
-                    .get("registry")
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "registry".to_string(),
-                    })?
-                    .as_str()
-                    .ok_or(ErrorKind::InvalidTypeName {
-                        name: "registry".to_string(),
-                    })?
-                    .to_string();
-
-                let type_ = map
-                    .get("type")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                Ok(ClinicalTrialNumber {
-                    clinical_trial_number,
-                    registry,
-                    type_,
-                })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
-        }
+string_enum! {
+    /// The position of a `Contributor` in the list of contributors
+    #[allow(missing_docs)]
+    pub enum ContributorSequence {
+        First => "first",
+        Additional => "additional",
     }
 }
 
@@ -833,93 +1048,24 @@ pub struct Contributor {
     pub family: Option<String>,
     pub given: Option<String>,
     pub name: Option<String>,
-    /// URL-form of an [ORCID](http://orcid.org) identifier
+    /// [ORCID](http://orcid.org) identifier, crossref sends its url form which is kept for serialization
     #[serde(rename = "ORCID")]
-    pub orcid: Option<String>,
+    pub orcid: Option<Orcid>,
     /// If true, record owner asserts that the ORCID user completed ORCID OAuth authentication
     #[serde(rename = "authenticated-orcid")]
     pub authenticated_orcid: Option<bool>,
     pub affiliation: Vec<Affiliation>,
-    pub sequence: String
+    pub sequence: ContributorSequence,
 }
 
-impl TryFrom<serde_json::Value> for Contributor {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-
-                let prefix = map
-                    .get("prefix")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let suffix = map
-                    .get("suffix")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-
-
-                let family = map
-                    .get("family")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let given = map
-                    .get("given")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let name = map
-                    .get("name")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let orcid = map
-                    .get("ORCID")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let authenticated_orcid = map
-                    .get("authenticated-orcid")
-                    .and_then(|v| v.as_bool())
-                    .map(|v| v);
-
-                let affiliation = map.get("affiliation").and_then(|v| v.as_array()).ok_or(
-                    ErrorKind::MissingField {
-                        msg: "affiliation".to_string(),
-                    },
-                ).map(|v| {
-                    v.iter()
-                        .map(|v| Affiliation::try_from(v.clone()).unwrap())
-                        .collect()
-                })?;
-
-                let sequence = map
-                    .get("sequence")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string())
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "sequence".to_string(),
-                    })?;
-
-                Ok(Contributor {
-                    prefix,
-                    suffix,
-                    family,
-                    given,
-                    name,
-                    orcid,
-                    authenticated_orcid,
-                    affiliation,
-                    sequence
-                })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
+impl Contributor {
+    /// `Family, Given`, only the family name if the given name is missing,
+    /// or the name of an organisation
+    pub fn display_name(&self) -> Option<String> {
+        match (&self.family, &self.given, &self.name) {
+            (Some(family), Some(given), _) => Some(format!("{}, {}", family, given)),
+            (Some(family), None, _) => Some(family.clone()),
+            (None, _, name) => name.clone(),
         }
     }
 }
@@ -931,203 +1077,29 @@ pub struct Affiliation {
     pub name: String,
 }
 
-impl TryFrom<serde_json::Value> for Affiliation {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let name = map
-                    .get("name")
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "name".to_string(),
-                    })?
-                    .as_str()
-                    .ok_or(ErrorKind::InvalidTypeName {
-                        name: "name".to_string(),
-                    })?
-                    .to_string();
-
-                Ok(Affiliation { name: name })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
-        }
-    }
-}
-
-/// represents full date information for an item
+/// a date with the time crossref recorded it, like `created` or `indexed`
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct Date {
-    /// Contains an ordered array of year, month, day of month.
-    /// Only year is required. Note that the field contains a nested array,
-    /// e.g. [ [ 2006, 5, 19 ] ] to conform to citeproc JSON dates
-    pub date_parts: DateParts,
+    /// the day of the date, crossref sends it as `date-parts`,
+    /// e.g. `[ [ 2006, 5, 19 ] ]` to conform to citeproc JSON dates
+    #[serde(rename = "date-parts", with = "date_parts")]
+    pub date: CrossrefDate,
     /// Seconds since UNIX epoch
     pub timestamp: usize,
     /// ISO 8601 date time
     pub date_time: String,
 }
 
-impl TryFrom<serde_json::Value> for Date {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let date_parts = map.get("date-parts").and_then(|v| v.as_array()).map(|v| {
-                    // build a vector of vectors of three optional u32 values
-                    let date_parts = v
-                        .iter()
-                        .map(|v| {
-                            v.as_array()
-                                .unwrap()
-                                .iter()
-                                .map(|v| v.as_u64().map(|v| v as u32))
-                                .collect::<Vec<_>>()
-                        })
-                        .collect::<Vec<_>>();
-
-                    DateParts(date_parts)
-                });
-
-                let timestamp = map
-                    .get("timestamp")
-                    .and_then(|v| v.as_u64())
-                    .map(|v| v as usize)
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "timestamp".to_string(),
-                    })?;
-
-                let date_time = map
-                    .get("date-time")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string())
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "date-time".to_string(),
-                    })?;
-
-                Ok(Date {
-                    date_parts: date_parts.unwrap(),
-                    timestamp,
-                    date_time,
-                })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
-        }
-    }
-}
-
-impl Date {
-    /// converts the nested array of numbers into the correct representation of chrono [NaiveDate]
-    pub fn as_date_field(&self) -> Option<DateField> {
-        self.date_parts.as_date()
-    }
-
-    pub fn to_string(&self) -> String {
-        match self.as_date_field() {
-            Some(DateField::Single(date)) => date.format("%Y-%m-%d").to_string(),
-            Some(DateField::Range { from, to }) => {
-                format!("{}-{}", from.format("%Y-%m-%d"), to.format("%Y-%m-%d"))
-            }
-            Some(DateField::Multi(dates)) => dates
-                .iter()
-                .map(|date| date.format("%Y-%m-%d").to_string())
-                .collect::<Vec<_>>()
-                .join(","),
-            None => "".to_string(),
-        }
-    }
-}
-
-/// represents an incomplete date only consisting of year or year and month
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
-pub struct PartialDate {
-    /// Contains an ordered array of year, month, day of month.
-    /// Only year is required
-    /// e.g. `[ [`2006`] ]` to conform to citeproc JSON dates
-    #[serde(rename = "date-parts")]
-    pub date_parts: DateParts,
-}
-
-impl TryFrom<serde_json::Value> for PartialDate {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let date_parts = map.get("date-parts").and_then(|v| v.as_array()).map(|v| {
-                    // build a vector of vectors of three optional u32 values
-                    let date_parts = v
-                        .iter()
-                        .map(|v| {
-                            v.as_array()
-                                .unwrap()
-                                .iter()
-                                .map(|v| v.as_u64().map(|v| v as u32))
-                                .collect::<Vec<_>>()
-                        })
-                        .collect::<Vec<_>>();
-
-                    DateParts(date_parts)
-                });
-
-                Ok(PartialDate {
-                    date_parts: date_parts.unwrap(),
-                })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
-        }
-    }
-}
-
-impl PartialDate {
-    /// converts the nested array of numbers into the correct representation of chrono [NaiveDate]
-    pub fn as_date_field(&self) -> Option<DateField> {
-        self.date_parts.as_date()
-    }
-}
-
-/// Helper struct to capture all possible occurrences of dates in the crossref api, a nested Vec of numbers
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
-pub enum DateField {
-    /// only a single date vector
-    Single(NaiveDate),
-    /// two date vectors represent a range
-    Range {
-        /// start date of the range
-        from: NaiveDate,
-        /// end date of the range
-        to: NaiveDate,
-    },
-    /// more than two date vectors are present
-    Multi(Vec<NaiveDate>),
-}
-
-impl DateField {
-    pub fn year(&self) -> i32 {
-        match self {
-            DateField::Single(date) => date.year(),
-            DateField::Range { from, to } => from.year(),
-            DateField::Multi(dates) => dates[0].year(),
-        }
-    }
-}
-
 /// metadata about when the `Work` entry was updated
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Update {
     /// Date on which the update was published
-    pub updated: PartialDate,
+    #[serde(with = "date_parts::object")]
+    pub updated: CrossrefDate,
     /// DOI of the updated work
     #[serde(rename = "DOI")]
-    pub doi: String,
+    pub doi: Doi,
     /// The type of update, for example retraction or correction
     #[serde(rename = "type")]
     pub type_: String,
@@ -1135,52 +1107,6 @@ pub struct Update {
     pub label: Option<String>,
 }
 
-impl TryFrom<serde_json::Value> for Update {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let updated = map
-                    .get("updated")
-                    .and_then(|v| v.as_object())
-                    .map(|v| PartialDate::try_from(Value::Object(v.clone())).unwrap());
-
-                let doi = map
-                    .get("DOI")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string())
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "DOI".to_string(),
-                    })?;
-
-                let type_ = map
-                    .get("type")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string())
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "type".to_string(),
-                    })?;
-
-                let label = map
-                    .get("label")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                Ok(Update {
-                    updated: updated.unwrap(),
-                    doi,
-                    type_,
-                    label,
-                })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
-        }
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[allow(missing_docs)]
 pub struct Assertion {
@@ -1194,110 +1120,20 @@ pub struct Assertion {
     pub group: Option<AssertionGroup>,
 }
 
-impl TryFrom<serde_json::Value> for Assertion {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let name = map
-                    .get("name")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string())
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "name".to_string(),
-                    })?;
-
-                let value = map
-                    .get("value")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let url = map
-                    .get("URL")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let explanation = map
-                    .get("explanation")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let label = map
-                    .get("label")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let order = map.get("order").and_then(|v| v.as_i64()).map(|v| v as i32);
-
-                let group = map
-                    .get("group")
-                    .and_then(|v| v.as_object())
-                    .map(|v| AssertionGroup::try_from(Value::Object(v.clone())).unwrap());
-
-                Ok(Assertion {
-                    name,
-                    value,
-                    url,
-                    explanation,
-                    label,
-                    order,
-                    group,
-                })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
-        }
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 #[allow(missing_docs)]
 pub struct Issue {
     /// Date on which the work was published in print
-    pub published_print: Option<PartialDate>,
+    #[serde(default, with = "date_parts::option")]
+    pub published_print: Option<CrossrefDate>,
     /// Date on which the work was published online
-    pub published_online: Option<PartialDate>,
+    #[serde(default, with = "date_parts::option")]
+    pub published_online: Option<CrossrefDate>,
     /// Issue number of an article's journal
     pub issue: Option<String>,
 }
 
-impl TryFrom<serde_json::Value> for Issue {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let published_print = map
-                    .get("published-print")
-                    .and_then(|v| v.as_object())
-                    .map(|v| PartialDate::try_from(Value::Object(v.clone())).unwrap());
-
-                let published_online = map
-                    .get("published-online")
-                    .and_then(|v| v.as_object())
-                    .map(|v| PartialDate::try_from(Value::Object(v.clone())).unwrap());
-
-                let issue = map
-                    .get("issue")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                Ok(Issue {
-                    published_print,
-                    published_online,
-                    issue,
-                })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
-        }
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[allow(missing_docs)]
 pub struct AssertionGroup {
@@ -1305,34 +1141,6 @@ pub struct AssertionGroup {
     pub label: Option<String>,
 }
 
-impl TryFrom<serde_json::Value> for AssertionGroup {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let name = map
-                    .get("name")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string())
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "name".to_string(),
-                    })?;
-
-                let label = map
-                    .get("label")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                Ok(AssertionGroup { name, label })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
-        }
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[allow(missing_docs)]
 pub struct Agency {
@@ -1345,76 +1153,25 @@ pub struct Agency {
 #[serde(rename_all = "kebab-case")]
 pub struct License {
     /// Either `vor` (version of record,) `am` (accepted manuscript) or `unspecified`
-    pub content_version: String,
+    pub content_version: ContentVersion,
     /// Number of days between the publication date of the work and the start date of this license
     pub delay_in_days: i32,
     /// Date on which this license begins to take effect
-    pub start: PartialDate,
+    #[serde(with = "date_parts::object")]
+    pub start: CrossrefDate,
     /// Link to a web page describing this license
     #[serde(rename = "URL")]
     pub url: String,
 }
 
-impl TryFrom<serde_json::Value> for License {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let content_version = map
-                    .get("content-version")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string())
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "content-version".to_string(),
-                    })?;
-
-                let delay_in_days = map
-                    .get("delay-in-days")
-                    .and_then(|v| v.as_i64())
-                    .map(|v| v as i32)
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "delay-in-days".to_string(),
-                    })?;
-
-                let start = map
-                    .get("start")
-                    .and_then(|v| v.as_object())
-                    .map(|v| PartialDate::try_from(Value::Object(v.clone())).unwrap())
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "start".to_string(),
-                    })?;
-
-                let url = map
-                    .get("URL")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string())
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "URL".to_string(),
-                    })?;
-
-                Ok(License {
-                    content_version,
-                    delay_in_days,
-                    start,
-                    url,
-                })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
-        }
-    }
-}
-
 /// metadata about a related resource
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct ResourceLink {
     /// Either `text-mining`, `similarity-checking` or `unspecified`
-    pub intended_application: String,
+    pub intended_application: IntendedApplication,
     /// Either `vor` (version of record,) `am` (accepted manuscript) or `unspecified`
-    pub content_version: String,
+    pub content_version: ContentVersion,
     /// Direct link to a full-text download location
     #[serde(rename = "URL")]
     pub url: String,
@@ -1422,55 +1179,6 @@ pub struct ResourceLink {
     pub content_type: Option<String>,
 }
 
-impl TryFrom<serde_json::Value> for ResourceLink {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let intended_application = map
-                    .get("intended-application")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string())
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "intended-application".to_string(),
-                    })?;
-
-                let content_version = map
-                    .get("content-version")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string())
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "content-version".to_string(),
-                    })?;
-
-                let url = map
-                    .get("URL")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string())
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "URL".to_string(),
-                    })?;
-
-                let content_type = map
-                    .get("content-type")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                Ok(ResourceLink {
-                    intended_application,
-                    content_version,
-                    url,
-                    content_type,
-                })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
-        }
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 #[allow(missing_docs)]
@@ -1479,7 +1187,7 @@ pub struct Reference {
     #[serde(rename = "DOI")]
     pub doi: Option<String>,
     /// One of `crossref` or `publisher`
-    pub doi_asserted_by: Option<String>,
+    pub doi_asserted_by: Option<DoiAssertedBy>,
     pub issue: Option<String>,
     pub first_page: Option<String>,
     pub volume: Option<String>,
@@ -1496,155 +1204,20 @@ pub struct Reference {
     pub volume_title: Option<String>,
     #[serde(rename = "ISSN")]
     pub issn: Option<String>,
-    /// One of `pissn` or `eissn`
-    pub issn_type: Option<String>,
+    /// Either `print` or `electronic`
+    pub issn_type: Option<IssnKind>,
     #[serde(rename = "ISBN")]
     pub isbn: Option<String>,
     pub isbn_type: Option<String>,
 }
 
-impl TryFrom<serde_json::Value> for Reference {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let key = map
-                    .get("key")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string())
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "key".to_string(),
-                    })?;
-
-                let doi = map
-                    .get("DOI")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let doi_asserted_by = map
-                    .get("doi-asserted-by")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let issue = map
-                    .get("issue")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let first_page = map
-                    .get("first-page")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let volume = map
-                    .get("volume")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let edition = map
-                    .get("edition")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let component = map
-                    .get("component")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let standard_designator = map
-                    .get("standard-designator")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let standards_body = map
-                    .get("standards-body")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let author = map
-                    .get("author")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let year = map
-                    .get("year")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let unstructured = map
-                    .get("unstructured")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let journal_title = map
-                    .get("journal-title")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let article_title = map
-                    .get("article-title")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let series_title = map
-                    .get("series-title")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let volume_title = map
-                    .get("volume-title")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let issn = map
-                    .get("ISSN")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let issn_type = map
-                    .get("issn-type")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let isbn = map
-                    .get("ISBN")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let isbn_type = map
-                    .get("isbn-type")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                Ok(Reference {
-                    key,
-                    doi,
-                    doi_asserted_by,
-                    issue,
-                    first_page,
-                    volume,
-                    edition,
-                    component,
-                    standard_designator,
-                    standards_body,
-                    author,
-                    year,
-                    unstructured,
-                    journal_title,
-                    article_title,
-                    series_title,
-                    volume_title,
-                    issn,
-                    issn_type,
-                    isbn,
-                    isbn_type,
-                })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
-        }
+string_enum! {
+    /// The medium an ISSN was assigned for
+    #[allow(missing_docs)]
+    pub enum IssnKind {
+        Print => "print",
+        Electronic => "electronic",
+        Link => "link",
     }
 }
 
@@ -1653,41 +1226,10 @@ impl TryFrom<serde_json::Value> for Reference {
 #[serde(rename_all = "kebab-case")]
 pub struct ISSN {
     /// identifier
-    pub value: String,
-    /// One of `eissn`, `pissn` or `lissn`
+    pub value: Issn,
+    /// Either `print` or `electronic`
     #[serde(rename = "type")]
-    pub type_: String,
-}
-
-impl TryFrom<serde_json::Value> for ISSN {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let value = map
-                    .get("value")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string())
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "value".to_string(),
-                    })?;
-
-                let type_ = map
-                    .get("type")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string())
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "type".to_string(),
-                    })?;
-
-                Ok(ISSN { value, type_ })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
-        }
-    }
+    pub type_: IssnKind,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
@@ -1698,93 +1240,83 @@ pub struct ContentDomain {
     pub crossmark_restriction: bool,
 }
 
-impl TryFrom<serde_json::Value> for ContentDomain {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let domain = map
-                    .get("domain")
-                    .and_then(|v| v.as_array())
-                    .map(|v| v.iter().map(|v| v.as_str().unwrap().to_string()).collect());
-
-                let crossmark_restriction = map
-                    .get("crossmark-restriction")
-                    .and_then(|v| v.as_bool())
-                    .map(|v| v)
-                    .ok_or(ErrorKind::MissingField {
-                        msg: "crossmark-restriction".to_string(),
-                    })?;
-
-                Ok(ContentDomain {
-                    domain: domain.unwrap(),
-                    crossmark_restriction,
-                })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
-        }
+string_enum! {
+    /// Which side of a relation asserted it
+    #[allow(missing_docs)]
+    pub enum AssertedBy {
+        Subject => "subject",
+        Object => "object",
     }
 }
 
+/// A relation of a `Work` to another item
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
-#[allow(missing_docs)]
 pub struct Relation {
-    pub id_type: Option<String>,
-    pub id: Option<String>,
-    pub asserted_by: Option<String>,
+    /// the type of the identifier in `id`
+    pub id_type: RelationIdType,
+    /// the identifier of the related item
+    pub id: String,
+    /// whether the relation was asserted by the subject or the object
+    pub asserted_by: Option<AssertedBy>,
 }
 
-impl TryFrom<serde_json::Value> for Relation {
-    type Error = ErrorKind;
-
-    fn try_from(value: serde_json::Value) -> std::result::Result<Self, Self::Error> {
-        match value {
-            Value::Object(map) => {
-                let id_type = map
-                    .get("id-type")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let id = map
-                    .get("id")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                let asserted_by = map
-                    .get("asserted-by")
-                    .and_then(|v| v.as_str())
-                    .map(|v| v.to_string());
-
-                Ok(Relation {
-                    id_type,
-                    id,
-                    asserted_by,
-                })
-            }
-            _ => Err(ErrorKind::InvalidMessageType {
-                name: value.to_string(),
-            })?,
+impl Relation {
+    /// the related item as `Doi`, if it is identified by a valid DOI
+    pub fn doi(&self) -> Option<Doi> {
+        match self.id_type {
+            RelationIdType::Doi => self.id.parse().ok(),
+            _ => None,
         }
     }
 }
 
+string_enum! {
+    /// The stage of the publication process a review was written for
+    #[allow(missing_docs)]
+    pub enum ReviewStage {
+        PrePublication => "pre-publication",
+        PostPublication => "post-publication",
+    }
+}
+
+string_enum! {
+    /// The recommendation of a review
+    #[allow(missing_docs)]
+    pub enum ReviewRecommendation {
+        MajorRevision => "major-revision",
+        MinorRevision => "minor-revision",
+        Reject => "reject",
+        RejectWithResubmit => "reject-with-resubmit",
+        Accept => "accept",
+    }
+}
+
+string_enum! {
+    /// The kind of a review
+    #[allow(missing_docs)]
+    pub enum ReviewType {
+        RefereeReport => "referee-report",
+        EditorReport => "editor-report",
+        AuthorComment => "author-comment",
+        CommunityComment => "community-comment",
+        Aggregate => "aggregate",
+    }
+}
+
+/// Peer review metadata of a `Work` of type `peer-review`
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 #[allow(missing_docs)]
 pub struct Review {
+    #[serde(default, deserialize_with = "deserialize_counter")]
     pub running_number: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_counter")]
     pub revision_round: Option<String>,
-    /// One of `pre-publication` or `post-publication`
-    pub stage: Option<String>,
-    /// One of `major-revision` or `minor-revision` or `reject` or `reject-with-resubmit` or `accept`
-    pub recommendation: Option<String>,
-    /// One of `referee-report` or `editor-report` or `author-comment` or `community-comment` or `aggregate`
+    pub stage: Option<ReviewStage>,
+    pub recommendation: Option<ReviewRecommendation>,
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ReviewType,
     pub competing_interest_statement: Option<String>,
     pub language: Option<String>,
 }
@@ -1795,16 +1327,97 @@ mod tests {
     use serde_json::*;
     #[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
     struct Demo {
-        pub date_parts: DateParts,
+        #[serde(with = "date_parts")]
+        pub date_parts: CrossrefDate,
+        #[serde(default, with = "date_parts::option")]
+        pub issued: Option<CrossrefDate>,
     }
     #[test]
     fn date_parts_serde() {
         let demo = Demo {
-            date_parts: DateParts(vec![vec![Some(2017), Some(10), Some(11)]]),
+            date_parts: CrossrefDate::new(2017, Some(10), Some(11)).unwrap(),
+            issued: CrossrefDate::new(2017, Some(10), None),
         };
-        let expected = r##"{"date_parts":[[2017,10,11]]}"##;
+        let expected = r##"{"date_parts":[[2017,10,11]],"issued":{"date-parts":[[2017,10]]}}"##;
         assert_eq!(expected, &to_string(&demo).unwrap());
         assert_eq!(demo, from_str::<Demo>(expected).unwrap());
+
+        let unknown = from_str::<Demo>(r#"{"date_parts":[[2017]],"issued":{"date-parts":[[null]]}}"#);
+        assert_eq!(None, unknown.unwrap().issued);
+        assert!(from_str::<Demo>(r#"{"date_parts":[[2017, 13]]}"#).is_err());
+        assert!(from_str::<Demo>(r#"{"date_parts":[[null]]}"#).is_err());
+    }
+
+    #[test]
+    fn crossref_date_precision_and_order() {
+        let year = CrossrefDate::from_parts(&[Some(2019)]).unwrap();
+        let month = CrossrefDate::from_parts(&[Some(2019), Some(5)]).unwrap();
+        let day = CrossrefDate::from_parts(&[Some(2019), Some(5), Some(1)]).unwrap();
+        let later = CrossrefDate::new(2019, Some(6), None).unwrap();
+
+        assert_eq!(DatePrecision::Year, year.precision());
+        assert_eq!(DatePrecision::Month, month.precision());
+        assert_eq!(DatePrecision::Day, day.precision());
+        assert!(year < month && month < day && day < later);
+
+        assert_eq!("2019", year.to_string());
+        assert_eq!("2019-05", month.to_string());
+        assert_eq!("2019-05-01", day.to_string());
+        assert_eq!(month, "2019-05".parse().unwrap());
+        assert!("2019-02-30".parse::<CrossrefDate>().is_err());
+        assert!(CrossrefDate::new(2019, None, Some(1)).is_none());
+        assert!(CrossrefDate::new(300000, None, None).is_none());
+        assert!(CrossrefDate::from_parts(&[Some(4_000_000_000)]).is_none());
+        assert!("4000000000".parse::<CrossrefDate>().is_err());
+
+        assert_eq!(None, month.to_naive_date());
+        assert_eq!(NaiveDate::from_ymd_opt(2019, 5, 1), day.to_naive_date());
+        assert_eq!(NaiveDate::from_ymd_opt(2019, 5, 31).unwrap(), month.last_day());
+        assert_eq!(NaiveDate::from_ymd_opt(2019, 12, 31).unwrap(), year.last_day());
+        assert_eq!(NaiveDate::from_ymd_opt(2019, 1, 1).unwrap(), year.first_day());
+        assert_eq!(vec![Some(2019), Some(5)], month.to_parts());
+    }
+
+    #[test]
+    fn work_publication_date() {
+        let mut value = malformed_work();
+        value["language"] = json!("en");
+        value["ISSN"] = json!(["1935-990X"]);
+        value["author"] = json!([]);
+        value["issued"] = json!({"date-parts": [[2004]]});
+        let mut work = Work::parse(&value, ParseMode::Strict).unwrap();
+        assert_eq!("2004", work.publication_date().unwrap().to_string());
+
+        work.published_print = CrossrefDate::new(2004, Some(2), Some(3));
+        work.published_online = CrossrefDate::new(2004, Some(1), None);
+        assert_eq!("2004-01", work.publication_date().unwrap().to_string());
+
+        work.published_online = None;
+        assert_eq!("2004-02-03", work.publication_date().unwrap().to_string());
+
+        work.published_print = CrossrefDate::new(2004, None, None);
+        work.published_online = CrossrefDate::new(2004, Some(3), Some(5));
+        assert_eq!("2004-03-05", work.publication_date().unwrap().to_string());
+        work.published_print = CrossrefDate::new(2003, None, None);
+        assert_eq!("2003", work.publication_date().unwrap().to_string());
+    }
+
+    #[test]
+    fn work_display_helpers() {
+        let work = crate::response::work_fixture(json!({
+            "title": ["Growth of <i>E. coli</i>"],
+            "subtitle": ["A case study"],
+            "page": "3–9",
+            "author": [
+                {"family": "Smith", "given": "Jane", "sequence": "first", "affiliation": []},
+                {"name": "The Consortium", "sequence": "additional", "affiliation": []}
+            ],
+        }));
+        assert_eq!(Some("Growth of E. coli: A case study".to_string()), work.full_title());
+        assert_eq!(Some(("3", Some("9"))), work.page_range());
+        assert_eq!(("e1234", None), split_page_range("e1234 "));
+        let names: Vec<_> = work.author.iter().flatten().filter_map(Contributor::display_name).collect();
+        assert_eq!(vec!["Smith, Jane", "The Consortium"], names);
     }
 
     #[test]
@@ -1938,4 +1551,202 @@ mod tests {
 
         let work: Work = from_str(work_str).unwrap();
     }
+
+    #[test]
+    fn work_fields_match_serialized_keys() {
+        let work = crate::response::work_fixture(json!({}));
+        assert!(work.extra.is_empty());
+
+        let value = to_value(&work).unwrap();
+        let keys: std::collections::BTreeSet<_> =
+            value.as_object().unwrap().keys().map(String::as_str).collect();
+        let fields: std::collections::BTreeSet<_> = Work::FIELDS.iter().copied().collect();
+        assert_eq!(fields, keys);
+        assert_eq!(Work::FIELDS.len(), fields.len());
+    }
+
+    #[test]
+    fn deserialize_partial_work_list() {
+        let list_str = r#"{"facets":{},"total-results":2,"items":[{"DOI":"10.1037\/0003-066x.59.1.29","title":["How the Mind Hurts and Heals the Body."],"author":[{"given":"Oakley","family":"Ray","sequence":"first","affiliation":[]}]},{"DOI":"10.5555\/12345678"}],"items-per-page":20,"query":{"start-index":0,"search-terms":null}}"#;
+
+        let list: PartialWorkList = from_str(list_str).unwrap();
+
+        assert_eq!(2, list.items.len());
+        assert_eq!(Some("10.5555/12345678"), list.items[1].doi.as_ref().map(Doi::as_str));
+        assert!(list.items[1].title.is_none());
+        assert!(list.items[0].publisher.is_none());
+    }
+
+    fn malformed_work() -> Value {
+        json!({
+            "publisher": "American Psychological Association (APA)",
+            "title": ["How the Mind Hurts and Heals the Body."],
+            "DOI": "10.1037/0003-066x.59.1.29",
+            "member": "15",
+            "type": "journal-article",
+            "created": {"date-parts": [[2004, 1, 21]], "date-time": "2004-01-21T14:31:19Z", "timestamp": 1074695479000u64},
+            "indexed": {"date-parts": [[2019, 2, 14]], "date-time": "2019-02-14T05:10:15Z", "timestamp": 1550121015066u64},
+            "language": 42,
+            "ISSN": ["1935-990X", "0003-0661"],
+            "author": [
+                {"given": "Oakley", "family": "Ray", "sequence": "first", "affiliation": []},
+                {"given": "Someone", "sequence": "additional", "affiliation": "none"}
+            ],
+            "issued": {"date-parts": "2004"}
+        })
+    }
+
+    #[test]
+    fn parse_work_lenient() {
+        let work = Work::parse(&malformed_work(), ParseMode::Lenient).unwrap();
+
+        assert!(work.language.is_none());
+        assert!(work.issued.is_none());
+        assert_eq!(1, work.issn.as_ref().unwrap().len());
+        assert_eq!(1, work.author.as_ref().unwrap().len());
+
+        let paths: Vec<_> = work.warnings.iter().map(|w| w.path.as_str()).collect();
+        assert_eq!(vec!["ISSN[1]", "author[1]", "issued", "language"], paths);
+        assert!(work
+            .warnings
+            .iter()
+            .all(|w| w.doi.as_deref() == Some("10.1037/0003-066x.59.1.29")));
+    }
+
+    #[test]
+    fn from_json_matches_parse() {
+        let json = include_str!("../../tests/data/work.json");
+        let derived = Work::from_json(json, ParseMode::Strict).unwrap();
+        let parsed = Work::parse(&from_str(json).unwrap(), ParseMode::Strict).unwrap();
+
+        assert!(parsed.warnings.is_empty());
+        assert_eq!(to_value(&derived).unwrap(), to_value(&parsed).unwrap());
+    }
+
+    #[test]
+    fn parse_work_strict() {
+        assert!(Work::parse(&malformed_work(), ParseMode::Strict).is_err());
+        assert!(Work::try_from(malformed_work()).is_err());
+    }
+
+    #[test]
+    fn malformed_orcid_keeps_author() {
+        let mut value = malformed_work();
+        value["language"] = json!("en");
+        value["issued"] = json!({"date-parts": [[2004]]});
+        value["ISSN"] = json!(["1935-990X"]);
+        value["author"] = json!([
+            {"given": "Oakley", "family": "Ray", "sequence": "first", "affiliation": []},
+            {"given": "Ann", "family": "Smith", "sequence": "additional", "affiliation": [],
+             "ORCID": "http://orcid.org/0000-0002-1825-0098"},
+            {"given": "Bo", "family": "Jones", "sequence": "additional", "affiliation": [],
+             "ORCID": "http://orcid.org/0000-0002-1825-0097"}
+        ]);
+        for mode in [ParseMode::Strict, ParseMode::Lenient] {
+            let work = Work::parse(&value, mode).unwrap();
+            let authors = work.author.as_ref().unwrap();
+            assert_eq!(3, authors.len());
+            assert!(authors[1].orcid.is_none());
+            assert!(authors[2].orcid.is_some());
+            let paths: Vec<_> = work.warnings.iter().map(|w| w.path.as_str()).collect();
+            assert_eq!(vec!["author[1].ORCID"], paths);
+        }
+    }
+
+    #[test]
+    fn parse_typed_enums() {
+        let mut value = malformed_work();
+        value["language"] = json!("en");
+        value["issued"] = json!({"date-parts": [[2004]]});
+        value["type"] = json!("grant");
+        value["ISSN"] = json!(["1935-990X"]);
+        value["author"] = json!([{"given": "Oakley", "family": "Ray", "sequence": "first", "affiliation": []}]);
+        value["issn-type"] = json!([{"value": "0003-066X", "type": "print"}]);
+        value["link"] = json!([{
+            "URL": "http://psycnet.apa.org/journals/amp/59/1/29.pdf",
+            "content-type": "unspecified",
+            "content-version": "vor",
+            "intended-application": "similarity-checking"
+        }]);
+        value["reference"] = json!([{"key": "ref1", "doi-asserted-by": "crossref", "issn-type": "electronic"}]);
+        let work = Work::parse(&value, ParseMode::Strict).unwrap();
+
+        assert_eq!(Type::Unknown("grant".to_string()), work.type_);
+        assert_eq!(ContributorSequence::First, work.author.as_ref().unwrap()[0].sequence);
+        assert_eq!(IssnKind::Print, work.issn_type.as_ref().unwrap()[0].type_);
+
+        let link = &work.link.as_ref().unwrap()[0];
+        assert_eq!(ContentVersion::Vor, link.content_version);
+        assert_eq!(IntendedApplication::SimilarityChecking, link.intended_application);
+
+        let reference = &work.reference.as_ref().unwrap()[0];
+        assert_eq!(Some(DoiAssertedBy::Crossref), reference.doi_asserted_by);
+        assert_eq!(Some(IssnKind::Electronic), reference.issn_type);
+
+        let serialized = serde_json::to_value(&work).unwrap();
+        assert_eq!(json!("grant"), serialized["type"]);
+        assert_eq!(json!("first"), serialized["author"][0]["sequence"]);
+    }
+
+    #[test]
+    fn parse_relations_and_review() {
+        let mut value = malformed_work();
+        value["language"] = json!("en");
+        value["issued"] = json!({"date-parts": [[2004]]});
+        value["relation"] = json!({
+            "is-review-of": [{"id-type": "doi", "id": "10.1101/2020.01.01.123456", "asserted-by": "subject"}],
+            "has-preprint": {"id-type": "doi", "id": "10.31234/osf.io/abcde", "asserted-by": "object"},
+            "is-tweeted-by": [{"id-type": "uri", "id": "https://example.org"}]
+        });
+        value["review"] = json!({
+            "type": "referee-report",
+            "stage": "pre-publication",
+            "recommendation": "minor-revision",
+            "revision-round": 1,
+            "language": "en"
+        });
+        let work = Work::parse(&value, ParseMode::Lenient).unwrap();
+
+        assert_eq!(1, work.reviews_of().len());
+        assert_eq!(AssertedBy::Subject, *work.reviews_of()[0].asserted_by.as_ref().unwrap());
+        assert_eq!(
+            "10.1101/2020.01.01.123456",
+            work.reviews_of()[0].doi().unwrap().as_str()
+        );
+        assert_eq!("10.31234/osf.io/abcde", work.preprints()[0].id);
+        assert!(work.preprint_of().is_empty());
+        assert_eq!(
+            RelationIdType::Uri,
+            work.relations_of(&RelationType::Unknown("is-tweeted-by".to_string()))[0].id_type
+        );
+
+        let review = work.review.unwrap();
+        assert_eq!(ReviewType::RefereeReport, review.type_);
+        assert_eq!(Some(ReviewStage::PrePublication), review.stage);
+        assert_eq!(Some(ReviewRecommendation::MinorRevision), review.recommendation);
+        assert_eq!(Some("1".to_string()), review.revision_round);
+    }
+
+    #[test]
+    fn parse_work_list_lenient() {
+        let mut missing_title = malformed_work();
+        missing_title["DOI"] = json!("10.5555/12345678");
+        missing_title.as_object_mut().unwrap().remove("title");
+        let list = json!({
+            "facets": {},
+            "total-results": 2,
+            "items": [malformed_work(), missing_title],
+            "items-per-page": 20
+        });
+
+        let list = WorkList::parse(&list, ParseMode::Lenient).unwrap();
+        assert_eq!(1, list.items.len());
+        assert_eq!(1, list.warnings.len());
+        assert_eq!("items[1]", list.warnings[0].path);
+        assert_eq!(Some("10.5555/12345678"), list.warnings[0].doi.as_deref());
+        assert_eq!(5, list.all_warnings().count());
+
+        let list = json!({"facets": {}, "total-results": 1, "items": [malformed_work()]});
+        assert!(WorkList::parse(&list, ParseMode::Strict).is_err());
+    }
 }
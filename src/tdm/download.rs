@@ -0,0 +1,560 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, Utc};
+use reqwest::header::{self, HeaderMap, HeaderValue};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+
+use super::FullTextFormat;
+use crate::error::{ErrorKind, Result};
+use crate::ident::Doi;
+use crate::response::work::{ResourceLink, Work};
+
+/// the header that carries the click-through token for publishers that require one
+const CLICKTHROUGH_TOKEN: &str = "CR-Clickthrough-Client-Token";
+/// the number of requests left until `CR-TDM-Rate-Limit-Reset`
+const RATE_LIMIT_REMAINING: &str = "CR-TDM-Rate-Limit-Remaining";
+/// the unix time in seconds when the publisher resets the limit
+const RATE_LIMIT_RESET: &str = "CR-TDM-Rate-Limit-Reset";
+
+/// Downloads full texts from the publisher links of a `Work`.
+///
+/// Requests to a publisher host are paused while its `CR-TDM-Rate-Limit-*` headers report that the
+/// limit is used up, and requests that are answered with `429 Too Many Requests` are retried after
+/// the announced time.
+#[derive(Debug, Clone)]
+pub struct TdmClient {
+    client: Client,
+    max_retries: usize,
+    /// whether links without a TDM license that applies on the day of the download are fetched
+    ignore_license: bool,
+    /// the earliest instant the next request to a host may be sent
+    hosts: Arc<Mutex<HashMap<String, Instant>>>,
+}
+
+/// Builds a `TdmClient`
+#[derive(Debug, Default)]
+pub struct TdmClientBuilder {
+    user_agent: Option<String>,
+    clickthrough_token: Option<String>,
+    max_retries: Option<usize>,
+    ignore_license: bool,
+}
+
+impl TdmClientBuilder {
+    /// be polite and set your email as `User-Agent`
+    pub fn polite(mut self, email: &str) -> Self {
+        self.user_agent = Some(format!("mailto:{}", email));
+        self
+    }
+
+    /// set the user agent directly
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// the token sent as `CR-Clickthrough-Client-Token`, required by publishers that license TDM
+    /// through the crossref click-through service
+    pub fn clickthrough_token(mut self, token: &str) -> Self {
+        self.clickthrough_token = Some(token.to_string());
+        self
+    }
+
+    /// how often a request answered with `429 Too Many Requests` is retried, 3 by default
+    pub fn max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// download links even if none of the TDM licenses of the work covers them today, e.g. because
+    /// the caller has its own agreement with the publisher. Such links are refused by default.
+    pub fn ignore_license(mut self, ignore_license: bool) -> Self {
+        self.ignore_license = ignore_license;
+        self
+    }
+
+    /// Returns a `TdmClient` that uses this configuration.
+    /// # Errors
+    ///
+    /// This will fail if a header value is invalid or the TLS backend cannot be initialized
+    pub fn build(self) -> Result<TdmClient> {
+        let mut headers = HeaderMap::new();
+        if let Some(agent) = &self.user_agent {
+            headers.insert(
+                header::USER_AGENT,
+                HeaderValue::from_str(agent).map_err(|_| ErrorKind::Config {
+                    msg: format!("failed to create User Agent header for `{}`", agent),
+                })?,
+            );
+        }
+        if let Some(token) = &self.clickthrough_token {
+            headers.insert(
+                CLICKTHROUGH_TOKEN,
+                HeaderValue::from_str(token).map_err(|_| ErrorKind::Config {
+                    msg: format!("failed to create {} header for `{}`", CLICKTHROUGH_TOKEN, token),
+                })?,
+            );
+        }
+        let client = Client::builder()
+            .default_headers(headers)
+            .build()
+            .map_err(|_| ErrorKind::Config {
+                msg: "failed to initialize TLS backend".to_string(),
+            })?;
+        Ok(TdmClient {
+            client,
+            max_retries: self.max_retries.unwrap_or(3),
+            ignore_license: self.ignore_license,
+            hosts: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+}
+
+/// A downloaded full text
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ManifestEntry {
+    /// the work the full text belongs to
+    #[serde(rename = "DOI")]
+    pub doi: Doi,
+    /// the link the full text was downloaded from
+    #[serde(rename = "URL")]
+    pub url: String,
+    /// the file name relative to the directory of the manifest
+    pub path: PathBuf,
+    /// the `Content-Type` the publisher answered with
+    pub content_type: Option<String>,
+    /// the size of the file in bytes
+    pub size: u64,
+    /// the url of a TDM license that applied on the day of the download
+    pub license: Option<String>,
+    /// when the file was downloaded
+    pub downloaded_at: DateTime<Utc>,
+}
+
+/// A line of the manifest file, the outcome for a single work
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum ManifestRecord {
+    Entry(ManifestEntry),
+    Failure {
+        #[serde(rename = "DOI")]
+        doi: Doi,
+        reason: String,
+    },
+}
+
+/// The record of all downloads into a directory, kept in its `manifest.jsonl`
+///
+/// The file holds one JSON line per downloaded or failed work, a later line for the same DOI
+/// replaces the failure of an earlier one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Manifest {
+    /// the downloaded full texts
+    pub entries: Vec<ManifestEntry>,
+    /// the works without a full text, with the reason
+    #[serde(default)]
+    pub failures: Vec<(Doi, String)>,
+}
+
+impl Manifest {
+    /// the file name of the manifest in the download directory
+    pub const FILE_NAME: &'static str = "manifest.jsonl";
+
+    /// read the manifest of `dir`, an empty manifest if there is none yet
+    ///
+    /// A last line that was cut off, because a previous run was interrupted while writing it, is
+    /// ignored.
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(Manifest::FILE_NAME);
+        let mut manifest = Manifest::default();
+        if !path.exists() {
+            return Ok(manifest);
+        }
+        let content = fs::read_to_string(path)?;
+        let mut lines = content.lines().filter(|line| !line.trim().is_empty()).peekable();
+        while let Some(line) = lines.next() {
+            match serde_json::from_str(line) {
+                Ok(record) => manifest.push(record),
+                Err(_) if lines.peek().is_none() && !content.ends_with('\n') => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// write the whole manifest into `dir`, through a temporary file that replaces the manifest
+    /// only once it is complete
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        let mut out = Vec::new();
+        for record in self.records() {
+            serde_json::to_writer(&mut out, &record)?;
+            out.push(b'\n');
+        }
+        let tmp = dir.join(format!("{}.tmp", Manifest::FILE_NAME));
+        fs::write(&tmp, out)?;
+        fs::rename(tmp, dir.join(Manifest::FILE_NAME))?;
+        Ok(())
+    }
+
+    /// add `record` and append it to the manifest file of `dir`
+    fn append(&mut self, dir: &Path, record: ManifestRecord) -> Result<()> {
+        let mut line = serde_json::to_vec(&record)?;
+        line.push(b'\n');
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(dir.join(Manifest::FILE_NAME))?
+            .write_all(&line)?;
+        self.push(record);
+        Ok(())
+    }
+
+    fn push(&mut self, record: ManifestRecord) {
+        match record {
+            ManifestRecord::Entry(entry) => {
+                self.failures.retain(|(doi, _)| doi != &entry.doi);
+                self.entries.push(entry);
+            }
+            ManifestRecord::Failure { doi, reason } => {
+                self.failures.retain(|(failed, _)| failed != &doi);
+                self.failures.push((doi, reason));
+            }
+        }
+    }
+
+    fn records(&self) -> impl Iterator<Item = ManifestRecord> + '_ {
+        let entries = self.entries.iter().cloned().map(ManifestRecord::Entry);
+        let failures = self.failures.iter().map(|(doi, reason)| ManifestRecord::Failure {
+            doi: doi.clone(),
+            reason: reason.clone(),
+        });
+        entries.chain(failures)
+    }
+
+    /// whether a full text of `doi` was downloaded
+    pub fn contains(&self, doi: &Doi) -> bool {
+        self.entries.iter().any(|entry| &entry.doi == doi)
+    }
+}
+
+impl TdmClient {
+    /// Constructs a new `TdmClientBuilder`.
+    pub fn builder() -> TdmClientBuilder {
+        TdmClientBuilder::default()
+    }
+
+    /// Download the full text behind `link`, returns the `Content-Type` and the body
+    ///
+    /// # Errors
+    ///
+    /// Fails if the publisher answers with an error status, or still with `429` after all retries
+    pub async fn download(&self, link: &ResourceLink) -> Result<(Option<String>, bytes::Bytes)> {
+        let host = reqwest::Url::parse(&link.url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_default();
+        let mut retries = 0;
+        loop {
+            self.wait_for(&host).await;
+            let res = self
+                .client
+                .get(&link.url)
+                .header(header::ACCEPT, accept(link.content_type.as_deref()))
+                .send()
+                .await?;
+            let blocked = blocked_for(res.headers(), res.status());
+            if let Some(wait) = blocked {
+                self.block(&host, wait);
+            }
+            if res.status() == StatusCode::TOO_MANY_REQUESTS && retries < self.max_retries {
+                if blocked.is_none() {
+                    self.block(&host, backoff(retries));
+                }
+                retries += 1;
+                continue;
+            }
+            let res = res.error_for_status()?;
+            let content_type = res
+                .headers()
+                .get(header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            return Ok((content_type, res.bytes().await?));
+        }
+    }
+
+    /// Download the first full text of `work` in one of the `formats` into `dir`
+    ///
+    /// The links are tried in the order of `formats`, the file is named after the DOI.
+    ///
+    /// # Errors
+    ///
+    /// Links without a TDM license that covers them and applies today are skipped, unless the
+    /// client was built with [TdmClientBuilder::ignore_license]. Fails without a request if that
+    /// leaves no link.
+    pub async fn download_work(
+        &self,
+        work: &Work,
+        formats: &[FullTextFormat],
+        dir: &Path,
+    ) -> Result<ManifestEntry> {
+        let today = Utc::now().date_naive();
+        let links = work.full_text_links(formats);
+        if links.is_empty() {
+            return Err(ErrorKind::MissingField {
+                msg: format!("no text-mining link for {}", work.doi),
+            }
+            .into());
+        }
+        let mut last_error = None;
+        for link in links {
+            let license = work.tdm_license_on(link, today).map(|license| license.url.clone());
+            if license.is_none() && !self.ignore_license {
+                let msg = match work.tdm_embargo_end(link).filter(|end| *end > today) {
+                    Some(end) => format!("the TDM license of {} applies from {}", work.doi, end),
+                    None => format!("no TDM license for {}", work.doi),
+                };
+                last_error = Some(ErrorKind::MissingField { msg }.into());
+                continue;
+            }
+            match self.download(link).await {
+                Ok((content_type, body)) => {
+                    let format = content_type
+                        .as_deref()
+                        .map(FullTextFormat::from_content_type)
+                        .filter(|format| *format != FullTextFormat::Other)
+                        .unwrap_or_else(|| link.format());
+                    let path = PathBuf::from(format!("{}.{}", file_stem(&work.doi), format.extension()));
+                    fs::write(dir.join(&path), &body)?;
+                    return Ok(ManifestEntry {
+                        doi: work.doi.clone(),
+                        url: link.url.clone(),
+                        path,
+                        content_type,
+                        size: body.len() as u64,
+                        license,
+                        downloaded_at: Utc::now(),
+                    });
+                }
+                Err(e) => {
+                    tracing::warn!(doi = %work.doi, url = %link.url, "full text download failed: {}", e);
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(last_error.expect("at least one link was tried"))
+    }
+
+    /// Download the full texts of all `works` into `dir` and record them in its `manifest.jsonl`.
+    ///
+    /// Works that are already in the manifest are skipped, so an interrupted harvest can be
+    /// resumed. The outcome of each work is appended to the manifest as soon as it is known. A failed work does not stop the others, it is recorded in `Manifest::failures`,
+    /// as are works without a TDM license that applies today.
+    pub async fn download_all<'a, I>(
+        &self,
+        works: I,
+        formats: &[FullTextFormat],
+        dir: &Path,
+    ) -> Result<Manifest>
+    where
+        I: IntoIterator<Item = &'a Work>,
+    {
+        fs::create_dir_all(dir)?;
+        let mut manifest = Manifest::load(dir)?;
+        // drops the lines of replaced failures and a line an interrupted run cut off
+        manifest.save(dir)?;
+        for work in works {
+            if manifest.contains(&work.doi) {
+                continue;
+            }
+            let record = match self.download_work(work, formats, dir).await {
+                Ok(entry) => ManifestRecord::Entry(entry),
+                Err(e) => ManifestRecord::Failure {
+                    doi: work.doi.clone(),
+                    reason: e.to_string(),
+                },
+            };
+            manifest.append(dir, record)?;
+        }
+        Ok(manifest)
+    }
+
+    async fn wait_for(&self, host: &str) {
+        let until = self
+            .hosts
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(host)
+            .copied();
+        if let Some(wait) = until.and_then(|until| until.checked_duration_since(Instant::now())) {
+            tracing::debug!(host, ?wait, "waiting for the publisher rate limit");
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    fn block(&self, host: &str, wait: Duration) {
+        self.hosts
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(host.to_string(), Instant::now() + wait);
+    }
+}
+
+/// how long the publisher asks to pause, from `Retry-After` or the `CR-TDM-Rate-Limit-*` headers
+fn blocked_for(headers: &HeaderMap, status: StatusCode) -> Option<Duration> {
+    let value = |name: &str| headers.get(name)?.to_str().ok()?.trim().parse::<u64>().ok();
+    let until_reset = || {
+        let reset = value(RATE_LIMIT_RESET)?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+        Some(Duration::from_secs(reset.saturating_sub(now)))
+    };
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        if let Some(secs) = value(header::RETRY_AFTER.as_str()) {
+            return Some(Duration::from_secs(secs));
+        }
+        return until_reset();
+    }
+    match value(RATE_LIMIT_REMAINING) {
+        Some(0) => until_reset(),
+        _ => None,
+    }
+}
+
+/// the pause before retry number `retries + 1` if the publisher did not announce one, doubling
+/// from a second up to about a minute
+fn backoff(retries: usize) -> Duration {
+    Duration::from_secs(1 << retries.min(6))
+}
+
+/// the `Accept` header for a link, publishers often announce the content type as `unspecified`
+fn accept(content_type: Option<&str>) -> &str {
+    let is_mime = |value: &str| {
+        let essence = value.split(';').next().unwrap_or_default().trim();
+        essence.split_once('/').is_some_and(|(type_, subtype)| {
+            let token = |s: &str| {
+                !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || "!#$&-^_.+*".contains(c))
+            };
+            token(type_) && token(subtype)
+        })
+    };
+    content_type.filter(|value| is_mime(value)).unwrap_or("*/*")
+}
+
+/// the DOI with every byte that is not safe in a file name percent-encoded, so distinct DOIs
+/// never share a file
+fn file_stem(doi: &Doi) -> String {
+    let mut stem = String::new();
+    for byte in doi.to_string().bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'-' | b'_') {
+            stem.push(byte as char);
+        } else {
+            stem.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    stem
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::work_fixture;
+    use serde_json::json;
+
+    #[test]
+    fn rate_limit_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(None, blocked_for(&headers, StatusCode::OK));
+        headers.insert(RATE_LIMIT_REMAINING, HeaderValue::from_static("0"));
+        headers.insert(RATE_LIMIT_RESET, HeaderValue::from_static("0"));
+        assert_eq!(Some(Duration::ZERO), blocked_for(&headers, StatusCode::OK));
+        headers.insert(header::RETRY_AFTER, HeaderValue::from_static("7"));
+        assert_eq!(
+            Some(Duration::from_secs(7)),
+            blocked_for(&headers, StatusCode::TOO_MANY_REQUESTS)
+        );
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        assert_eq!(Duration::from_secs(1), backoff(0));
+        assert_eq!(Duration::from_secs(8), backoff(3));
+        assert_eq!(Duration::from_secs(64), backoff(64));
+        assert_eq!(Duration::from_secs(64), backoff(usize::MAX));
+    }
+
+    #[test]
+    fn file_stems_are_distinct() {
+        let stem = |doi: &str| file_stem(&doi.parse().unwrap());
+        assert_eq!("10.1000%2Fa%3Ab.c", stem("10.1000/a:b.c"));
+        assert_eq!("10.1000%2Fa_b", stem("10.1000/a_b"));
+        assert_eq!("10.1000%2Fa%2Fb", stem("10.1000/a/b"));
+    }
+
+    #[test]
+    fn accept_falls_back_to_any() {
+        assert_eq!("text/xml", accept(Some("text/xml")));
+        assert_eq!("application/jats+xml; charset=utf-8", accept(Some("application/jats+xml; charset=utf-8")));
+        assert_eq!("*/*", accept(Some("unspecified")));
+        assert_eq!("*/*", accept(None));
+    }
+
+    #[tokio::test]
+    async fn unlicensed_work_is_refused() {
+        let work = work_fixture(json!({
+            "link": [{"URL": "https://example.org/1.xml", "content-type": "text/xml",
+                      "content-version": "vor", "intended-application": "text-mining"}],
+        }));
+        let dir = std::env::temp_dir().join(format!("crossref-tdm-{}", std::process::id()));
+        let client = TdmClient::builder().build().unwrap();
+
+        let manifest = client.download_all(&[work], &[FullTextFormat::Xml], &dir).await.unwrap();
+        assert!(manifest.entries.is_empty());
+        assert_eq!("no TDM license for 10.5555/12345678", manifest.failures[0].1);
+        assert_eq!(manifest, Manifest::load(&dir).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn manifest_lines() {
+        let dir = std::env::temp_dir().join(format!("crossref-manifest-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let doi: Doi = "10.1000/a".parse().unwrap();
+        let entry = ManifestEntry {
+            doi: doi.clone(),
+            url: "https://example.org/a.xml".to_string(),
+            path: PathBuf::from("10.1000%2Fa.xml"),
+            content_type: Some("text/xml".to_string()),
+            size: 3,
+            license: None,
+            downloaded_at: Utc::now(),
+        };
+        let mut manifest = Manifest::default();
+        let failure = ManifestRecord::Failure {
+            doi: doi.clone(),
+            reason: "timeout".to_string(),
+        };
+        manifest.append(&dir, failure).unwrap();
+        manifest.append(&dir, ManifestRecord::Entry(entry.clone())).unwrap();
+        assert!(manifest.failures.is_empty());
+        assert_eq!(manifest, Manifest::load(&dir).unwrap());
+
+        // the line of an interrupted write
+        let mut file = fs::OpenOptions::new()
+            .append(true)
+            .open(dir.join(Manifest::FILE_NAME))
+            .unwrap();
+        file.write_all(b"{\"failure\": {\"DOI\"").unwrap();
+        assert_eq!(manifest, Manifest::load(&dir).unwrap());
+        manifest.save(&dir).unwrap();
+        assert_eq!(vec![entry], Manifest::load(&dir).unwrap().entries);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
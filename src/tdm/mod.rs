@@ -1 +1,221 @@
+//! Text and data mining (TDM) of the full texts publishers register with crossref.
+//!
+//! Publishers deposit links to the full text of a work in `Work::link` and the licenses that apply
+//! in `Work::license`. This module selects the links meant for text mining, checks whether a
+//! license permits TDM on a given day, and downloads the full texts with a [TdmClient], see the
+//! [crossref TDM documentation](https://www.crossref.org/documentation/retrieve-metadata/rest-api/text-and-data-mining/).
 
+mod download;
+
+pub use self::download::{Manifest, ManifestEntry, TdmClient, TdmClientBuilder};
+
+use chrono::NaiveDate;
+
+use crate::query::{ContentVersion, IntendedApplication};
+use crate::response::license::LicenseKind;
+use crate::response::work::{License, ResourceLink, Work};
+
+/// The format of a full-text link, derived from its content type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FullTextFormat {
+    /// `application/pdf`
+    Pdf,
+    /// `text/xml`, `application/xml` or a JATS/Elsevier xml flavour
+    Xml,
+    /// `text/html`
+    Html,
+    /// `text/plain`
+    Plain,
+    /// `unspecified` or any other content type
+    Other,
+}
+
+impl FullTextFormat {
+    /// the format of the mime type `content_type`, parameters like `; charset=utf-8` are ignored
+    pub fn from_content_type(content_type: &str) -> Self {
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        match mime.as_str() {
+            "application/pdf" => FullTextFormat::Pdf,
+            "text/html" | "application/xhtml+xml" => FullTextFormat::Html,
+            "text/plain" => FullTextFormat::Plain,
+            mime if mime.ends_with("/xml") || mime.ends_with("+xml") => FullTextFormat::Xml,
+            _ => FullTextFormat::Other,
+        }
+    }
+
+    /// the file extension for downloads of this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            FullTextFormat::Pdf => "pdf",
+            FullTextFormat::Xml => "xml",
+            FullTextFormat::Html => "html",
+            FullTextFormat::Plain => "txt",
+            FullTextFormat::Other => "bin",
+        }
+    }
+}
+
+impl ResourceLink {
+    /// the format of the full text behind this link
+    pub fn format(&self) -> FullTextFormat {
+        self.content_type
+            .as_deref()
+            .map(FullTextFormat::from_content_type)
+            .unwrap_or(FullTextFormat::Other)
+    }
+
+    /// whether the publisher registered this link for text mining
+    pub fn is_text_mining(&self) -> bool {
+        self.intended_application == IntendedApplication::TextMining
+    }
+}
+
+impl License {
+    /// whether this license permits text and data mining, either an open license or a publisher
+    /// TDM license. Registering a license for the `tdm` content version alone does not make it one.
+    pub fn permits_tdm(&self) -> bool {
+        let kind = self.kind();
+        kind.is_open() || kind == LicenseKind::PublisherTdm
+    }
+
+    /// whether this license covers mining the full text behind `link`, a license for the `tdm`
+    /// content version covers the links of every version
+    pub fn covers(&self, link: &ResourceLink) -> bool {
+        self.content_version == ContentVersion::Tdm || self.applies_to(&link.content_version)
+    }
+}
+
+impl Work {
+    /// the full-text links the publisher registered for text mining
+    pub fn text_mining_links(&self) -> Vec<&ResourceLink> {
+        self.link
+            .iter()
+            .flatten()
+            .filter(|link| link.is_text_mining())
+            .collect()
+    }
+
+    /// the text-mining links in one of the `formats`, in the order of `formats`
+    pub fn full_text_links(&self, formats: &[FullTextFormat]) -> Vec<&ResourceLink> {
+        let links = self.text_mining_links();
+        formats
+            .iter()
+            .flat_map(|format| links.iter().filter(move |link| link.format() == *format))
+            .copied()
+            .collect()
+    }
+
+    /// the licenses of this work that permit text and data mining
+    pub fn tdm_licenses(&self) -> Vec<&License> {
+        self.license
+            .iter()
+            .flatten()
+            .filter(|license| license.permits_tdm())
+            .collect()
+    }
+
+    /// a TDM license that covers `link` and applies on `date`, `None` if there is none or all are
+    /// still embargoed
+    pub fn tdm_license_on(&self, link: &ResourceLink, date: NaiveDate) -> Option<&License> {
+        self.tdm_licenses()
+            .into_iter()
+            .find(|license| license.covers(link) && license.is_active_on(date))
+    }
+
+    /// the first day any TDM license that covers `link` applies
+    pub fn tdm_embargo_end(&self, link: &ResourceLink) -> Option<NaiveDate> {
+        self.tdm_licenses()
+            .into_iter()
+            .filter(|license| license.covers(link))
+            .map(License::start_date)
+            .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::work_fixture;
+    use serde_json::json;
+
+    fn work() -> Work {
+        work_fixture(json!({
+            "DOI": "10.1016/j.cell.2019.01.001",
+            "publisher": "Elsevier",
+            "title": ["Cells"],
+            "link": [
+                {"URL": "https://api.elsevier.com/content/article/PII:1?httpAccept=text/xml",
+                 "content-type": "text/xml", "content-version": "vor", "intended-application": "text-mining"},
+                {"URL": "https://api.elsevier.com/content/article/PII:1?httpAccept=text/plain",
+                 "content-type": "text/plain", "content-version": "vor", "intended-application": "text-mining"},
+                {"URL": "https://www.cell.com/pdf/1.pdf",
+                 "content-type": "application/pdf", "content-version": "vor", "intended-application": "similarity-checking"}
+            ],
+            "license": [
+                {"URL": "https://www.elsevier.com/tdm/userlicense/1.0/", "content-version": "tdm",
+                 "delay-in-days": 0, "start": {"date-parts": [[2019, 2, 1]]}},
+                {"URL": "http://creativecommons.org/licenses/by-nc-nd/4.0/", "content-version": "am",
+                 "delay-in-days": 365, "start": {"date-parts": [[2020, 2, 1]]}},
+                {"URL": "https://www.elsevier.com/legal/userlicense", "content-version": "vor",
+                 "delay-in-days": 0, "start": {"date-parts": [[2019, 2, 1]]}}
+            ],
+        }))
+    }
+
+    #[test]
+    fn full_text_links() {
+        let work = work();
+        assert_eq!(2, work.text_mining_links().len());
+        let links = work.full_text_links(&[FullTextFormat::Pdf, FullTextFormat::Plain, FullTextFormat::Xml]);
+        assert_eq!(
+            vec![FullTextFormat::Plain, FullTextFormat::Xml],
+            links.iter().map(|l| l.format()).collect::<Vec<_>>()
+        );
+        assert_eq!(FullTextFormat::Xml, FullTextFormat::from_content_type("application/jats+xml; charset=utf-8"));
+    }
+
+    #[test]
+    fn tdm_licenses_and_embargo() {
+        let work = work();
+        assert_eq!(2, work.tdm_licenses().len());
+        let day = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let vor = work.text_mining_links()[0];
+        assert_eq!(Some(day(2019, 2, 1)), work.tdm_embargo_end(vor));
+        assert!(work.tdm_license_on(vor, day(2019, 1, 31)).is_none());
+        let license = work.tdm_license_on(vor, day(2019, 2, 1)).unwrap();
+        assert_eq!(ContentVersion::Tdm, license.content_version);
+    }
+
+    #[test]
+    fn tdm_licenses_per_link() {
+        let work = work_fixture(json!({
+            "link": [
+                {"URL": "https://example.org/vor.xml", "content-type": "text/xml",
+                 "content-version": "vor", "intended-application": "text-mining"},
+                {"URL": "https://example.org/am.xml", "content-type": "text/xml",
+                 "content-version": "am", "intended-application": "text-mining"}
+            ],
+            "license": [
+                {"URL": "https://example.org/terms", "content-version": "tdm",
+                 "delay-in-days": 0, "start": {"date-parts": [[2019, 2, 1]]}},
+                {"URL": "http://creativecommons.org/licenses/by/4.0/", "content-version": "am",
+                 "delay-in-days": 0, "start": {"date-parts": [[2019, 2, 1]]}}
+            ],
+        }));
+        let day = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let links = work.text_mining_links();
+
+        // the terms of use are registered for `tdm`, but they are no TDM license
+        assert_eq!(1, work.tdm_licenses().len());
+        assert!(work.tdm_license_on(links[0], day).is_none());
+        assert_eq!(None, work.tdm_embargo_end(links[0]));
+        let license = work.tdm_license_on(links[1], day).unwrap();
+        assert_eq!(ContentVersion::Am, license.content_version);
+    }
+}
@@ -0,0 +1,454 @@
+//! A small, forgiving reader for the xml crossref embeds in its json (JATS abstracts) or serves
+//! directly (UNIXREF). Malformed markup never fails: stray `<` are kept as text, unmatched end tags
+//! are ignored and unclosed elements are closed at the end of the input. Tags nested deeper than
+//! [MAX_DEPTH] are kept as text, so hostile input cannot overflow the stack of the recursive walks.
+
+use std::borrow::Cow;
+
+/// the deepest nesting of elements, tags below are kept as text
+pub(crate) const MAX_DEPTH: usize = 256;
+
+/// a node of a parsed xml fragment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Node {
+    Element(Element),
+    Text(String),
+}
+
+/// an xml element, its name is stored without namespace prefix, so `jats:p` becomes `p`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(crate) struct Element {
+    pub name: String,
+    pub attrs: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+impl Element {
+    /// the value of the attribute `name`, matched without namespace prefix
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// all direct child elements
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|node| match node {
+            Node::Element(el) => Some(el),
+            Node::Text(_) => None,
+        })
+    }
+
+    /// the first direct child element called `name`
+    pub fn child(&self, name: &str) -> Option<&Element> {
+        self.elements().find(|el| el.name == name)
+    }
+
+    /// all direct child elements called `name`
+    pub fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+        self.elements().filter(move |el| el.name == name)
+    }
+
+    /// the first element called `name` anywhere below this element
+    pub fn find(&self, name: &str) -> Option<&Element> {
+        self.elements()
+            .find_map(|el| if el.name == name { Some(el) } else { el.find(name) })
+    }
+
+    /// the concatenated text of all descendants, with whitespace collapsed
+    pub fn text(&self) -> String {
+        fn collect(nodes: &[Node], out: &mut String) {
+            for node in nodes {
+                match node {
+                    Node::Text(text) => out.push_str(text),
+                    Node::Element(el) => collect(&el.children, out),
+                }
+            }
+        }
+        let mut out = String::new();
+        collect(&self.children, &mut out);
+        collapse_whitespace(&out)
+    }
+
+    /// the text of the first direct child element called `name`
+    pub fn child_text(&self, name: &str) -> Option<String> {
+        self.child(name).map(Element::text).filter(|s| !s.is_empty())
+    }
+
+    /// the text directly inside this element, without the text of child elements
+    pub fn own_text(&self) -> String {
+        let text: String = self
+            .children
+            .iter()
+            .filter_map(|node| match node {
+                Node::Text(text) => Some(text.as_str()),
+                Node::Element(_) => None,
+            })
+            .collect();
+        collapse_whitespace(&text)
+    }
+
+    /// all elements called `name` anywhere below this element, in document order
+    pub fn descendants_named<'a>(&'a self, name: &str) -> Vec<&'a Element> {
+        fn collect<'a>(el: &'a Element, name: &str, out: &mut Vec<&'a Element>) {
+            for child in el.elements() {
+                if child.name == name {
+                    out.push(child);
+                }
+                collect(child, name, out);
+            }
+        }
+        let mut out = Vec::new();
+        collect(self, name, &mut out);
+        out
+    }
+
+    /// write the children of this element back as xml, names are written without namespace prefix
+    pub fn inner_xml(&self) -> String {
+        fn write(nodes: &[Node], out: &mut String) {
+            for node in nodes {
+                match node {
+                    Node::Text(text) => out.push_str(&escape(text)),
+                    Node::Element(el) => {
+                        out.push('<');
+                        out.push_str(&el.name);
+                        for (k, v) in &el.attrs {
+                            out.push_str(&format!(" {}=\"{}\"", k, escape(v)));
+                        }
+                        out.push('>');
+                        write(&el.children, out);
+                        out.push_str(&format!("</{}>", el.name));
+                    }
+                }
+            }
+        }
+        let mut out = String::new();
+        write(&self.children, &mut out);
+        out
+    }
+}
+
+/// parse an xml document or fragment into its top level nodes
+pub(crate) fn parse(input: &str) -> Vec<Node> {
+    let mut stack: Vec<Element> = Vec::new();
+    let mut roots = Vec::new();
+    let mut rest = input;
+
+    fn push(stack: &mut [Element], roots: &mut Vec<Node>, node: Node) {
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => roots.push(node),
+        }
+    }
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push(&mut stack, &mut roots, Node::Text(decode_entities(rest).into_owned()));
+            break;
+        };
+        if start > 0 {
+            push(
+                &mut stack,
+                &mut roots,
+                Node::Text(decode_entities(&rest[..start]).into_owned()),
+            );
+        }
+        rest = &rest[start..];
+
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map(|i| &after[i + 3..]).unwrap_or("");
+        } else if let Some(after) = rest.strip_prefix("<![CDATA[") {
+            let end = after.find("]]>").unwrap_or(after.len());
+            push(&mut stack, &mut roots, Node::Text(after[..end].to_string()));
+            rest = after.get(end + 3..).unwrap_or("");
+        } else if rest.starts_with("<?") || rest.starts_with("<!") {
+            rest = rest.find('>').map(|i| &rest[i + 1..]).unwrap_or("");
+        } else if let Some(after) = rest.strip_prefix("</") {
+            match after.find('>') {
+                Some(end) => {
+                    let name = local_name(after[..end].trim());
+                    if let Some(pos) = stack.iter().rposition(|el| el.name == name) {
+                        while stack.len() > pos {
+                            let el = stack.pop().expect("checked length");
+                            push(&mut stack, &mut roots, Node::Element(el));
+                        }
+                    }
+                    rest = &after[end + 1..];
+                }
+                None => {
+                    push(&mut stack, &mut roots, Node::Text(rest.to_string()));
+                    rest = "";
+                }
+            }
+        } else {
+            match parse_start_tag(&rest[1..]) {
+                Some((_, _, len)) if stack.len() >= MAX_DEPTH => {
+                    push(&mut stack, &mut roots, Node::Text(rest[..1 + len].to_string()));
+                    rest = &rest[1 + len..];
+                }
+                Some((el, self_closing, len)) => {
+                    if self_closing {
+                        push(&mut stack, &mut roots, Node::Element(el));
+                    } else {
+                        stack.push(el);
+                    }
+                    rest = &rest[1 + len..];
+                }
+                None => {
+                    // not a tag, e.g. `a < b`
+                    push(&mut stack, &mut roots, Node::Text("<".to_string()));
+                    rest = &rest[1..];
+                }
+            }
+        }
+    }
+
+    while let Some(el) = stack.pop() {
+        push(&mut stack, &mut roots, Node::Element(el));
+    }
+    merge_text(&mut roots);
+    roots
+}
+
+/// parse an xml document and return its root element
+pub(crate) fn parse_document(input: &str) -> Option<Element> {
+    parse(input).into_iter().find_map(|node| match node {
+        Node::Element(el) => Some(el),
+        Node::Text(_) => None,
+    })
+}
+
+/// parses the tag after the `<`, returns the element, whether it is self closing and the consumed length
+fn parse_start_tag(input: &str) -> Option<(Element, bool, usize)> {
+    let name_len = input
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .unwrap_or(input.len());
+    let name = &input[..name_len];
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, ':' | '-' | '_' | '.'))
+        || !name.starts_with(|c: char| c.is_alphabetic() || c == '_')
+    {
+        return None;
+    }
+
+    let mut el = Element {
+        name: local_name(name).to_string(),
+        ..Element::default()
+    };
+    let mut pos = name_len;
+    loop {
+        let rest = &input[pos..];
+        let trimmed = rest.trim_start();
+        pos += rest.len() - trimmed.len();
+        if let Some(after) = trimmed.strip_prefix("/>") {
+            return Some((el, true, input.len() - after.len()));
+        }
+        if let Some(after) = trimmed.strip_prefix('>') {
+            return Some((el, false, input.len() - after.len()));
+        }
+        if trimmed.is_empty() || trimmed.starts_with('<') {
+            return None;
+        }
+
+        let key_len = trimmed
+            .find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/'))
+            .unwrap_or(trimmed.len());
+        if key_len == 0 {
+            // a stray `/` or `=`, skip it
+            pos += 1;
+            continue;
+        }
+        let key = local_name(&trimmed[..key_len]).to_string();
+        let after_key = trimmed[key_len..].trim_start();
+        let Some(value) = after_key.strip_prefix('=') else {
+            el.attrs.push((key, String::new()));
+            pos = input.len() - after_key.len();
+            continue;
+        };
+        let value = value.trim_start();
+        let (raw, remaining) = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let end = value[1..].find(quote)?;
+                (&value[1..1 + end], &value[end + 2..])
+            }
+            _ => {
+                let end = value
+                    .find(|c: char| c.is_whitespace() || c == '>')
+                    .unwrap_or(value.len());
+                (&value[..end], &value[end..])
+            }
+        };
+        el.attrs.push((key, decode_entities(raw).into_owned()));
+        pos = input.len() - remaining.len();
+    }
+}
+
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+/// joins adjacent text nodes that are the result of stray `<`
+fn merge_text(nodes: &mut Vec<Node>) {
+    let mut merged: Vec<Node> = Vec::with_capacity(nodes.len());
+    for node in nodes.drain(..) {
+        match (merged.last_mut(), node) {
+            (Some(Node::Text(prev)), Node::Text(text)) => prev.push_str(&text),
+            (_, Node::Element(mut el)) => {
+                merge_text(&mut el.children);
+                merged.push(Node::Element(el));
+            }
+            (_, node) => merged.push(node),
+        }
+    }
+    *nodes = merged;
+}
+
+/// replace xml and the common html entities, unknown entities are kept as they are
+pub(crate) fn decode_entities(input: &str) -> Cow<'_, str> {
+    if !input.contains('&') {
+        return Cow::Borrowed(input);
+    }
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| decode_entity(&rest[1..1 + end]).map(|c| (c, end + 2)));
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(num) = entity.strip_prefix('#') {
+        let code = match num.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => num.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+    Some(match entity {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        "ndash" => '–',
+        "mdash" => '—',
+        "hellip" => '…',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "times" => '×',
+        "deg" => '°',
+        "plusmn" => '±',
+        "micro" => 'µ',
+        _ => return None,
+    })
+}
+
+/// escape text for html or xml output
+pub(crate) fn escape(input: &str) -> Cow<'_, str> {
+    if !input.contains(['&', '<', '>', '"']) {
+        return Cow::Borrowed(input);
+    }
+    let mut out = String::with_capacity(input.len() + 8);
+    for c in input.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// the text of a fragment that may contain markup like `<i>`, with whitespace collapsed
+pub(crate) fn strip_tags(input: &str) -> String {
+    if !input.contains(['<', '&']) {
+        return collapse_whitespace(input);
+    }
+    Element {
+        children: parse(input),
+        ..Element::default()
+    }
+    .text()
+}
+
+/// replace every run of whitespace with a single space and trim the ends
+pub(crate) fn collapse_whitespace(input: &str) -> String {
+    input.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root(input: &str) -> Element {
+        parse_document(input).unwrap()
+    }
+
+    #[test]
+    fn parse_nested_with_namespaces() {
+        let el = root(r#"<jats:sec id="s1"><jats:title>Intro</jats:title><jats:p>a &amp; b</jats:p></jats:sec>"#);
+        assert_eq!("sec", el.name);
+        assert_eq!(Some("s1"), el.attr("id"));
+        assert_eq!(Some("Intro".to_string()), el.child_text("title"));
+        assert_eq!("a & b", el.child("p").unwrap().text());
+    }
+
+    #[test]
+    fn parse_malformed() {
+        let nodes = parse("<p>1 < 2 <i>open</p></b> rest &bogus; &#x3b1;");
+        assert_eq!(2, nodes.len());
+        let Node::Element(p) = &nodes[0] else { panic!("expected an element") };
+        assert_eq!("1 < 2 open", p.text());
+        assert_eq!(Node::Text(" rest &bogus; α".to_string()), nodes[1]);
+    }
+
+    #[test]
+    fn parse_document_skips_prolog() {
+        let el = root("<?xml version=\"1.0\"?><!-- c --><doc a='1' b=2 c><x/><![CDATA[<raw>]]></doc>");
+        assert_eq!(Some("1"), el.attr("a"));
+        assert_eq!(Some("2"), el.attr("b"));
+        assert_eq!(Some(""), el.attr("c"));
+        assert!(el.child("x").is_some());
+        assert_eq!("<raw>", el.text());
+    }
+
+    #[test]
+    fn deep_nesting_is_kept_as_text() {
+        let depth = 100_000;
+        let input = format!("{}x{}", "<a>".repeat(depth), "</a>".repeat(depth));
+        let mut el = &root(&input);
+        let mut levels = 1;
+        while let Some(child) = el.child("a") {
+            el = child;
+            levels += 1;
+        }
+        assert_eq!(MAX_DEPTH, levels);
+        assert!(el.own_text().starts_with("<a><a>"));
+        assert!(el.own_text().ends_with("x"));
+        assert!(root(&input).find("b").is_none());
+    }
+}
@@ -1,9 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use crossref_rs::query::journals::{JournalResultControl, Journals};
-    use crossref_rs::query::ResultControl;
+    use crossref_rs::query::{JournalsQuery, ResultControl};
     use crossref_rs::{
-        CrossrefBuilder, FieldQuery, Type, WorkResultControl, WorksFilter, WorksIdentQuery, WorksQuery
+        CrossrefBuilder, FieldQuery, Type, WorkElement, WorkResultControl, WorksFilter, WorksIdentQuery, WorksQuery
     };
 
     #[tokio::test]
@@ -91,12 +90,23 @@ mod tests {
             .contains(&"Economic Geography".to_string()));
     }
 
+    #[tokio::test]
+    async fn combined_query_rejects_select() {
+        let client = CrossrefBuilder::default().build().unwrap();
+        let ident = WorksIdentQuery::new(
+            "78",
+            WorksQuery::empty().elements(vec![WorkElement::DOI, WorkElement::Title]),
+        );
+        assert!(client.member_works(ident).await.is_err());
+    }
+
     #[tokio::test]
     async fn journal_query() {
         let client = CrossrefBuilder::default().build().unwrap();
-        let control = Some(JournalResultControl::new_from_limit(10));
         let response = client
-            .journals("Economic Geography".to_string(), control)
+            .journals(
+                JournalsQuery::new("Economic Geography").result_control(ResultControl::Rows(10)),
+            )
             .await;
         println!("{:?}", response);
         assert!(response.is_ok());
@@ -106,4 +116,17 @@ mod tests {
             .title
             .contains(&"Economic Geography".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_funder_parent_and_ancestors() {
+        let client = CrossrefBuilder::default().build().unwrap();
+        // Office of Science, below the U.S. Department of Energy
+        let parent = client.funder_parent("100006132").await;
+        println!("{:?}", parent);
+        assert_eq!("100000015", parent.unwrap().unwrap().id.as_str());
+
+        let ancestors = client.funder_ancestors("100006132").await.unwrap();
+        assert_eq!("100000015", ancestors[0].as_ref().unwrap().id.as_str());
+        assert!(client.funder_parent("100000015").await.unwrap().is_none());
+    }
 }